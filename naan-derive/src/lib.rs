@@ -0,0 +1,879 @@
+//! Derive macros for [`naan`](https://docs.rs/naan).
+//!
+//! These are re-exported by `naan` when its `derive` feature is enabled,
+//! and should be used from there (`#[derive(naan::Functor)]`)
+//! rather than by depending on this crate directly.
+
+#![warn(missing_docs)]
+
+mod semigroup;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Group, TokenStream as Tokens, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::spanned::Spanned;
+use syn::{parse_macro_input,
+          Data,
+          DeriveInput,
+          Error,
+          Fields,
+          GenericArgument,
+          GenericParam,
+          Ident,
+          Member,
+          PathArguments,
+          Type,
+          WherePredicate};
+
+/// Derive `Functor` over the last type parameter of a struct or enum
+///
+/// This also generates the [`HKT1`](https://docs.rs/naan/latest/naan/trait.HKT1.html)
+/// marker for the type, named after the type with an `HKT` suffix
+/// (`Tree<A>` gets `TreeHKT`, `Pair<E, A>` gets `PairHKT<E>`), which
+/// `#[derive(Foldable)]` and `#[derive(Traversable)]` expect to exist.
+///
+/// Fields that mention the type parameter may be:
+///  * the type parameter itself
+///  * the type being derived (recursion)
+///  * tuples, arrays, `Box`es and `PhantomData`s of these
+///  * any naan `Functor` (`Option`, `Vec`, `Result`, `BTreeMap`, ...)
+///    whose only argument mentioning the type parameter is one of these
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// #[derive(Debug, PartialEq, Functor)]
+/// enum Tree<A> {
+///   Leaf(A),
+///   Node(Vec<Tree<A>>),
+/// }
+///
+/// let tree = Tree::Node(vec![Tree::Leaf(1), Tree::Node(vec![Tree::Leaf(2)])]);
+///
+/// assert_eq!(tree.fmap(|n: u8| n.to_string()),
+///            Tree::Node(vec![Tree::Leaf("1".to_string()),
+///                            Tree::Node(vec![Tree::Leaf("2".to_string())])]));
+/// ```
+#[proc_macro_derive(Functor)]
+pub fn derive_functor(input: TokenStream) -> TokenStream {
+  expand(input, functor)
+}
+
+/// Derive `Foldable` over the last type parameter of a struct or enum
+///
+/// Values are folded in the order that their fields are declared,
+/// recursing through the same field types as `#[derive(Functor)]`.
+///
+/// This uses the HKT marker generated by `#[derive(Functor)]`.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// #[derive(Functor, Foldable)]
+/// struct Labeled<A> {
+///   label: &'static str,
+///   first: A,
+///   rest: Vec<(A, Option<A>)>,
+/// }
+///
+/// let nums = Labeled { label: "nums",
+///                      first: 1,
+///                      rest: vec![(2, None), (3, Some(4))] };
+///
+/// assert_eq!(nums.foldl_ref(|acc: Vec<u8>, n: &u8| acc.append(vec![*n]), vec![]),
+///            vec![1, 2, 3, 4]);
+/// assert_eq!(nums.foldr(|n: u8, acc: Vec<u8>| acc.append(vec![n]), vec![]),
+///            vec![4, 3, 2, 1]);
+/// ```
+#[proc_macro_derive(Foldable)]
+pub fn derive_foldable(input: TokenStream) -> TokenStream {
+  expand(input, foldable)
+}
+
+/// Derive `Traversable` over the last type parameter of a struct or enum
+///
+/// The generated impl uses the type's `Functor` impl to take its values out,
+/// traverses them, and puts each result back where its input was taken from
+/// (see [`naan::traverse::Refill`](https://docs.rs/naan/latest/naan/traverse/struct.Refill.html)).
+/// This means the type must also implement `Functor` and `Foldable`, and
+/// its fields must be `Clone` when the type parameter is `usize`.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq, Functor, Foldable, Traversable)]
+/// enum Tree<A> {
+///   Leaf(A),
+///   Node(Vec<Tree<A>>),
+/// }
+///
+/// let parse = |s: &str| s.parse::<u8>().ok();
+///
+/// let tree = Tree::Node(vec![Tree::Leaf("1"), Tree::Node(vec![Tree::Leaf("2")])]);
+/// assert_eq!(tree.traverse::<hkt::Option, _>(parse),
+///            Some(Tree::Node(vec![Tree::Leaf(1), Tree::Node(vec![Tree::Leaf(2)])])));
+///
+/// let tree = Tree::Node(vec![Tree::Leaf("1"), Tree::Leaf("two")]);
+/// assert_eq!(tree.traverse::<hkt::Option, _>(parse), None);
+/// ```
+#[proc_macro_derive(Traversable)]
+pub fn derive_traversable(input: TokenStream) -> TokenStream {
+  expand(input, traversable)
+}
+
+/// Derive `Semigroup` for a struct by appending each of its fields
+///
+/// Every field must implement `Semigroup`.
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use naan::prelude::*;
+///
+/// #[derive(Debug, PartialEq, Semigroup, Monoid)]
+/// struct Metrics {
+///   errors: Vec<String>,
+///   latest_version: Option<String>,
+///   hits: HashMap<&'static str, usize>,
+/// }
+///
+/// let a = Metrics { errors: vec!["timed out".into()],
+///                   latest_version: None,
+///                   hits: HashMap::from([("/", 1)]) };
+/// let b = Metrics { errors: vec!["not found".into()],
+///                   latest_version: Some("1.2".into()),
+///                   hits: HashMap::from([("/about", 2)]) };
+///
+/// assert_eq!(a.append(b),
+///            Metrics { errors: vec!["timed out".into(), "not found".into()],
+///                      latest_version: Some("1.2".into()),
+///                      hits: HashMap::from([("/", 1), ("/about", 2)]) });
+/// ```
+#[proc_macro_derive(Semigroup)]
+pub fn derive_semigroup(input: TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+  semigroup::semigroup(&input).unwrap_or_else(Error::into_compile_error)
+                              .into()
+}
+
+/// Derive `Monoid` for a struct whose identity is
+/// the identity of each of its fields
+///
+/// Every field must implement `Monoid`, and the struct must implement `Semigroup`.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// #[derive(Debug, PartialEq, Semigroup, Monoid)]
+/// struct Args(Vec<String>, Option<String>);
+///
+/// assert_eq!(Args::identity(), Args(vec![], None));
+/// ```
+#[proc_macro_derive(Monoid)]
+pub fn derive_monoid(input: TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+  semigroup::monoid(&input).unwrap_or_else(Error::into_compile_error)
+                           .into()
+}
+
+fn expand(input: TokenStream, derive: fn(&Target) -> syn::Result<Tokens>) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+  Target::new(input).and_then(|target| derive(&target))
+                    .unwrap_or_else(Error::into_compile_error)
+                    .into()
+}
+
+/// A struct or enum being derived, and the type parameter being derived over
+struct Target {
+  input: DeriveInput,
+  a: Ident,
+  hkt: Ident,
+  params: Vec<GenericParam>,
+  preds: Vec<WherePredicate>,
+}
+
+impl Target {
+  fn new(input: DeriveInput) -> syn::Result<Self> {
+    let mut params = input.generics.params.iter().cloned().collect::<Vec<_>>();
+
+    let a = match params.pop() {
+      | Some(GenericParam::Type(p)) if p.bounds.is_empty() && p.default.is_none() => p.ident,
+      | Some(GenericParam::Type(p)) => {
+        return Err(Error::new(p.span(),
+                              "the last type parameter can't have bounds or a default, \
+                               since the derived impls must work for any type"))
+      },
+      | _ => {
+        return Err(Error::new(input.ident.span(),
+                              "expected the last generic parameter to be a type parameter to derive over"))
+      },
+    };
+
+    let preds = input.generics
+                     .where_clause
+                     .iter()
+                     .flat_map(|w| w.predicates.iter().cloned())
+                     .collect::<Vec<_>>();
+
+    if let Some(pred) = preds.iter()
+                             .find(|p| mentions(p.to_token_stream(), &a.to_string()))
+    {
+      return Err(Error::new(pred.span(),
+                            "the last type parameter can't have bounds, \
+                             since the derived impls must work for any type"));
+    }
+
+    for param in params.iter_mut() {
+      match param {
+        | GenericParam::Type(p) => {
+          p.eq_token = None;
+          p.default = None;
+        },
+        | GenericParam::Const(p) => {
+          p.eq_token = None;
+          p.default = None;
+        },
+        | GenericParam::Lifetime(_) => (),
+      }
+    }
+
+    let hkt = format_ident!("{}HKT", input.ident);
+
+    Ok(Self { input,
+              a,
+              hkt,
+              params,
+              preds })
+  }
+
+  /// The generic arguments other than the one being derived over
+  fn args(&self) -> Vec<Tokens> {
+    self.params
+        .iter()
+        .map(|p| match p {
+          | GenericParam::Lifetime(p) => p.lifetime.to_token_stream(),
+          | GenericParam::Type(p) => p.ident.to_token_stream(),
+          | GenericParam::Const(p) => p.ident.to_token_stream(),
+        })
+        .collect()
+  }
+
+  /// The HKT marker, e.g. `PairHKT<E>`
+  fn hkt_ty(&self) -> Tokens {
+    let hkt = &self.hkt;
+    let args = self.args();
+    if args.is_empty() {
+      quote!(#hkt)
+    } else {
+      quote!(#hkt<#(#args),*>)
+    }
+  }
+
+  /// The type being derived, with `a` in place of the type parameter
+  fn ty(&self, a: impl ToTokens) -> Tokens {
+    let ident = &self.input.ident;
+    let args = self.args();
+    quote!(#ident<#(#args,)* #a>)
+  }
+
+  fn is_self(&self, ty: &Type) -> bool {
+    let path = match ty {
+      | Type::Path(ty) if ty.qself.is_none() => &ty.path,
+      | _ => return false,
+    };
+
+    if path.is_ident("Self") {
+      return true;
+    }
+
+    let seg = match path.segments.last() {
+      | Some(seg) if seg.ident == self.input.ident => seg,
+      | _ => return false,
+    };
+
+    let expected = self.args()
+                       .into_iter()
+                       .chain(Some(self.a.to_token_stream()))
+                       .map(|t| t.to_string())
+                       .collect::<Vec<_>>();
+
+    match &seg.arguments {
+      | PathArguments::AngleBracketed(args) => {
+        args.args.len() == expected.len()
+        && args.args
+               .iter()
+               .zip(expected.iter())
+               .all(|(arg, expected)| arg.to_token_stream().to_string() == *expected)
+      },
+      | _ => false,
+    }
+  }
+
+  /// Whether `ty` contains the type parameter being derived over,
+  /// or the type being derived.
+  fn mentions(&self, ty: &Type) -> bool {
+    let tokens = ty.to_token_stream();
+    mentions(tokens.clone(), &self.a.to_string())
+    || mentions(tokens.clone(), &self.input.ident.to_string())
+    || mentions(tokens, "Self")
+  }
+
+  /// The arms of a `match` on `this`, each rebuilding the matched
+  /// variant with `field` applied to each of its fields.
+  fn rebuild_arms(&self,
+                  mut field: impl FnMut(&Type, Tokens) -> syn::Result<Tokens>)
+                  -> syn::Result<Vec<Tokens>> {
+    self.variants()
+        .into_iter()
+        .map(|(path, fields)| {
+          let (members, bindings) = bindings(fields);
+          let exprs = fields.iter()
+                            .zip(bindings.iter())
+                            .map(|(f, x)| field(&f.ty, x.to_token_stream()))
+                            .collect::<syn::Result<Vec<_>>>()?;
+
+          Ok(quote! {
+               #path { #(#members: #bindings),* } => #path { #(#members: #exprs),* }
+             })
+        })
+        .collect()
+  }
+
+  /// The arms of a `match` on `this`, each threading `b` through
+  /// `field` applied to each field of the matched variant
+  fn fold_arms(&self,
+               rev: bool,
+               mut field: impl FnMut(&Type, Tokens) -> syn::Result<Tokens>)
+               -> syn::Result<Vec<Tokens>> {
+    self.variants()
+        .into_iter()
+        .map(|(path, fields)| {
+          let (members, bindings) = bindings(fields);
+          let mut steps = fields.iter()
+                                .zip(bindings.iter())
+                                .map(|(f, x)| field(&f.ty, x.to_token_stream()))
+                                .collect::<syn::Result<Vec<_>>>()?;
+
+          if rev {
+            steps.reverse();
+          }
+
+          Ok(quote! {
+               #path { #(#members: #bindings),* } => {
+                 #(let b = #steps;)*
+                 b
+               }
+             })
+        })
+        .collect()
+  }
+
+  /// The path to construct each variant (or the struct) with, and its fields
+  fn variants(&self) -> Vec<(Tokens, &Fields)> {
+    let ident = &self.input.ident;
+    match &self.input.data {
+      | Data::Struct(s) => vec![(quote!(#ident), &s.fields)],
+      | Data::Enum(e) => e.variants
+                          .iter()
+                          .map(|v| {
+                            let var = &v.ident;
+                            (quote!(#ident::#var), &v.fields)
+                          })
+                          .collect(),
+      | Data::Union(_) => vec![],
+    }
+  }
+
+  fn check_data(&self) -> syn::Result<()> {
+    match &self.input.data {
+      | Data::Union(u) => Err(Error::new(u.union_token.span(), "unions are not supported")),
+      | _ => Ok(()),
+    }
+  }
+
+  /// An expression applying `f` to every value in `val: ty`
+  fn fmap_expr(&self, ty: &Type, val: Tokens, depth: usize) -> syn::Result<Tokens> {
+    let x = format_ident!("__x{}", depth);
+
+    if !self.mentions(ty) {
+      return Ok(val);
+    }
+
+    if self.is_self(ty) {
+      return Ok(quote!(go(#val, f)));
+    }
+
+    match ty {
+      | Type::Paren(ty) => self.fmap_expr(&ty.elem, val, depth),
+      | Type::Group(ty) => self.fmap_expr(&ty.elem, val, depth),
+      | Type::Path(p) if p.path.is_ident(&self.a) => Ok(quote!(::naan::prelude::F1::call(f, #val))),
+      | Type::Tuple(tup) => {
+        let xs = (0..tup.elems.len()).map(|i| format_ident!("__x{}_{}", depth, i))
+                                     .collect::<Vec<_>>();
+        let exprs = tup.elems
+                       .iter()
+                       .zip(xs.iter())
+                       .map(|(ty, x)| self.fmap_expr(ty, x.to_token_stream(), depth + 1))
+                       .collect::<syn::Result<Vec<_>>>()?;
+        Ok(quote!({
+             let (#(#xs,)*) = #val;
+             (#(#exprs,)*)
+           }))
+      },
+      | Type::Array(arr) => {
+        let expr = self.fmap_expr(&arr.elem, x.to_token_stream(), depth + 1)?;
+        Ok(quote!((#val).map(|#x| #expr)))
+      },
+      | Type::Path(_) => match self.container(ty)? {
+        | Container::Box(inner) => {
+          let expr = self.fmap_expr(inner, quote!(*#val), depth + 1)?;
+          // `Box` isn't in scope in `no_std` crates, but `From` always is
+          Ok(quote!(::core::convert::From::from(#expr)))
+        },
+        | Container::Phantom => Ok(quote!(::core::marker::PhantomData)),
+        | Container::Functor(inner) => {
+          let expr = self.fmap_expr(inner, x.to_token_stream(), depth + 1)?;
+          let inner = self.concrete(inner);
+          Ok(quote!(::naan::prelude::Functor::fmap(#val, |#x: #inner| #expr)))
+        },
+      },
+      | _ => Err(unsupported(ty)),
+    }
+  }
+
+  /// An expression folding every value in `val: ty` into `acc`
+  fn fold_expr(&self,
+               ty: &Type,
+               val: Tokens,
+               acc: Tokens,
+               fold: Fold,
+               depth: usize)
+               -> syn::Result<Tokens> {
+    let x = format_ident!("__x{}", depth);
+    let b = format_ident!("__b{}", depth);
+
+    if !self.mentions(ty) {
+      return Ok(acc);
+    }
+
+    if self.is_self(ty) {
+      return Ok(quote!(go(#val, f, #acc)));
+    }
+
+    match ty {
+      | Type::Paren(ty) => self.fold_expr(&ty.elem, val, acc, fold, depth),
+      | Type::Group(ty) => self.fold_expr(&ty.elem, val, acc, fold, depth),
+      | Type::Path(p) if p.path.is_ident(&self.a) => Ok(if fold.right {
+                                                          quote!(::naan::prelude::F2::call(f, #val, #acc))
+                                                        } else {
+                                                          quote!(::naan::prelude::F2::call(f, #acc, #val))
+                                                        }),
+      | Type::Tuple(tup) => {
+        let xs = (0..tup.elems.len()).map(|i| format_ident!("__x{}_{}", depth, i))
+                                     .collect::<Vec<_>>();
+        let mut steps = tup.elems
+                           .iter()
+                           .zip(xs.iter())
+                           .map(|(ty, x)| {
+                             self.fold_expr(ty,
+                                            x.to_token_stream(),
+                                            b.to_token_stream(),
+                                            fold,
+                                            depth + 1)
+                           })
+                           .collect::<syn::Result<Vec<_>>>()?;
+
+        if fold.right {
+          steps.reverse();
+        }
+
+        Ok(quote!({
+             let (#(#xs,)*) = #val;
+             let #b = #acc;
+             #(let #b = #steps;)*
+             #b
+           }))
+      },
+      | Type::Array(arr) => {
+        let step = self.fold_expr(&arr.elem,
+                                  x.to_token_stream(),
+                                  b.to_token_stream(),
+                                  fold,
+                                  depth + 1)?;
+        let rev = if fold.right { quote!(.rev()) } else { quote!() };
+        Ok(quote! {
+             ::core::iter::IntoIterator::into_iter(#val)#rev.fold(#acc, |#b, #x| #step)
+           })
+      },
+      | Type::Path(_) => match self.container(ty)? {
+        | Container::Box(inner) => {
+          let deref = if fold.by_ref {
+            quote!(&**#val)
+          } else {
+            quote!(*#val)
+          };
+          self.fold_expr(inner, deref, acc, fold, depth + 1)
+        },
+        | Container::Phantom => Ok(acc),
+        | Container::Functor(inner) => {
+          let step = self.fold_expr(inner,
+                                    x.to_token_stream(),
+                                    b.to_token_stream(),
+                                    fold,
+                                    depth + 1)?;
+          let inner = self.concrete(inner);
+          let x = if fold.by_ref {
+            quote!(#x: &'__a #inner)
+          } else {
+            quote!(#x: #inner)
+          };
+          Ok(match (fold.right, fold.by_ref) {
+               | (false, false) => {
+                 quote!(::naan::prelude::Foldable::foldl(#val, |#b, #x| #step, #acc))
+               },
+               | (true, false) => {
+                 quote!(::naan::prelude::Foldable::foldr(#val, |#x, #b| #step, #acc))
+               },
+               | (false, true) => {
+                 quote!(::naan::prelude::Foldable::foldl_ref(#val, |#b, #x| #step, #acc))
+               },
+               | (true, true) => {
+                 quote!(::naan::prelude::Foldable::foldr_ref(#val, |#x, #b| #step, #acc))
+               },
+             })
+        },
+      },
+      | _ => Err(unsupported(ty)),
+    }
+  }
+
+  /// `ty` with `Self` replaced by the type being derived,
+  /// so that it can be written in a nested function
+  fn concrete(&self, ty: &Type) -> Tokens {
+    fn replace(tokens: Tokens, this: &Tokens) -> Tokens {
+      tokens.into_iter()
+            .map(|t| match t {
+              | TokenTree::Ident(i) if i == "Self" => this.clone(),
+              | TokenTree::Group(g) => {
+                let mut group = Group::new(g.delimiter(), replace(g.stream(), this));
+                group.set_span(g.span());
+                TokenTree::Group(group).into()
+              },
+              | t => t.into(),
+            })
+            .collect()
+    }
+
+    replace(ty.to_token_stream(), &self.ty(&self.a))
+  }
+
+  /// `ty` as a field of the type being derived with `usize` in place of the
+  /// type parameter, e.g. the shape left behind by `naan::traverse::Refill`
+  fn shape_ty(&self, ty: &Type) -> Tokens {
+    fn replace(tokens: Tokens, a: &Ident, this: &Tokens) -> Tokens {
+      tokens.into_iter()
+            .map(|t| match t {
+              | TokenTree::Ident(i) if i == *a => quote!(usize),
+              | TokenTree::Ident(i) if i == "Self" => this.clone(),
+              | TokenTree::Group(g) => {
+                let mut group = Group::new(g.delimiter(), replace(g.stream(), a, this));
+                group.set_span(g.span());
+                TokenTree::Group(group).into()
+              },
+              | t => t.into(),
+            })
+            .collect()
+    }
+
+    replace(ty.to_token_stream(), &self.a, &self.ty(quote!(usize)))
+  }
+
+  /// Figure out how to recurse into a path type that mentions the type parameter
+  fn container<'a>(&self, ty: &'a Type) -> syn::Result<Container<'a>> {
+    let seg = match ty {
+      | Type::Path(p) if p.qself.is_none() => p.path.segments.last(),
+      | _ => None,
+    };
+    let seg = seg.ok_or_else(|| unsupported(ty))?;
+
+    let args = match &seg.arguments {
+      | PathArguments::AngleBracketed(args) => args.args
+                                                   .iter()
+                                                   .filter_map(|arg| match arg {
+                                                     | GenericArgument::Type(ty) => Some(ty),
+                                                     | _ => None,
+                                                   })
+                                                   .collect::<Vec<_>>(),
+      | _ => vec![],
+    };
+
+    if seg.ident == "PhantomData" {
+      return Ok(Container::Phantom);
+    }
+
+    let mut mentioning = args.into_iter().filter(|ty| self.mentions(ty));
+    match (mentioning.next(), mentioning.next()) {
+      | (Some(inner), None) if seg.ident == "Box" => Ok(Container::Box(inner)),
+      | (Some(inner), None) => Ok(Container::Functor(inner)),
+      | (Some(_), Some(_)) => {
+        Err(Error::new(ty.span(),
+                       format!("can't derive through `{}`, since more than one of its \
+                                type arguments mention `{}`",
+                               ty.to_token_stream(),
+                               self.a)))
+      },
+      | (None, _) => Err(unsupported(ty)),
+    }
+  }
+}
+
+#[derive(Clone, Copy)]
+struct Fold {
+  right: bool,
+  by_ref: bool,
+}
+
+enum Container<'a> {
+  Box(&'a Type),
+  Phantom,
+  Functor(&'a Type),
+}
+
+fn unsupported(ty: &Type) -> Error {
+  Error::new(ty.span(),
+             format!("can't derive through `{}`; expected the type parameter, \
+                      the type being derived, a tuple, an array, a Box, \
+                      or a naan Functor / Foldable",
+                     ty.to_token_stream()))
+}
+
+/// Whether `tokens` contains the identifier `ident`
+fn mentions(tokens: Tokens, ident: &str) -> bool {
+  tokens.into_iter().any(|t| match t {
+                      | TokenTree::Ident(i) => i == ident,
+                      | TokenTree::Group(g) => mentions(g.stream(), ident),
+                      | _ => false,
+                    })
+}
+
+/// The members of `fields` and identifiers to bind them to
+fn bindings(fields: &Fields) -> (Vec<Member>, Vec<Ident>) {
+  fields.iter()
+        .enumerate()
+        .map(|(i, f)| {
+          let member = match &f.ident {
+            | Some(ident) => Member::Named(ident.clone()),
+            | None => Member::Unnamed(i.into()),
+          };
+          (member, format_ident!("__field{}", i))
+        })
+        .unzip()
+}
+
+fn functor(target: &Target) -> syn::Result<Tokens> {
+  target.check_data()?;
+
+  let Target { input,
+               a,
+               hkt,
+               params,
+               preds,
+               .. } = target;
+  let hkt_ty = target.hkt_ty();
+  let ta = target.ty(a);
+  let tb = target.ty(quote!(__B));
+
+  let phantoms = params.iter()
+                       .filter_map(|p| match p {
+                         | GenericParam::Lifetime(p) => {
+                           let lt = &p.lifetime;
+                           Some(quote!(&#lt ()))
+                         },
+                         | GenericParam::Type(p) => Some(p.ident.to_token_stream()),
+                         | GenericParam::Const(_) => None,
+                       })
+                       .collect::<Vec<_>>();
+
+  let vis = &input.vis;
+  let doc = format!("[`{}`] lifted to an HKT1\n\n(Kind `Type -> Type`)",
+                    input.ident);
+
+  let arms = target.rebuild_arms(|ty, val| target.fmap_expr(ty, val, 0))?;
+
+  Ok(quote! {
+       #[doc = #doc]
+       #vis struct #hkt<#(#params),*>(::core::marker::PhantomData<(#(#phantoms,)*)>)
+         where #(#preds),*;
+
+       impl<#(#params),*> ::naan::HKT1 for #hkt_ty where #(#preds),* {
+         type T<#a> = #ta;
+       }
+
+       #[automatically_derived]
+       impl<#(#params,)* #a> ::naan::prelude::Functor<#hkt_ty, #a> for #ta where #(#preds),* {
+         fn fmap<__AB, __B>(self, f: __AB) -> #tb
+           where __AB: ::naan::prelude::F1<#a, Ret = __B>
+         {
+           fn go<#(#params,)* #a, __B, __F>(this: #ta, f: &__F) -> #tb
+             where __F: ::naan::prelude::F1<#a, Ret = __B>,
+                   #(#preds),*
+           {
+             match this {
+               #(#arms,)*
+             }
+           }
+
+           go(self, &f)
+         }
+       }
+     })
+}
+
+fn foldable(target: &Target) -> syn::Result<Tokens> {
+  target.check_data()?;
+
+  let Target { a, params, preds, .. } = target;
+  let hkt_ty = target.hkt_ty();
+  let ta = target.ty(a);
+
+  let arms = |right, by_ref| {
+    target.fold_arms(right, |ty, val| {
+            target.fold_expr(ty, val, quote!(b), Fold { right, by_ref }, 0)
+          })
+  };
+  let foldl = arms(false, false)?;
+  let foldr = arms(true, false)?;
+  let foldl_ref = arms(false, true)?;
+  let foldr_ref = arms(true, true)?;
+
+  Ok(quote! {
+       #[automatically_derived]
+       impl<#(#params,)* #a> ::naan::prelude::Foldable<#hkt_ty, #a> for #ta where #(#preds),* {
+         fn foldl<__B, __BAB>(self, f: __BAB, b: __B) -> __B
+           where __BAB: ::naan::prelude::F2<__B, #a, Ret = __B>
+         {
+           fn go<#(#params,)* #a, __B, __F>(this: #ta, f: &__F, b: __B) -> __B
+             where __F: ::naan::prelude::F2<__B, #a, Ret = __B>,
+                   #(#preds),*
+           {
+             match this {
+               #(#foldl,)*
+             }
+           }
+
+           go(self, &f, b)
+         }
+
+         fn foldr<__B, __ABB>(self, f: __ABB, b: __B) -> __B
+           where __ABB: ::naan::prelude::F2<#a, __B, Ret = __B>
+         {
+           fn go<#(#params,)* #a, __B, __F>(this: #ta, f: &__F, b: __B) -> __B
+             where __F: ::naan::prelude::F2<#a, __B, Ret = __B>,
+                   #(#preds),*
+           {
+             match this {
+               #(#foldr,)*
+             }
+           }
+
+           go(self, &f, b)
+         }
+
+         fn foldl_ref<'__a, __B, __BAB>(&'__a self, f: __BAB, b: __B) -> __B
+           where __BAB: ::naan::prelude::F2<__B, &'__a #a, Ret = __B>,
+                 #a: '__a
+         {
+           fn go<'__a, #(#params,)* #a, __B, __F>(this: &'__a #ta, f: &__F, b: __B) -> __B
+             where __F: ::naan::prelude::F2<__B, &'__a #a, Ret = __B>,
+                   #a: '__a,
+                   #(#preds),*
+           {
+             match this {
+               #(#foldl_ref,)*
+             }
+           }
+
+           go(self, &f, b)
+         }
+
+         fn foldr_ref<'__a, __B, __ABB>(&'__a self, f: __ABB, b: __B) -> __B
+           where __ABB: ::naan::prelude::F2<&'__a #a, __B, Ret = __B>,
+                 #a: '__a
+         {
+           fn go<'__a, #(#params,)* #a, __B, __F>(this: &'__a #ta, f: &__F, b: __B) -> __B
+             where __F: ::naan::prelude::F2<&'__a #a, __B, Ret = __B>,
+                   #a: '__a,
+                   #(#preds),*
+           {
+             match this {
+               #(#foldr_ref,)*
+             }
+           }
+
+           go(self, &f, b)
+         }
+       }
+     })
+}
+
+fn traversable(target: &Target) -> syn::Result<Tokens> {
+  target.check_data()?;
+
+  let Target { a, params, preds, .. } = target;
+  let hkt_ty = target.hkt_ty();
+  let ta = target.ty(a);
+  let tshape = target.ty(quote!(usize));
+  let tb = quote!(<#hkt_ty as ::naan::HKT1>::T<__B>);
+  let tf = quote!(::naan::traverse::Refill<#hkt_ty, __B>);
+
+  // Only the fields need to be `Clone` to copy the shape left behind by `Refill`,
+  // not the type being derived.
+  let mut fields = Vec::<String>::new();
+  let mut clone_preds = Vec::new();
+  for (_, fs) in target.variants() {
+    for f in fs.iter() {
+      let ty = target.shape_ty(&f.ty);
+      if !fields.contains(&ty.to_string()) {
+        fields.push(ty.to_string());
+        clone_preds.push(quote!(#ty: ::core::clone::Clone));
+      }
+    }
+  }
+
+  let clone_arms = target.rebuild_arms(|_, val| Ok(quote!(::core::clone::Clone::clone(#val))))?;
+
+  Ok(quote! {
+       #[automatically_derived]
+       impl<#(#params,)* #a, __B> ::naan::prelude::Traversable<#hkt_ty, #a, __B, #tf> for #ta
+         where #(#clone_preds,)*
+               #(#preds),*
+       {
+         fn traversem1<__Ap, __AtoApOfB>(self, f: __AtoApOfB) -> <__Ap as ::naan::HKT1>::T<#tb>
+           where __Ap: ::naan::HKT1,
+                 __Ap::T<__B>: ::naan::prelude::Applicative<__Ap, __B> + ::naan::prelude::ApplyOnce<__Ap, __B>,
+                 __Ap::T<#tf>: ::naan::prelude::Applicative<__Ap, #tf> + ::naan::prelude::ApplyOnce<__Ap, #tf>,
+                 __Ap::T<#tb>: ::naan::prelude::Applicative<__Ap, #tb> + ::naan::prelude::ApplyOnce<__Ap, #tb>,
+                 __AtoApOfB: ::naan::prelude::F1<#a, Ret = __Ap::T<__B>>
+         {
+           ::naan::traverse::Refill::<#hkt_ty, __B>::traversem1::<#a, __Ap, __AtoApOfB>(self, f)
+         }
+
+         fn traversemm<__Ap, __AtoApOfB>(self, f: __AtoApOfB) -> <__Ap as ::naan::HKT1>::T<#tb>
+           where __Ap: ::naan::HKT1,
+                 __B: ::core::clone::Clone,
+                 __Ap::T<__B>: ::naan::prelude::Applicative<__Ap, __B>,
+                 __Ap::T<#tf>: ::naan::prelude::Applicative<__Ap, #tf>,
+                 __Ap::T<#tb>: ::naan::prelude::Applicative<__Ap, #tb>,
+                 __AtoApOfB: ::naan::prelude::F1<#a, Ret = __Ap::T<__B>>
+         {
+           let clone_shape = |this: &#tshape| -> #tshape {
+             match this {
+               #(#clone_arms,)*
+             }
+           };
+
+           ::naan::traverse::Refill::<#hkt_ty, __B>::traversemm_with::<#a, __Ap, __AtoApOfB>(self, clone_shape, f)
+         }
+       }
+     })
+}
@@ -0,0 +1,100 @@
+use proc_macro2::TokenStream as Tokens;
+use quote::{format_ident, quote, ToTokens};
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Error, Fields, GenericParam, Ident, Member, Type, WherePredicate};
+
+/// The fields of a struct, erroring on enums and unions
+/// since there's no one way to append their variants.
+fn fields<'a>(input: &'a DeriveInput, derive: &str) -> syn::Result<&'a Fields> {
+  match &input.data {
+    | Data::Struct(s) => Ok(&s.fields),
+    | Data::Enum(e) => Err(Error::new(e.enum_token.span(),
+                                      format!("{derive} can only be derived for structs"))),
+    | Data::Union(u) => Err(Error::new(u.union_token.span(),
+                                       format!("{derive} can only be derived for structs"))),
+  }
+}
+
+/// The struct's where clause, plus `bound` for each field type
+/// that mentions one of the struct's type parameters
+fn where_clause(input: &DeriveInput, fields: &Fields, bound: Tokens) -> Vec<Tokens> {
+  let params = input.generics
+                    .params
+                    .iter()
+                    .filter_map(|p| match p {
+                      | GenericParam::Type(p) => Some(p.ident.to_string()),
+                      | _ => None,
+                    })
+                    .collect::<Vec<_>>();
+
+  let preds = input.generics
+                   .where_clause
+                   .iter()
+                   .flat_map(|w| w.predicates.iter())
+                   .map(WherePredicate::to_token_stream);
+
+  let bounds = fields.iter()
+                     .map(|f| &f.ty)
+                     .filter(|ty| {
+                       params.iter()
+                             .any(|p| crate::mentions(ty.to_token_stream(), p))
+                     })
+                     .map(|ty: &Type| quote!(#ty: #bound));
+
+  preds.chain(bounds).collect()
+}
+
+fn members(fields: &Fields) -> Vec<Member> {
+  fields.iter()
+        .enumerate()
+        .map(|(i, f)| match &f.ident {
+          | Some(ident) => Member::Named(ident.clone()),
+          | None => Member::Unnamed(i.into()),
+        })
+        .collect()
+}
+
+fn bindings(prefix: &str, fields: &Fields) -> Vec<Ident> {
+  (0..fields.len()).map(|i| format_ident!("__{}{}", prefix, i))
+                   .collect()
+}
+
+pub(crate) fn semigroup(input: &DeriveInput) -> syn::Result<Tokens> {
+  let fields = fields(input, "Semigroup")?;
+  let ident = &input.ident;
+  let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+  let preds = where_clause(input, fields, quote!(::naan::prelude::Semigroup));
+
+  let members = members(fields);
+  let a = bindings("a", fields);
+  let b = bindings("b", fields);
+
+  Ok(quote! {
+       #[automatically_derived]
+       impl #impl_generics ::naan::prelude::Semigroup for #ident #ty_generics where #(#preds),* {
+         fn append(self, b: Self) -> Self {
+           let #ident { #(#members: #a),* } = self;
+           let #ident { #(#members: #b),* } = b;
+           #ident { #(#members: ::naan::prelude::Semigroup::append(#a, #b)),* }
+         }
+       }
+     })
+}
+
+pub(crate) fn monoid(input: &DeriveInput) -> syn::Result<Tokens> {
+  let fields = fields(input, "Monoid")?;
+  let ident = &input.ident;
+  let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+  let preds = where_clause(input, fields, quote!(::naan::prelude::Monoid));
+
+  let members = members(fields);
+
+  Ok(quote! {
+       #[automatically_derived]
+       impl #impl_generics ::naan::prelude::Monoid for #ident #ty_generics where #(#preds),* {
+         fn identity() -> Self {
+           #ident { #(#members: ::naan::prelude::Monoid::identity()),* }
+         }
+       }
+     })
+}
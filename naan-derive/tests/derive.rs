@@ -0,0 +1,204 @@
+use std::collections::{BTreeMap, HashMap};
+use std::marker::PhantomData;
+
+use naan::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Functor, Foldable, Traversable)]
+enum Tree<A> {
+  Leaf(A),
+  Node(Vec<Tree<A>>),
+}
+
+#[derive(Clone, Debug, PartialEq, Functor, Foldable, Traversable)]
+struct Everything<'a, E, const N: usize, A>
+  where E: Clone
+{
+  name: &'a str,
+  err: Result<A, E>,
+  pair: (A, u8, Option<A>),
+  array: [A; N],
+  boxed: Option<Box<Everything<'a, E, N, A>>>,
+  map: BTreeMap<u8, Vec<A>>,
+  phantom: PhantomData<A>,
+}
+
+#[derive(Debug, PartialEq, Functor, Foldable, Traversable)]
+struct Env<A> {
+  name: String,
+  vars: HashMap<String, A>,
+}
+
+#[derive(Debug, PartialEq, Functor, Foldable)]
+struct Unit<A>(PhantomData<A>);
+
+#[derive(Debug, PartialEq, Functor, Foldable)]
+enum List<A> {
+  Nil,
+  Cons { head: A, tail: Box<Self> },
+}
+
+fn tree() -> Tree<u32> {
+  Tree::Node(vec![Tree::Leaf(1),
+                  Tree::Node(vec![Tree::Leaf(2), Tree::Leaf(3)]),
+                  Tree::Leaf(4)])
+}
+
+fn everything() -> Everything<'static, String, 2, u32> {
+  let inner = Everything { name: "inner",
+                           err: Err("oh no".to_string()),
+                           pair: (6, 0, None),
+                           array: [7, 8],
+                           boxed: None,
+                           map: BTreeMap::new(),
+                           phantom: PhantomData };
+
+  Everything { name: "outer",
+               err: Ok(1),
+               pair: (2, 0, Some(3)),
+               array: [4, 5],
+               boxed: Some(Box::new(inner)),
+               map: BTreeMap::from([(1, vec![9, 10]), (0, vec![])]),
+               phantom: PhantomData }
+}
+
+fn push<A>(mut v: Vec<A>, a: A) -> Vec<A> {
+  v.push(a);
+  v
+}
+
+#[test]
+fn functor() {
+  assert_eq!(tree().fmap(|n: u32| n * 10),
+             Tree::Node(vec![Tree::Leaf(10),
+                             Tree::Node(vec![Tree::Leaf(20), Tree::Leaf(30)]),
+                             Tree::Leaf(40)]));
+
+  let strs = everything().fmap(|n: u32| n.to_string());
+  assert_eq!(strs.name, "outer");
+  assert_eq!(strs.pair, ("2".to_string(), 0, Some("3".to_string())));
+  assert_eq!(strs.boxed.unwrap().array,
+             ["7".to_string(), "8".to_string()]);
+  assert_eq!(strs.map.get(&1),
+             Some(&vec!["9".to_string(), "10".to_string()]));
+
+  assert_eq!(Unit::<u8>(PhantomData).fmap(|n: u8| n as i8),
+             Unit(PhantomData));
+
+  let list = List::Cons { head: 1,
+                          tail: Box::new(List::Cons { head: 2,
+                                                      tail: Box::new(List::Nil) }) };
+  assert_eq!(list.fmap(|n: u8| n + 1),
+             List::Cons { head: 2,
+                          tail: Box::new(List::Cons { head: 3,
+                                                      tail: Box::new(List::Nil) }) });
+}
+
+#[test]
+fn foldable() {
+  assert_eq!(tree().foldl(push, vec![]), vec![1, 2, 3, 4]);
+  assert_eq!(tree().foldr(|a, v| push(v, a), vec![]), vec![4, 3, 2, 1]);
+  assert_eq!(tree().foldl_ref(|v, a: &u32| push(v, *a), vec![]),
+             vec![1, 2, 3, 4]);
+  assert_eq!(tree().foldr_ref(|a: &u32, v| push(v, *a), vec![]),
+             vec![4, 3, 2, 1]);
+
+  let expected = (1..=10).collect::<Vec<u32>>();
+  assert_eq!(everything().foldl(push, vec![]), expected);
+  assert_eq!(everything().foldl_ref(|v, a: &u32| push(v, *a), vec![]),
+             expected);
+  assert_eq!(everything().foldr(|a, v| push(v, a), vec![]),
+             expected.iter().copied().rev().collect::<Vec<_>>());
+  assert_eq!(everything().foldr_ref(|a: &u32, v| push(v, *a), vec![]),
+             expected.iter().copied().rev().collect::<Vec<_>>());
+
+  assert_eq!(Unit::<u8>(PhantomData).foldl(push, vec![]), vec![]);
+}
+
+#[test]
+fn traversable() {
+  let tree = tree().fmap(|n: u32| n.to_string());
+  let parse = |s: String| s.parse::<u32>().ok();
+
+  assert_eq!(tree.clone().traverse::<hkt::Option, _>(parse),
+             Some(self::tree()));
+  assert_eq!(Tree::Node(vec![Tree::Leaf("1".to_string()), Tree::Leaf("x".to_string())])
+               .traverse::<hkt::Option, _>(parse),
+             None);
+
+  let strs = everything().fmap(|n: u32| n.to_string());
+  assert_eq!(strs.traverse::<hkt::Option, _>(parse), Some(everything()));
+
+  let both = Tree::Node(vec![Tree::Leaf(1), Tree::Leaf(2)]).traversemm::<hkt::Vec, _>(|n: u32| {
+                                                             vec![n, n * 10]
+                                                           });
+  assert_eq!(both,
+             vec![Tree::Node(vec![Tree::Leaf(1), Tree::Leaf(2)]),
+                  Tree::Node(vec![Tree::Leaf(1), Tree::Leaf(20)]),
+                  Tree::Node(vec![Tree::Leaf(10), Tree::Leaf(2)]),
+                  Tree::Node(vec![Tree::Leaf(10), Tree::Leaf(20)]),]);
+
+  fn env<A>(vars: HashMap<String, A>) -> Env<A> {
+    Env { name: "env".to_string(),
+          vars }
+  }
+
+  let nums = (0..32u32).map(|n| (n.to_string(), n))
+                       .collect::<HashMap<_, _>>();
+  let strs = nums.clone().fmap(|n: u32| n.to_string());
+
+  assert_eq!(env(strs).traverse::<hkt::Option, _>(parse),
+             Some(env(nums.clone())));
+
+  let strs = (0..4u32).map(|n| (n.to_string(), n.to_string()))
+                      .collect::<HashMap<_, _>>();
+  let both = env(strs).traversemm::<hkt::Vec, _>(|s: String| vec![s.clone(), s + "0"]);
+  assert_eq!(both.len(), 16);
+  assert!(both.iter().all(|env| env.vars
+                                   .iter()
+                                   .all(|(k, v)| *v == *k || *v == k.clone() + "0")));
+}
+
+#[derive(Debug, PartialEq, Semigroup, Monoid)]
+struct Config<T>
+  where T: Clone
+{
+  paths: Vec<T>,
+  name: Option<String>,
+  env: BTreeMap<String, String>,
+}
+
+#[derive(Debug, PartialEq, Semigroup, Monoid)]
+struct Counts(Vec<u8>, (String, Option<String>));
+
+#[derive(Debug, PartialEq, Semigroup, Monoid)]
+struct Nothing;
+
+#[test]
+fn semigroup_monoid() {
+  let a = Config { paths: vec!["a"],
+                   name: None,
+                   env: BTreeMap::from([("A".to_string(), "1".to_string())]) };
+  let b = Config { paths: vec!["b"],
+                   name: Some("b".to_string()),
+                   env: BTreeMap::from([("B".to_string(), "2".to_string())]) };
+
+  assert_eq!(a.append(b),
+             Config { paths: vec!["a", "b"],
+                      name: Some("b".to_string()),
+                      env: BTreeMap::from([("A".to_string(), "1".to_string()),
+                                           ("B".to_string(), "2".to_string())]) });
+  assert_eq!(Config::<&str>::identity(),
+             Config { paths: vec![],
+                      name: None,
+                      env: BTreeMap::new() });
+
+  let counts = Counts(vec![1], ("a".to_string(), None)).append(Counts(vec![2],
+                                                                      ("b".to_string(),
+                                                                       Some("c".to_string()))));
+  assert_eq!(counts,
+             Counts(vec![1, 2], ("ab".to_string(), Some("c".to_string()))));
+  assert_eq!(Counts::identity().append(Counts(vec![1], Monoid::identity())),
+             Counts(vec![1], (String::new(), None)));
+
+  assert_eq!(Nothing.append(Nothing::identity()), Nothing);
+}
@@ -0,0 +1,53 @@
+use crate::prelude::*;
+
+/// [`Foldable`] for types with 2 parameters, collapsing
+/// the values of both types into a single value.
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use naan::prelude::*;
+///
+/// let config = BTreeMap::from([("host", "localhost"), ("port", "8080")]);
+///
+/// let chars = config.bifoldl(|n, k: &str| n + k.len(), |n, v: &str| n + v.len(), 0);
+/// assert_eq!(chars, 21);
+///
+/// let rendered = (1, "one").bifold_map(|n: u8| format!("{n}="), |s: &str| s.to_string());
+/// assert_eq!(rendered, format!("1=one"));
+/// ```
+pub trait Bifoldable<F, A, B>
+  where F: HKT2<T<A, B> = Self>
+{
+  /// Fold the data structure from left -> right
+  fn bifoldl<C, CAC, CBC>(self, fa: CAC, fb: CBC, c: C) -> C
+    where CAC: F2<C, A, Ret = C>,
+          CBC: F2<C, B, Ret = C>;
+
+  /// Fold the data structure from right -> left
+  fn bifoldr<C, ACC, BCC>(self, fa: ACC, fb: BCC, c: C) -> C
+    where ACC: F2<A, C, Ret = C>,
+          BCC: F2<B, C, Ret = C>;
+
+  /// Map both types into the same [`Monoid`], then [`append`](Semigroup::append)
+  /// them from left -> right.
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let r = Result::<u8, &str>::Err("bad");
+  ///
+  /// assert_eq!(r.bifold_map(|n: u8| vec![n as usize], |s: &str| vec![s.len()]),
+  ///            vec![3]);
+  /// ```
+  fn bifold_map<M, AM, BM>(self, fa: AM, fb: BM) -> M
+    where Self: Sized,
+          AM: F1<A, Ret = M>,
+          BM: F1<B, Ret = M>,
+          M: Monoid
+  {
+    self.bifoldl(|m: M, a| m.append(fa.call(a)),
+                 |m: M, b| m.append(fb.call(b)),
+                 M::identity())
+  }
+}
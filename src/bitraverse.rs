@@ -0,0 +1,89 @@
+use crate::prelude::*;
+
+/// [`Traversable`] for types with 2 parameters, running effects
+/// for the values of both types and collecting the results.
+///
+/// Like [`Traversable`], `TF1` and `TF2` are (usually curried) function
+/// types that the implementation needs to store in the [`Applicative`]
+/// while it rebuilds the data structure, and can almost always be inferred.
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use naan::prelude::*;
+///
+/// #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+/// enum Key {
+///   Host,
+///   Port,
+/// }
+///
+/// fn parse_key(s: &str) -> Option<Key> {
+///   match s {
+///     | "host" => Some(Key::Host),
+///     | "port" => Some(Key::Port),
+///     | _ => None,
+///   }
+/// }
+///
+/// fn parse_val(s: &str) -> Option<String> {
+///   Some(s.trim().to_string()).filter(|s| !s.is_empty())
+/// }
+///
+/// let raw = BTreeMap::from([("host", " localhost "), ("port", "8080")]);
+/// assert_eq!(raw.bitraverse::<hkt::Option, _, _>(parse_key, parse_val),
+///            Some(BTreeMap::from([(Key::Host, format!("localhost")),
+///                                 (Key::Port, format!("8080"))])));
+///
+/// let raw = BTreeMap::from([("host", "localhost"), ("user", "root")]);
+/// assert_eq!(raw.bitraverse::<hkt::Option, _, _>(parse_key, parse_val),
+///            None);
+/// ```
+pub trait Bitraversable<F, A, B, A2, B2, TF1, TF2>
+  where F: HKT2<T<A, B> = Self>
+{
+  /// Traverse both types of the data structure, collecting into an
+  /// Applicative of the data structure.
+  fn bitraverse<Ap, FA, FB>(self, fa: FA, fb: FB) -> Ap::T<F::T<A2, B2>>
+    where Ap: HKT1,
+          Ap::T<A2>: Applicative<Ap, A2> + ApplyOnce<Ap, A2>,
+          Ap::T<B2>: Applicative<Ap, B2> + ApplyOnce<Ap, B2>,
+          Ap::T<TF1>: Applicative<Ap, TF1> + ApplyOnce<Ap, TF1>,
+          Ap::T<TF2>: Applicative<Ap, TF2> + ApplyOnce<Ap, TF2>,
+          Ap::T<F::T<A2, B2>>: Applicative<Ap, F::T<A2, B2>> + ApplyOnce<Ap, F::T<A2, B2>>,
+          FA: F1<A, Ret = Ap::T<A2>>,
+          FB: F1<B, Ret = Ap::T<B2>>;
+}
+
+/// Bisequence extends [`Bitraversable`] with a function that inverts a data structure
+/// of Applicatives into an Applicative of a data structure.
+///
+/// e.g. `(Option<A>, Option<B>)` to `Option<(A, B)>`
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// assert_eq!((Some(1), Some("a")).bisequence::<hkt::Option>(),
+///            Some((1, "a")));
+/// assert_eq!((Some(1), None::<&str>).bisequence::<hkt::Option>(), None);
+///
+/// let r: Result<Option<u8>, Option<&str>> = Err(Some("bad"));
+/// assert_eq!(r.bisequence::<hkt::Option>(), Some(Err("bad")));
+/// ```
+pub trait Bisequence<F, A, B, TF1, TF2> {
+  /// See [`Bisequence`]
+  fn bisequence<Ap>(self) -> Ap::T<F::T<A, B>>
+    where Self: Sized + Bitraversable<F, Ap::T<A>, Ap::T<B>, A, B, TF1, TF2>,
+          Ap: HKT1,
+          Ap::T<A>: Applicative<Ap, A> + ApplyOnce<Ap, A>,
+          Ap::T<B>: Applicative<Ap, B> + ApplyOnce<Ap, B>,
+          Ap::T<TF1>: Applicative<Ap, TF1> + ApplyOnce<Ap, TF1>,
+          Ap::T<TF2>: Applicative<Ap, TF2> + ApplyOnce<Ap, TF2>,
+          Ap::T<F::T<A, B>>: Applicative<Ap, F::T<A, B>> + ApplyOnce<Ap, F::T<A, B>>,
+          F: HKT2<T<Ap::T<A>, Ap::T<B>> = Self>
+  {
+    self.bitraverse::<Ap, _, _>(|a| a, |b| b)
+  }
+}
+
+impl<F, A, B, TF1, TF2, T> Bisequence<F, A, B, TF1, TF2> for T {}
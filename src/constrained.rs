@@ -0,0 +1,85 @@
+use crate::prelude::*;
+
+/// [`Functor`] for [`HKT1C`]s, whose elements must satisfy
+/// a [`Constraint`] before they can be mapped into.
+///
+/// ```
+/// use std::collections::BTreeSet;
+///
+/// use naan::prelude::*;
+///
+/// let lengths = BTreeSet::from(["a", "bb", "cc"]).cfmap(|s: &str| s.len());
+///
+/// assert_eq!(lengths, BTreeSet::from([1, 2]));
+/// ```
+pub trait CFunctor<F, A>
+  where F: HKT1C<T<A> = Self>
+{
+  /// Use a function from `A -> B` to transform something
+  /// akin to `F<A>` to something akin to `F<B>`.
+  fn cfmap<AB, B>(self, f: AB) -> F::T<B>
+    where AB: F1<A, Ret = B>,
+          B: Constraint<F>;
+}
+
+/// [`Foldable`] for [`HKT1C`]s
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use naan::prelude::*;
+///
+/// let ages = BTreeMap::from([("bob", 30), ("alice", 29)]);
+///
+/// let names = ages.cfoldl(|names: String, name: &str| names + name, String::new());
+/// assert_eq!(names, format!("alicebob"));
+/// ```
+pub trait CFoldable<F, A>
+  where F: HKT1C<T<A> = Self>
+{
+  /// Fold the data structure from left -> right
+  fn cfoldl<B, BAB>(self, f: BAB, b: B) -> B
+    where BAB: F2<B, A, Ret = B>;
+
+  /// Fold the data structure from right -> left
+  fn cfoldr<B, ABB>(self, f: ABB, b: B) -> B
+    where ABB: F2<A, B, Ret = B>;
+
+  /// Fold the data structure, accumulating the values into a [`Monoid`].
+  fn cfold_map<AB, B>(self, f: AB) -> B
+    where Self: Sized,
+          AB: F1<A, Ret = B>,
+          B: Monoid
+  {
+    self.cfoldl(|b, a| B::append(b, f.call(a)), B::identity())
+  }
+}
+
+/// [`Monad`] for [`HKT1C`]s, whose elements must satisfy
+/// a [`Constraint`] before they can be lifted or bound into.
+///
+/// ```
+/// use std::collections::HashSet;
+///
+/// use naan::prelude::*;
+///
+/// let divisors = |n: u32| (1..=n).filter(|d| n % d == 0).collect::<HashSet<u32>>();
+///
+/// assert_eq!(HashSet::from([4, 6]).cbind(divisors),
+///            HashSet::from([1, 2, 3, 4, 6]));
+/// assert_eq!(HashSet::cpure(1), HashSet::from([1]));
+/// ```
+pub trait CMonad<F, A>
+  where Self: CFunctor<F, A>,
+        F: HKT1C<T<A> = Self>
+{
+  /// Lift `A` to `F<A>`
+  fn cpure(a: A) -> F::T<A>
+    where A: Constraint<F>;
+
+  /// Use a function from `A -> F<B>` to transform something
+  /// akin to `F<A>` to something akin to `F<B>`.
+  fn cbind<B, AMB>(self, f: AMB) -> F::T<B>
+    where AMB: F1<A, Ret = F::T<B>>,
+          B: Constraint<F>;
+}
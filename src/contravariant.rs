@@ -0,0 +1,49 @@
+use crate::prelude::*;
+
+/// Contravariant functors consume values of their type parameter
+/// rather than produce them, so mapping runs "backwards".
+///
+/// Where [`Functor`] lifts `A -> B` to `F<A> -> F<B>`,
+/// `contramap` lifts `B -> A` to `F<A> -> F<B>`; e.g. a
+/// [`Predicate`] on numbers can become a predicate on
+/// anything that can be turned into a number.
+///
+/// Since the function is stored inside the returned
+/// `F<B>`, implementors are free to erase it behind a pointer,
+/// which is why it must outlive `'a` (e.g. the lifetime of
+/// the boxed function in a [`Predicate<'a, A>`](Predicate)).
+///
+/// # Laws
+/// - `contramap` with an identity function (e.g. `|a| a`) should do absolutely nothing.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// struct User {
+///   name: String,
+/// }
+///
+/// let bob = User { name: "bob".into() };
+/// let robert = User { name: "robert".into() };
+///
+/// let short = Predicate::new(|len: usize| len < 5);
+/// let short_name = short.contramap(|u: &User| u.name.len());
+///
+/// assert!(short_name.test(&bob));
+/// assert!(!short_name.test(&robert));
+///
+/// // the function may borrow, as long as it outlives the predicate
+/// let names = vec!["bob".to_string()];
+/// let known = Predicate::new(|known: bool| known).contramap(|u: &User| names.contains(&u.name));
+///
+/// assert!(known.test(&bob));
+/// assert!(!known.test(&robert));
+/// ```
+pub trait Contravariant<'a, F, A>
+  where F: HKT1<T<A> = Self>
+{
+  /// Use a function from `B -> A` to transform an
+  /// `F<A>` to an `F<B>`.
+  fn contramap<BA, B>(self, f: BA) -> F::T<B>
+    where BA: F1<B, Ret = A> + 'a;
+}
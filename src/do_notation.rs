@@ -0,0 +1,316 @@
+// `mdo!` desugars to these functions rather than calling `bind` directly
+// so that the compiler knows the argument type of each closure
+// before checking its body; binds don't need type annotations.
+
+use crate::io::Bind;
+use crate::prelude::*;
+
+/// [`Monad::bind`] for closures
+#[doc(hidden)]
+pub fn bind<M, A, B, MA, AMB>(ma: MA, f: AMB) -> M::T<B>
+  where M: HKT1<T<A> = MA>,
+        MA: Monad<M, A>,
+        AMB: Fn(A) -> M::T<B>
+{
+  ma.bind::<B, AMB>(f)
+}
+
+/// [`MonadOnce::bind1`] for closures
+#[doc(hidden)]
+pub fn bind1<M, A, B, MA, AMB>(ma: MA, f: AMB) -> M::T<B>
+  where M: HKT1<T<A> = MA>,
+        MA: MonadOnce<M, A>,
+        AMB: FnOnce(A) -> M::T<B>
+{
+  ma.bind1::<B, AMB>(f)
+}
+
+/// [`MonadSurrogate::bind_`] for [`IOLike`]s, accepting a closure that
+/// returns any [`IOLike`] rather than exactly [`IO`].
+#[doc(hidden)]
+pub fn bind_io<A, B, IOA, IOB, AMB>(ioa: IOA, f: AMB) -> Bind<AMB, A, B, IOA>
+  where IOA: IOLike<A>,
+        AMB: FnOnce(A) -> IOB,
+        IOB: IOLike<B>
+{
+  Bind::new(f, ioa)
+}
+
+/// [`StateLike::and_then_`] for closures
+#[doc(hidden)]
+pub fn bind_state<S, A, B, SA, SB, AMB>(sa: SA, f: AMB) -> crate::state::Bind<AMB, A, B, SA>
+  where SA: StateLike<S, A>,
+        AMB: FnOnce(A) -> SB,
+        SB: StateLike<S, B>
+{
+  sa.and_then_(f)
+}
+
+/// [`ReaderLike::and_then_`] for closures
+#[doc(hidden)]
+pub fn bind_reader<R, A, B, RA, RB, AMB>(ra: RA, f: AMB) -> crate::reader::Bind<AMB, A, B, RA>
+  where RA: ReaderLike<R, A>,
+        AMB: FnOnce(A) -> RB,
+        RB: ReaderLike<R, B>
+{
+  ra.and_then_(f)
+}
+
+/// [`StateTLike::and_then_`] for closures
+#[doc(hidden)]
+pub fn bind_state_t<M, S, A, B, SA, SB, AMB>(sa: SA,
+                                             f: AMB)
+                                             -> crate::transformer::state_t::Bind<AMB, A, B, SA>
+  where M: MonadHKT,
+        SA: StateTLike<M, S, A>,
+        AMB: Fn(A) -> SB,
+        SB: StateTLike<M, S, B>
+{
+  sa.and_then_(f)
+}
+
+/// [`ReaderTLike::and_then_`] for closures
+#[doc(hidden)]
+pub fn bind_reader_t<M, R, A, B, RA, RB, AMB>(
+  ra: RA,
+  f: AMB)
+  -> crate::transformer::reader_t::Bind<AMB, A, B, RA>
+  where M: MonadHKT,
+        RA: ReaderTLike<M, R, A>,
+        AMB: Fn(A) -> RB,
+        RB: ReaderTLike<M, R, B>
+{
+  ra.and_then_(f)
+}
+
+/// Do-notation for [`Monad`]s
+///
+/// Desugars a sequence of statements into nested binds,
+/// much like Haskell's `do` blocks:
+///  - `x <- expr;` binds the value in the monad `expr` to `x` (also `mut x`, `(a, b)` and `_`)
+///  - `let x = expr;` is a plain `let` binding
+///  - `expr;` is shorthand for `_ <- expr;`
+///  - the last statement is an expression yielding the monad
+///
+/// By default statements are desugared to [`Monad::bind`].
+///
+/// `mdo!(once { .. })` uses [`MonadOnce::bind1`] instead, which allows
+/// the rest of the block to move values out of the scope it closes over.
+///
+/// `mdo!(io { .. })` chains [`IOLike`]s the way [`MonadSurrogate::bind_`] does,
+/// but accepts blocks that end in any [`IOLike`], not only [`IO`].
+///
+/// `mdo!(state { .. })` and `mdo!(reader { .. })` do the same for [`StateLike`]s
+/// and [`ReaderLike`]s with [`StateLike::and_then_`] and [`ReaderLike::and_then_`].
+/// `mdo!(state_t { .. })` and `mdo!(reader_t { .. })` chain [`StateTLike`]s
+/// and [`ReaderTLike`]s the same way.
+///
+/// [`do_!`](crate::do_) is an alias for `mdo!`.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// let sum = mdo! {
+///   a <- Some(1);
+///   b <- Some(2);
+///   let c = a + b;
+///   Some(c * 10)
+/// };
+/// assert_eq!(sum, Some(30));
+///
+/// let pairs = mdo! {
+///   n <- vec![1, 2];
+///   c <- vec!['a', 'b'];
+///   vec![(n, c)]
+/// };
+/// assert_eq!(pairs, vec![(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]);
+/// ```
+///
+/// `_ <- expr` runs `expr` in the monad and discards its result,
+/// in the spirit of [`MonadOnce::discard`]:
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// fn validate(name: &str) -> Result<(), String> {
+///   if name.is_empty() {
+///     Err("name is empty".into())
+///   } else {
+///     Ok(())
+///   }
+/// }
+///
+/// let greet = |name: String| {
+///   naan::do_!(once {
+///     _ <- validate(&name);
+///     (first, rest) <- name.split_once(' ').ok_or(format!("{name} has no last name"));
+///     Ok(format!("hello, {first} of {rest}"))
+///   })
+/// };
+///
+/// assert_eq!(greet("bob ross".into()), Ok("hello, bob of ross".into()));
+/// assert_eq!(greet("".into()), Err("name is empty".into()));
+/// assert_eq!(greet("bob".into()), Err("bob has no last name".into()));
+///
+/// let checked = mdo! {
+///   Some(());
+///   Some(1)
+/// };
+/// assert_eq!(checked, Some(1));
+/// ```
+///
+/// A block that doesn't end with an expression is rejected:
+///
+/// ```compile_fail
+/// use naan::prelude::*;
+///
+/// let nothing: Option<()> = mdo! {
+///   a <- Some(1);
+///   Some(());
+/// };
+/// ```
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// let io = mdo!(io {
+///   a <- IO::pure(1);
+///   b <- IO::suspend(|()| 2);
+///   IO::pure(a + b)
+/// });
+///
+/// assert_eq!(io.exec(), 3);
+/// ```
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// let next_id = || State::suspend(|n: u32| (n, n + 1));
+///
+/// let ids = mdo!(state {
+///   a <- next_id();
+///   b <- next_id();
+///   _ <- State::modify(|n: u32| n * 10);
+///   State::pure((a, b))
+/// });
+///
+/// assert_eq!(ids.run(1), ((1, 2), 30));
+/// ```
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// struct Config {
+///   host: &'static str,
+///   port: u16,
+/// }
+///
+/// let addr = mdo!(reader {
+///   host <- Reader::asks(|c: &Config| c.host);
+///   port <- Reader::asks(|c: &Config| c.port);
+///   Reader::pure(format!("{host}:{port}"))
+/// });
+///
+/// assert_eq!(addr.run(&Config { host: "localhost",
+///                               port: 8080 }),
+///            "localhost:8080");
+/// ```
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// /// Pop the top of a stack, failing when it's empty
+/// fn pop() -> impl StateTLike<hkt::Option, Vec<u8>, u8> {
+///   StateT::suspend(|mut stack: Vec<u8>| stack.pop().map(|n| (n, stack)))
+/// }
+///
+/// let add = || {
+///   mdo!(state_t {
+///     a <- pop();
+///     b <- pop();
+///     StateT::<hkt::Option, Vec<u8>, u8>::lift(Some(a + b))
+///   })
+/// };
+///
+/// assert_eq!(add().run(vec![1, 2, 3]), Some((5, vec![1])));
+/// assert_eq!(add().run(vec![1]), None);
+/// ```
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// let at = |ix: usize| ReaderT::suspend(move |v: &'static [u8]| v.get(ix).copied());
+///
+/// let first_and_last = mdo!(reader_t {
+///   len <- ReaderT::<hkt::Option, &'static [u8], usize>::asks(|v: &'static [u8]| v.len());
+///   first <- at(0);
+///   last <- at(len - 1);
+///   ReaderT::lift(Some((first, last)))
+/// });
+///
+/// assert_eq!(first_and_last.run(&[1, 2, 3]), Some((1, 3)));
+/// ```
+#[macro_export]
+macro_rules! mdo {
+  (@$bind:ident; let $p:pat = $e:expr; $($rest:tt)+) => {{
+    let $p = $e;
+    $crate::mdo!(@$bind; $($rest)+)
+  }};
+  (@$bind:ident; _ <- $e:expr; $($rest:tt)+) => {
+    $crate::do_notation::$bind($e, move |_| $crate::mdo!(@$bind; $($rest)+))
+  };
+  (@$bind:ident; mut $x:ident <- $e:expr; $($rest:tt)+) => {
+    $crate::do_notation::$bind($e, move |mut $x| $crate::mdo!(@$bind; $($rest)+))
+  };
+  (@$bind:ident; $x:ident <- $e:expr; $($rest:tt)+) => {
+    $crate::do_notation::$bind($e, move |$x| $crate::mdo!(@$bind; $($rest)+))
+  };
+  (@$bind:ident; ($($p:tt)*) <- $e:expr; $($rest:tt)+) => {
+    $crate::do_notation::$bind($e, move |($($p)*)| $crate::mdo!(@$bind; $($rest)+))
+  };
+  (@$bind:ident; $e:expr; $($rest:tt)+) => {
+    $crate::do_notation::$bind($e, move |_| $crate::mdo!(@$bind; $($rest)+))
+  };
+  (@$bind:ident; $e:expr) => {
+    $e
+  };
+  (@$bind:ident; $($t:tt)*) => {
+    ::core::compile_error!("expected `pat <- expr;`, `let pat = expr;` or `expr;` statements \
+                            in mdo!, ending with an expression")
+  };
+  (once { $($body:tt)+ }) => {
+    $crate::mdo!(@bind1; $($body)+)
+  };
+  (io { $($body:tt)+ }) => {
+    $crate::mdo!(@bind_io; $($body)+)
+  };
+  (state { $($body:tt)+ }) => {
+    $crate::mdo!(@bind_state; $($body)+)
+  };
+  (reader { $($body:tt)+ }) => {
+    $crate::mdo!(@bind_reader; $($body)+)
+  };
+  (state_t { $($body:tt)+ }) => {
+    $crate::mdo!(@bind_state_t; $($body)+)
+  };
+  (reader_t { $($body:tt)+ }) => {
+    $crate::mdo!(@bind_reader_t; $($body)+)
+  };
+  ($($body:tt)+) => {
+    $crate::mdo!(@bind; $($body)+)
+  };
+}
+
+/// Alias for [`mdo!`](crate::mdo)
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// assert_eq!(naan::do_! { a <- Some(1); Some(a + 1) }, Some(2));
+/// ```
+#[macro_export]
+macro_rules! do_ {
+  ($($t:tt)*) => {
+    $crate::mdo!($($t)*)
+  };
+}
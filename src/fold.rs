@@ -1,3 +1,5 @@
+use core::ops::ControlFlow;
+
 use crate::prelude::*;
 
 /// [`FoldableOnce`] for indexed data structures
@@ -85,6 +87,54 @@ pub trait FoldableIndexed<F, Idx, A>
     where ABB: F3<Idx, &'a A, B, Ret = B>,
           A: 'a,
           Idx: 'a;
+
+  /// Fold the data structure from left -> right, stopping as soon as `f`
+  /// returns [`ControlFlow::Break`].
+  ///
+  /// See [`Foldable::try_foldl`]
+  ///
+  /// ```
+  /// use core::ops::ControlFlow;
+  ///
+  /// use naan::prelude::*;
+  ///
+  /// let first_gap = vec![1, 2, 3, 5, 6].try_foldl_idx(|_, ix, n| {
+  ///                                                     if n == ix + 1 {
+  ///                                                       ControlFlow::Continue(None)
+  ///                                                     } else {
+  ///                                                       ControlFlow::Break(Some(ix + 1))
+  ///                                                     }
+  ///                                                   },
+  ///                                                   None);
+  ///
+  /// assert_eq!(first_gap, ControlFlow::Break(Some(4)));
+  /// ```
+  fn try_foldl_idx<B, BAB>(self, f: BAB, b: B) -> ControlFlow<B, B>
+    where Self: Sized,
+          BAB: F3<B, Idx, A, Ret = ControlFlow<B, B>>
+  {
+    self.foldl_idx(|b: ControlFlow<B, B>, ix, a| match b {
+                     | ControlFlow::Continue(b) => f.call(b, ix, a),
+                     | done => done,
+                   },
+                   ControlFlow::Continue(b))
+  }
+
+  /// Fold the data structure from left -> right, stopping as soon as `f`
+  /// returns [`ControlFlow::Break`].
+  ///
+  /// See [`Foldable::try_foldl`]
+  fn try_foldl_idx_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F3<B, Idx, &'a A, Ret = ControlFlow<B, B>>,
+          A: 'a,
+          Idx: 'a
+  {
+    self.foldl_idx_ref(|b: ControlFlow<B, B>, ix, a| match b {
+                         | ControlFlow::Continue(b) => f.call(b, ix, a),
+                         | done => done,
+                       },
+                       ControlFlow::Continue(b))
+  }
 }
 
 /// Foldable represents data structures which can be collapsed by
@@ -167,6 +217,58 @@ pub trait Foldable<F, A>
     where ABB: F2<&'a A, B, Ret = B>,
           A: 'a;
 
+  /// Fold the data structure from left -> right, stopping as soon as `f`
+  /// returns [`ControlFlow::Break`].
+  ///
+  /// Yields [`ControlFlow::Break`] with the value `f` broke with,
+  /// or [`ControlFlow::Continue`] with the final accumulated value if `f` never broke.
+  ///
+  /// The default implementation is written in terms of [`Foldable::foldl`], and so
+  /// visits every element (without calling `f` after it breaks); the data structures
+  /// in this crate implement it so that the remaining elements are never visited.
+  ///
+  /// ```
+  /// use core::ops::ControlFlow;
+  ///
+  /// use naan::prelude::*;
+  ///
+  /// let sum_until_negative = |ns: Vec<i32>| {
+  ///   ns.try_foldl(|sum, n| {
+  ///                  if n < 0 {
+  ///                    ControlFlow::Break(sum)
+  ///                  } else {
+  ///                    ControlFlow::Continue(sum + n)
+  ///                  }
+  ///                },
+  ///                0)
+  /// };
+  ///
+  /// assert_eq!(sum_until_negative(vec![1, 2, -1, 4]), ControlFlow::Break(3));
+  /// assert_eq!(sum_until_negative(vec![1, 2, 3]), ControlFlow::Continue(6));
+  /// ```
+  fn try_foldl<B, BAB>(self, f: BAB, b: B) -> ControlFlow<B, B>
+    where Self: Sized,
+          BAB: F2<B, A, Ret = ControlFlow<B, B>>
+  {
+    self.foldl(|b: ControlFlow<B, B>, a| match b {
+                 | ControlFlow::Continue(b) => f.call(b, a),
+                 | done => done,
+               },
+               ControlFlow::Continue(b))
+  }
+
+  /// [`Foldable::try_foldl`], borrowing the elements of the data structure
+  fn try_foldl_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F2<B, &'a A, Ret = ControlFlow<B, B>>,
+          A: 'a
+  {
+    self.foldl_ref(|b: ControlFlow<B, B>, a| match b {
+                     | ControlFlow::Continue(b) => f.call(b, a),
+                     | done => done,
+                   },
+                   ControlFlow::Continue(b))
+  }
+
   /// Fold the data structure, accumulating the values into a [`Monoid`].
   ///
   /// ```
@@ -273,19 +375,39 @@ pub trait Foldable<F, A>
 
   /// Test if any element in the structure satisfies a predicate `f`
   ///
+  /// Stops testing elements as soon as one satisfies `f`.
+  ///
   /// ```
+  /// use core::cell::Cell;
+  ///
   /// use naan::prelude::*;
   ///
   /// let strings = vec![format!("ab"), format!("cde")];
   ///
   /// assert_eq!(strings.any(|s: &String| s.len() > 2), true);
+  ///
+  /// let tested = Cell::new(0);
+  /// let found = vec![1, 2, 3, 4].any(|n: &u8| {
+  ///                               tested.set(tested.get() + 1);
+  ///                               *n == 2
+  ///                             });
+  ///
+  /// assert_eq!(found, true);
+  /// assert_eq!(tested.get(), 2);
   /// ```
   fn any<'a, P>(&'a self, f: P) -> bool
     where P: F1<&'a A, Ret = bool>,
           A: 'a
   {
-    self.foldl_ref(|pass: bool, cur| if !pass { f.call(cur) } else { true },
-                   false)
+    self.try_foldl_ref(|(), cur| {
+                         if f.call(cur) {
+                           ControlFlow::Break(())
+                         } else {
+                           ControlFlow::Continue(())
+                         }
+                       },
+                       ())
+        .is_break()
   }
 
   /// Test if every element in the structure satisfies a predicate `f`
@@ -301,7 +423,15 @@ pub trait Foldable<F, A>
     where P: F1<&'a A, Ret = bool>,
           A: 'a
   {
-    self.foldl_ref(|pass: bool, cur| pass && f.call(cur), true)
+    self.try_foldl_ref(|(), cur| {
+                         if f.call(cur) {
+                           ControlFlow::Continue(())
+                         } else {
+                           ControlFlow::Break(())
+                         }
+                       },
+                       ())
+        .is_continue()
   }
 
   /// Get the number of elements contained within the structure
@@ -329,15 +459,37 @@ pub trait Foldable<F, A>
   }
 
   /// Fold values until a match is found
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let strings = vec!["a", "1", "b", "2"];
+  ///
+  /// assert_eq!(strings.find_map(|s: &str| s.parse::<u8>().ok()), Some(1));
+  /// ```
   fn find_map<AB, B>(self, f: AB) -> Option<B>
     where Self: Sized,
           AB: F1<A, Ret = Option<B>>
   {
-    self.foldl(|found: Option<B>, a| found.fmap(Some).get_or(f.call(a)),
-               None)
+    match self.try_foldl(|_, a| match f.call(a) {
+                           | Some(b) => ControlFlow::Break(Some(b)),
+                           | None => ControlFlow::Continue(None),
+                         },
+                         None)
+    {
+      | ControlFlow::Break(found) | ControlFlow::Continue(found) => found,
+    }
   }
 
   /// Fold values until a match is found
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let strings = vec!["a", "bb", "cc"];
+  ///
+  /// assert_eq!(strings.find(|s: &&str| s.len() == 2), Some("bb"));
+  /// ```
   fn find<P>(self, f: P) -> Option<A>
     where Self: Sized,
           P: for<'a> F1<&'a A, Ret = bool>
@@ -345,3 +497,61 @@ pub trait Foldable<F, A>
     self.find_map(|a| Some(a).filter(|a| f.call(a)))
   }
 }
+
+/// [`Foldable`] for data structures that always contain at least one element.
+///
+/// Because there is always a first element to start from, folds
+/// only need a [`Semigroup`] instead of a [`Monoid`].
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// let ns = NonEmptyVec::new(3, vec![1, 2]);
+///
+/// assert_eq!(ns.clone().reduce(i32::min), 1);
+/// assert_eq!(ns.fold1_map(Max), Max(3));
+/// ```
+pub trait Foldable1<F, A>
+  where Self: Foldable<F, A>,
+        F: HKT1<T<A> = Self>
+{
+  /// Fold the data structure from left -> right, using `init`
+  /// to turn the first element into the accumulator `B`.
+  fn foldl1<B, AB, BAB>(self, init: AB, f: BAB) -> B
+    where AB: F1Once<A, Ret = B>,
+          BAB: F2<B, A, Ret = B>;
+
+  /// Map each element to a [`Semigroup`], then [`append`](Semigroup::append)
+  /// them from left -> right.
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let words = NonEmptyVec::new("a", vec!["b", "c"]);
+  ///
+  /// assert_eq!(words.fold1_map(|s: &str| s.to_uppercase()), format!("ABC"));
+  /// ```
+  fn fold1_map<AB, B>(self, f: AB) -> B
+    where Self: Sized,
+          AB: F1<A, Ret = B>,
+          B: Semigroup
+  {
+    self.foldl1(|a| f.call(a), |b: B, a| b.append(f.call(a)))
+  }
+
+  /// Combine the elements from left -> right with `f`
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let ns = NonEmptyVec::new(1, vec![2, 3]);
+  ///
+  /// assert_eq!(ns.reduce(|a, b| a * 10 + b), 123);
+  /// ```
+  fn reduce<AAA>(self, f: AAA) -> A
+    where Self: Sized,
+          AAA: F2<A, A, Ret = A>
+  {
+    self.foldl1(|a| a, f)
+  }
+}
@@ -0,0 +1,280 @@
+use std_alloc::boxed::Box;
+use std_alloc::rc::Rc;
+use std_alloc::sync::Arc;
+
+use super::{curry2, curry3, F1Once, F2Once, F3Once, F1, F2, F3};
+
+/// A type-erased [`F1`]
+///
+/// Useful when a function's concrete type (e.g. a closure, or a
+/// deeply nested [`Compose`](super::compose::Compose)) can't be named,
+/// like in struct fields or collections of different functions.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// struct Handler<'a> {
+///   name: &'static str,
+///   run: BoxF1<'a, u8, u8>,
+/// }
+///
+/// let handlers = vec![Handler { name: "inc",
+///                               run: BoxF1::new(|n: u8| n + 1) },
+///                     Handler { name: "double_dec",
+///                               run: BoxF1::new((|n: u8| n * 2).chain(|n: u8| n - 1)) }];
+///
+/// let results = handlers.iter()
+///                       .map(|h| (h.name, h.run.call(3)))
+///                       .collect::<Vec<_>>();
+///
+/// assert_eq!(results, vec![("inc", 4), ("double_dec", 5)]);
+/// ```
+pub struct BoxF1<'a, A, B>(pub Box<dyn Fn(A) -> B + 'a>);
+
+impl<'a, A, B> BoxF1<'a, A, B> {
+  /// Box a function
+  pub fn new<F>(f: F) -> Self
+    where F: F1<A, Ret = B> + 'a
+  {
+    BoxF1(Box::new(move |a| f.call(a)))
+  }
+}
+
+impl<'a, A, B> F1Once<A> for BoxF1<'a, A, B> {
+  type Ret = B;
+
+  fn call1(self, a: A) -> B {
+    (self.0)(a)
+  }
+}
+
+impl<'a, A, B> F1<A> for BoxF1<'a, A, B> {
+  fn call(&self, a: A) -> B {
+    (self.0)(a)
+  }
+}
+
+/// A type-erased [`F1Once`]
+///
+/// See [`F1Once::boxed`].
+pub struct BoxF1Once<'a, A, B>(pub Box<dyn FnOnce(A) -> B + 'a>);
+
+impl<'a, A, B> BoxF1Once<'a, A, B> {
+  /// Box a function
+  pub fn new<F>(f: F) -> Self
+    where F: F1Once<A, Ret = B> + 'a
+  {
+    BoxF1Once(Box::new(move |a| f.call1(a)))
+  }
+}
+
+impl<'a, A, B> F1Once<A> for BoxF1Once<'a, A, B> {
+  type Ret = B;
+
+  fn call1(self, a: A) -> B {
+    (self.0)(a)
+  }
+}
+
+/// A type-erased [`F1`] that can be cheaply cloned
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// let shout = RcF1::new((|s: &str| s.to_uppercase()).chain(|s: String| s + "!"));
+///
+/// assert_eq!(vec!["hi", "bye"].fmap(shout.clone()),
+///            vec!["HI!".to_string(), "BYE!".to_string()]);
+/// assert_eq!(shout.call("ok"), "OK!");
+/// ```
+pub struct RcF1<'a, A, B>(pub Rc<dyn Fn(A) -> B + 'a>);
+
+impl<'a, A, B> RcF1<'a, A, B> {
+  /// Wrap a function in an [`Rc`]
+  pub fn new<F>(f: F) -> Self
+    where F: F1<A, Ret = B> + 'a
+  {
+    RcF1(Rc::new(move |a| f.call(a)))
+  }
+}
+
+impl<'a, A, B> Clone for RcF1<'a, A, B> {
+  fn clone(&self) -> Self {
+    RcF1(Rc::clone(&self.0))
+  }
+}
+
+impl<'a, A, B> F1Once<A> for RcF1<'a, A, B> {
+  type Ret = B;
+
+  fn call1(self, a: A) -> B {
+    (self.0)(a)
+  }
+}
+
+impl<'a, A, B> F1<A> for RcF1<'a, A, B> {
+  fn call(&self, a: A) -> B {
+    (self.0)(a)
+  }
+}
+
+/// A type-erased [`F1`] that can be cheaply cloned
+/// and shared across threads
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// let double = ArcF1::new(|n: u32| n * 2);
+///
+/// let handle = std::thread::spawn({
+///   let double = double.clone();
+///   move || double.call(21)
+/// });
+///
+/// assert_eq!(handle.join().unwrap(), 42);
+/// assert_eq!(double.call(1), 2);
+/// ```
+pub struct ArcF1<'a, A, B>(pub Arc<dyn Fn(A) -> B + Send + Sync + 'a>);
+
+impl<'a, A, B> ArcF1<'a, A, B> {
+  /// Wrap a function in an [`Arc`]
+  pub fn new<F>(f: F) -> Self
+    where F: F1<A, Ret = B> + Send + Sync + 'a
+  {
+    ArcF1(Arc::new(move |a| f.call(a)))
+  }
+}
+
+impl<'a, A, B> Clone for ArcF1<'a, A, B> {
+  fn clone(&self) -> Self {
+    ArcF1(Arc::clone(&self.0))
+  }
+}
+
+impl<'a, A, B> F1Once<A> for ArcF1<'a, A, B> {
+  type Ret = B;
+
+  fn call1(self, a: A) -> B {
+    (self.0)(a)
+  }
+}
+
+impl<'a, A, B> F1<A> for ArcF1<'a, A, B> {
+  fn call(&self, a: A) -> B {
+    (self.0)(a)
+  }
+}
+
+macro_rules! boxed_fn {
+  (
+    $(#[$m:meta])*
+    $box_f:ident $f:ident;
+    $(#[$m_once:meta])*
+    $box_fonce:ident $fonce:ident;
+    $curry_mod:ident ($($a:ident: $A:ident),+) -> $R:ident
+  ) => {
+    $(#[$m])*
+    pub struct $box_f<'a, $($A,)+ $R>(pub Box<dyn Fn($($A),+) -> $R + 'a>);
+
+    impl<'a, $($A,)+ $R> $box_f<'a, $($A,)+ $R> {
+      /// Box a function
+      pub fn new<F>(f: F) -> Self
+        where F: $f<$($A),+, Ret = $R> + 'a
+      {
+        $box_f(Box::new(move |$($a),+| f.call($($a),+)))
+      }
+    }
+
+    impl<'a, $($A,)+ $R> $fonce<$($A),+> for $box_f<'a, $($A,)+ $R> {
+      type Ret = $R;
+      type Curried = $curry_mod::Applied0<Self, $($A,)+ $R>;
+
+      fn call1(self, $($a: $A),+) -> $R {
+        (self.0)($($a),+)
+      }
+
+      fn curry(self) -> Self::Curried {
+        $curry_mod::Applied0::curry(self)
+      }
+    }
+
+    impl<'a, $($A,)+ $R> $f<$($A),+> for $box_f<'a, $($A,)+ $R> {
+      fn call(&self, $($a: $A),+) -> $R {
+        (self.0)($($a),+)
+      }
+    }
+
+    $(#[$m_once])*
+    pub struct $box_fonce<'a, $($A,)+ $R>(pub Box<dyn FnOnce($($A),+) -> $R + 'a>);
+
+    impl<'a, $($A,)+ $R> $box_fonce<'a, $($A,)+ $R> {
+      /// Box a function
+      pub fn new<F>(f: F) -> Self
+        where F: $fonce<$($A),+, Ret = $R> + 'a
+      {
+        $box_fonce(Box::new(move |$($a),+| f.call1($($a),+)))
+      }
+    }
+
+    impl<'a, $($A,)+ $R> $fonce<$($A),+> for $box_fonce<'a, $($A,)+ $R> {
+      type Ret = $R;
+      type Curried = $curry_mod::Applied0<Self, $($A,)+ $R>;
+
+      fn call1(self, $($a: $A),+) -> $R {
+        (self.0)($($a),+)
+      }
+
+      fn curry(self) -> Self::Curried {
+        $curry_mod::Applied0::curry(self)
+      }
+    }
+  };
+}
+
+boxed_fn! {
+  /// A type-erased [`F2`]
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let ops: Vec<(&str, BoxF2<u8, u8, u8>)> = vec![("add", BoxF2::new(|a: u8, b: u8| a + b)),
+  ///                                                ("max", BoxF2::new(u8::max))];
+  ///
+  /// assert_eq!(ops.iter().map(|(_, f)| f.call(2, 3)).collect::<Vec<_>>(),
+  ///            vec![5, 3]);
+  /// assert_eq!(BoxF2::new(|a: u8, b: u8| a - b).curry().call1(3).call1(1), 2);
+  /// ```
+  BoxF2 F2;
+  /// A type-erased [`F2Once`]
+  BoxF2Once F2Once;
+  curry2 (a: A, b: B) -> C
+}
+
+boxed_fn! {
+  /// A type-erased [`F3`]
+  BoxF3 F3;
+  /// A type-erased [`F3Once`]
+  BoxF3Once F3Once;
+  curry3 (a: A, b: B, c: C) -> D
+}
+
+macro_rules! debug_opaque {
+  ($($t:ident<$($p:ident),+>),+) => {
+    $(
+      impl<'a, $($p),+> core::fmt::Debug for $t<'a, $($p),+> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+          f.write_str(concat!(stringify!($t), "(..)"))
+        }
+      }
+    )+
+  };
+}
+
+debug_opaque!(BoxF1<A, B>,
+              BoxF1Once<A, B>,
+              RcF1<A, B>,
+              ArcF1<A, B>,
+              BoxF2<A, B, C>,
+              BoxF2Once<A, B, C>,
+              BoxF3<A, B, C, D>,
+              BoxF3Once<A, B, C, D>);
@@ -0,0 +1,274 @@
+use super::{curry2,
+            curry3,
+            F1Once,
+            F2Once,
+            F3Once,
+            F4Once,
+            F5Once,
+            F6Once,
+            F7Once,
+            F8Once,
+            F1,
+            F2,
+            F3,
+            F4,
+            F5,
+            F6,
+            F7,
+            F8};
+
+/// A function that returns its argument
+///
+/// Unlike a generic `fn identity<A>(a: A) -> A`, this is a
+/// nameable type and so can be stored in structs and returned from functions.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// assert_eq!(Some(1).fmap(Identity), Some(1));
+///
+/// let len = (|s: &str| s.len()).chain(Identity);
+/// assert_eq!(len.call("abc"), 3);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Identity;
+
+impl<A> F1Once<A> for Identity {
+  type Ret = A;
+
+  fn call1(self, a: A) -> A {
+    a
+  }
+}
+
+impl<A> F1<A> for Identity {
+  fn call(&self, a: A) -> A {
+    a
+  }
+}
+
+/// Struct returned by [`const_`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Const<A>(A);
+
+/// Create a function that ignores its argument, always returning `a`
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// assert_eq!(vec![1, 2, 3].fmap(const_("hi")), vec!["hi", "hi", "hi"]);
+/// ```
+pub fn const_<A>(a: A) -> Const<A> {
+  Const(a)
+}
+
+impl<A, X> F1Once<X> for Const<A> {
+  type Ret = A;
+
+  fn call1(self, _: X) -> A {
+    self.0
+  }
+}
+
+impl<A, X> F1<X> for Const<A> where A: Clone
+{
+  fn call(&self, _: X) -> A {
+    self.0.clone()
+  }
+}
+
+/// Struct returned by [`flip`]
+#[derive(Debug, Clone, Copy)]
+pub struct Flip<F>(F);
+
+/// Swap the first two arguments of a function of 2 or 3 arguments
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// let repeat = |s: &str, n: usize| s.repeat(n);
+/// assert_eq!(flip(repeat).call(3, "ab"), "ababab");
+///
+/// let clamp = |n: u8, min: u8, max: u8| n.clamp(min, max);
+/// let at_least_10 = flip(clamp).curry().call(10);
+/// assert_eq!(at_least_10.call(5).call(20), 10);
+/// ```
+pub fn flip<F>(f: F) -> Flip<F> {
+  Flip(f)
+}
+
+impl<F, A, B> F2Once<A, B> for Flip<F> where F: F2Once<B, A>
+{
+  type Ret = F::Ret;
+  type Curried = curry2::Applied0<Self, A, B, F::Ret>;
+
+  fn call1(self, a: A, b: B) -> F::Ret {
+    self.0.call1(b, a)
+  }
+
+  fn curry(self) -> Self::Curried {
+    curry2::Applied0::curry(self)
+  }
+}
+
+impl<F, A, B> F2<A, B> for Flip<F> where F: F2<B, A>
+{
+  fn call(&self, a: A, b: B) -> F::Ret {
+    self.0.call(b, a)
+  }
+}
+
+impl<F, A, B, C> F3Once<A, B, C> for Flip<F> where F: F3Once<B, A, C>
+{
+  type Ret = F::Ret;
+  type Curried = curry3::Applied0<Self, A, B, C, F::Ret>;
+
+  fn call1(self, a: A, b: B, c: C) -> F::Ret {
+    self.0.call1(b, a, c)
+  }
+
+  fn curry(self) -> Self::Curried {
+    curry3::Applied0::curry(self)
+  }
+}
+
+impl<F, A, B, C> F3<A, B, C> for Flip<F> where F: F3<B, A, C>
+{
+  fn call(&self, a: A, b: B, c: C) -> F::Ret {
+    self.0.call(b, a, c)
+  }
+}
+
+/// Struct returned by [`on`]
+#[derive(Debug, Clone, Copy)]
+pub struct On<F, K>(F, K);
+
+/// Create a binary function that applies `key` to both arguments
+/// before passing them to `f`
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// let same_len = on(|a: usize, b: usize| a == b, str::len);
+///
+/// assert!(same_len.call("abc", "def"));
+/// assert!(!same_len.call("abc", "de"));
+/// ```
+pub fn on<F, K>(f: F, key: K) -> On<F, K> {
+  On(f, key)
+}
+
+impl<F, K, A, B> F2Once<A, A> for On<F, K>
+  where K: F1<A, Ret = B>,
+        F: F2Once<B, B>
+{
+  type Ret = F::Ret;
+  type Curried = curry2::Applied0<Self, A, A, F::Ret>;
+
+  fn call1(self, a: A, b: A) -> F::Ret {
+    let (a, b) = (self.1.call(a), self.1.call(b));
+    self.0.call1(a, b)
+  }
+
+  fn curry(self) -> Self::Curried {
+    curry2::Applied0::curry(self)
+  }
+}
+
+impl<F, K, A, B> F2<A, A> for On<F, K>
+  where K: F1<A, Ret = B>,
+        F: F2<B, B>
+{
+  fn call(&self, a: A, b: A) -> F::Ret {
+    self.0.call(self.1.call(a), self.1.call(b))
+  }
+}
+
+/// Struct returned by [`uncurry`]
+#[derive(Debug, Clone, Copy)]
+pub struct Uncurry<F>(F);
+
+/// Transform a function of 2 to 8 arguments into
+/// a function that accepts them as a tuple
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// let add = |a: u8, b: u8| a + b;
+/// assert_eq!(vec![(1, 2), (3, 4)].fmap(uncurry(add)), vec![3, 7]);
+///
+/// let clamp = |n: u8, min: u8, max: u8| n.clamp(min, max);
+/// assert_eq!(Some((20, 0, 10)).fmap(uncurry(clamp)), Some(10));
+/// ```
+pub fn uncurry<F>(f: F) -> Uncurry<F> {
+  Uncurry(f)
+}
+
+macro_rules! uncurry_impls {
+  ($($fonce:ident $f:ident ($($a:ident: $A:ident),+);)+) => {
+    $(
+      impl<F, $($A),+> F1Once<($($A,)+)> for Uncurry<F> where F: $fonce<$($A),+>
+      {
+        type Ret = F::Ret;
+
+        fn call1(self, ($($a,)+): ($($A,)+)) -> F::Ret {
+          self.0.call1($($a),+)
+        }
+      }
+
+      impl<F, $($A),+> F1<($($A,)+)> for Uncurry<F> where F: $f<$($A),+>
+      {
+        fn call(&self, ($($a,)+): ($($A,)+)) -> F::Ret {
+          self.0.call($($a),+)
+        }
+      }
+    )+
+  };
+}
+
+uncurry_impls! {
+  F2Once F2 (a: A, b: B);
+  F3Once F3 (a: A, b: B, c: C);
+  F4Once F4 (a: A, b: B, c: C, d: D);
+  F5Once F5 (a: A, b: B, c: C, d: D, e: E);
+  F6Once F6 (a: A, b: B, c: C, d: D, e: E, g: G);
+  F7Once F7 (a: A, b: B, c: C, d: D, e: E, g: G, h: H);
+  F8Once F8 (a: A, b: B, c: C, d: D, e: E, g: G, h: H, i: I);
+}
+
+/// Struct returned by [`apply_to`]
+#[derive(Debug, Clone, Copy)]
+pub struct ApplyTo<A>(A);
+
+/// Create a function that calls its argument with `a`
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// let double = |n: u8| n * 2;
+/// assert_eq!(Some(double).fmap(apply_to(4)), Some(8));
+///
+/// let fs: Vec<fn(u8) -> u8> = vec![|n| n + 1, |n| n * 2];
+/// assert_eq!(fs.fmap(apply_to(4)), vec![5, 8]);
+/// ```
+pub fn apply_to<A>(a: A) -> ApplyTo<A> {
+  ApplyTo(a)
+}
+
+impl<A, F> F1Once<F> for ApplyTo<A> where F: F1Once<A>
+{
+  type Ret = F::Ret;
+
+  fn call1(self, f: F) -> F::Ret {
+    f.call1(self.0)
+  }
+}
+
+impl<A, F> F1<F> for ApplyTo<A>
+  where F: F1Once<A>,
+        A: Clone
+{
+  fn call(&self, f: F) -> F::Ret {
+    f.call1(self.0.clone())
+  }
+}
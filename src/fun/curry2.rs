@@ -1,7 +1,7 @@
 use core::marker::PhantomData;
 
 use super::arg::Arg;
-use super::{F1Once, F2Once, Just, Nothing, F1};
+use super::{F1Once, F2Once, Just, Nothing, F1, F2};
 
 /// A curried function that accepts 2 arguments and has not been called with either.
 pub type Applied0<F, A, B, C> = Curry2<F, Nothing<A>, Nothing<B>, C>;
@@ -19,7 +19,7 @@ pub struct Curry2<F, A, B, C> {
 impl<A, B, C, F> Clone for Curry2<F, A, B, C>
   where A: Arg + Clone,
         B: Arg,
-        F: Clone + Fn(A::T, B::T) -> C
+        F: Clone + F2<A::T, B::T, Ret = C>
 {
   fn clone(&self) -> Self {
     Curry2 { f: self.f.clone(),
@@ -31,7 +31,7 @@ impl<A, B, C, F> Clone for Curry2<F, A, B, C>
 impl<A, B, C, F> Copy for Curry2<F, A, B, C>
   where A: Arg + Copy,
         B: Arg,
-        F: Copy + Fn(A::T, B::T) -> C
+        F: Copy + F2<A::T, B::T, Ret = C>
 {
 }
 
@@ -50,7 +50,7 @@ impl<F, A, B, C> Applied0<F, A, B, C> where F: F2Once<A, B, Ret = C>
   }
 }
 
-impl<F, A, B, C> F1<A> for Applied0<F, A, B, C> where F: Clone + Fn(A, B) -> C
+impl<F, A, B, C> F1<A> for Applied0<F, A, B, C> where F: Clone + F2<A, B, Ret = C>
 {
   fn call(&self, a: A) -> Applied1<F, A, B, C> {
     Applied1::<F, A, B, C> { a: Just(a),
@@ -59,7 +59,7 @@ impl<F, A, B, C> F1<A> for Applied0<F, A, B, C> where F: Clone + Fn(A, B) -> C
   }
 }
 
-impl<F, A, B, C> F1Once<A> for Applied0<F, A, B, C> where F: FnOnce(A, B) -> C
+impl<F, A, B, C> F1Once<A> for Applied0<F, A, B, C> where F: F2Once<A, B, Ret = C>
 {
   type Ret = Applied1<F, A, B, C>;
   fn call1(self, a: A) -> Applied1<F, A, B, C> {
@@ -70,18 +70,18 @@ impl<F, A, B, C> F1Once<A> for Applied0<F, A, B, C> where F: FnOnce(A, B) -> C
 }
 
 impl<F, A, B, C> F1<B> for Applied1<F, A, B, C>
-  where F: Fn(A, B) -> C,
+  where F: F2<A, B, Ret = C>,
         A: Clone
 {
   fn call(&self, b: B) -> C {
-    (self.f)(self.a.0.clone(), b)
+    self.f.call(self.a.0.clone(), b)
   }
 }
 
-impl<F, A, B, C> F1Once<B> for Applied1<F, A, B, C> where F: FnOnce(A, B) -> C
+impl<F, A, B, C> F1Once<B> for Applied1<F, A, B, C> where F: F2Once<A, B, Ret = C>
 {
   type Ret = C;
   fn call1(self, b: B) -> C {
-    (self.f)(self.a.0, b)
+    F2Once::call1(self.f, self.a.0, b)
   }
 }
@@ -1,7 +1,7 @@
 use core::marker::PhantomData;
 
 use super::arg::Arg;
-use super::{F1Once, F3Once, Just, Nothing, F1};
+use super::{F1Once, F3Once, Just, Nothing, F1, F3};
 
 /// A curried function that accepts 3 arguments and has not been called with either.
 pub type Applied0<F, A, B, C, D> = Curry3<F, Nothing<A>, Nothing<B>, Nothing<C>, D>;
@@ -21,7 +21,7 @@ pub struct Curry3<F, A, B, C, D> {
 }
 
 impl<F, A, B, C, D> Clone for Curry3<F, A, B, C, D>
-  where F: Clone + FnOnce(A::T, B::T) -> C::T,
+  where F: Clone,
         A: Arg + Clone,
         B: Arg + Clone,
         C: Arg
@@ -50,7 +50,7 @@ impl<F, A, B, C, D> Applied0<F, A, B, C, D> where F: F3Once<A, B, C, Ret = D>
   }
 }
 
-impl<F, A, B, C, D> F1<A> for Applied0<F, A, B, C, D> where F: Clone + Fn(A, B, C) -> D
+impl<F, A, B, C, D> F1<A> for Applied0<F, A, B, C, D> where F: Clone + F3<A, B, C, Ret = D>
 {
   fn call(&self, a: A) -> Applied1<F, A, B, C, D> {
     Applied1::<F, A, B, C, D> { a: Just(a),
@@ -60,7 +60,7 @@ impl<F, A, B, C, D> F1<A> for Applied0<F, A, B, C, D> where F: Clone + Fn(A, B,
   }
 }
 
-impl<F, A, B, C, D> F1Once<A> for Applied0<F, A, B, C, D> where F: FnOnce(A, B, C) -> D
+impl<F, A, B, C, D> F1Once<A> for Applied0<F, A, B, C, D> where F: F3Once<A, B, C, Ret = D>
 {
   type Ret = Applied1<F, A, B, C, D>;
   fn call1(self, a: A) -> Applied1<F, A, B, C, D> {
@@ -72,7 +72,7 @@ impl<F, A, B, C, D> F1Once<A> for Applied0<F, A, B, C, D> where F: FnOnce(A, B,
 }
 
 impl<F, A, B, C, D> F1<B> for Applied1<F, A, B, C, D>
-  where F: Fn(A, B, C) -> D + Clone,
+  where F: Clone + F3<A, B, C, Ret = D>,
         A: Clone
 {
   fn call(&self, b: B) -> Applied2<F, A, B, C, D> {
@@ -83,7 +83,7 @@ impl<F, A, B, C, D> F1<B> for Applied1<F, A, B, C, D>
   }
 }
 
-impl<F, A, B, C, D> F1Once<B> for Applied1<F, A, B, C, D> where F: FnOnce(A, B, C) -> D
+impl<F, A, B, C, D> F1Once<B> for Applied1<F, A, B, C, D> where F: F3Once<A, B, C, Ret = D>
 {
   type Ret = Applied2<F, A, B, C, D>;
   fn call1(self, b: B) -> Applied2<F, A, B, C, D> {
@@ -95,19 +95,19 @@ impl<F, A, B, C, D> F1Once<B> for Applied1<F, A, B, C, D> where F: FnOnce(A, B,
 }
 
 impl<F, A, B, C, D> F1<C> for Applied2<F, A, B, C, D>
-  where F: Fn(A, B, C) -> D,
+  where F: F3<A, B, C, Ret = D>,
         A: Clone,
         B: Clone
 {
   fn call(&self, c: C) -> D {
-    (self.f)(self.a.clone().0, self.b.clone().0, c)
+    self.f.call(self.a.0.clone(), self.b.0.clone(), c)
   }
 }
 
-impl<F, A, B, C, D> F1Once<C> for Applied2<F, A, B, C, D> where F: FnOnce(A, B, C) -> D
+impl<F, A, B, C, D> F1Once<C> for Applied2<F, A, B, C, D> where F: F3Once<A, B, C, Ret = D>
 {
   type Ret = D;
   fn call1(self, c: C) -> D {
-    (self.f)(self.a.0, self.b.0, c)
+    F3Once::call1(self.f, self.a.0, self.b.0, c)
   }
 }
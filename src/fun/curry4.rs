@@ -0,0 +1,4 @@
+curry! {
+  4 Curry4 F4Once F4 (a: A, b: B, c: C, d: D) -> E;
+  0 Applied0, 1 Applied1, 2 Applied2, 3 Applied3
+}
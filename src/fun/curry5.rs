@@ -0,0 +1,4 @@
+curry! {
+  5 Curry5 F5Once F5 (a: A, b: B, c: C, d: D, e: E) -> G;
+  0 Applied0, 1 Applied1, 2 Applied2, 3 Applied3, 4 Applied4
+}
@@ -0,0 +1,4 @@
+curry! {
+  6 Curry6 F6Once F6 (a: A, b: B, c: C, d: D, e: E, g: G) -> H;
+  0 Applied0, 1 Applied1, 2 Applied2, 3 Applied3, 4 Applied4, 5 Applied5
+}
@@ -0,0 +1,4 @@
+curry! {
+  7 Curry7 F7Once F7 (a: A, b: B, c: C, d: D, e: E, g: G, h: H) -> I;
+  0 Applied0, 1 Applied1, 2 Applied2, 3 Applied3, 4 Applied4, 5 Applied5, 6 Applied6
+}
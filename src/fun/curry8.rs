@@ -0,0 +1,4 @@
+curry! {
+  8 Curry8 F8Once F8 (a: A, b: B, c: C, d: D, e: E, g: G, h: H, i: I) -> J;
+  0 Applied0, 1 Applied1, 2 Applied2, 3 Applied3, 4 Applied4, 5 Applied5, 6 Applied6, 7 Applied7
+}
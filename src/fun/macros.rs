@@ -0,0 +1,190 @@
+/// Generate the body of a `curryN` module; the `CurryN` struct,
+/// an `AppliedK` alias for each number of arguments applied,
+/// and `F1`/`F1Once` for each alias.
+///
+/// Each step moves one argument from the "not applied" list to
+/// the "applied" list, so the last step is the one that calls the function.
+macro_rules! curry {
+  (
+    $n:literal $curry:ident $fonce:ident $f:ident ($($a:ident: $A:ident),+) -> $R:ident;
+    $($k:literal $alias:ident),+
+  ) => {
+    use core::marker::PhantomData;
+
+    use super::{$f, $fonce, F1Once, Just, Nothing, F1};
+
+    #[doc = concat!("A curried function that accepts ", $n, " arguments")]
+    pub struct $curry<F, $($A,)+ $R> {
+      f: F,
+      $($a: $A,)+
+      _r: PhantomData<$R>,
+    }
+
+    impl<F, $($A,)+ $R> Clone for $curry<F, $($A,)+ $R>
+      where F: Clone,
+            $($A: Clone),+
+    {
+      fn clone(&self) -> Self {
+        $curry { f: self.f.clone(),
+                 $($a: self.$a.clone(),)+
+                 _r: PhantomData }
+      }
+    }
+
+    impl<F, $($A,)+ $R> Copy for $curry<F, $($A,)+ $R>
+      where F: Copy,
+            $($A: Copy),+
+    {
+    }
+
+    curry!(@step $n $curry $fonce $f ($($A),+) -> $R;
+           [] [$($a: $A),+];
+           $($k $alias),+);
+
+    impl<F, $($A,)+ $R> Applied0<F, $($A,)+ $R> where F: $fonce<$($A),+, Ret = $R>
+    {
+      #[doc = concat!("Curry a function of ", $n, " arguments")]
+      pub fn curry(f: F) -> Self {
+        $curry { f,
+                 $($a: Nothing::new(),)+
+                 _r: PhantomData }
+      }
+
+      #[doc = concat!("Unwrap the `", stringify!($curry), "` wrapper, getting the inner function")]
+      pub fn uncurry(self) -> F {
+        self.f
+      }
+    }
+  };
+  (
+    @step $n:literal $curry:ident $fonce:ident $f:ident ($($A:ident),+) -> $R:ident;
+    [$($d:ident: $D:ident),*] [$next:ident: $N:ident];
+    $k:literal $alias:ident
+  ) => {
+    #[doc = concat!("A curried function that accepts ", $n, " arguments and has been called with ", $k, " of them.")]
+    pub type $alias<F, $($A,)+ $R> = $curry<F, $(Just<$D>,)* Nothing<$N>, $R>;
+
+    impl<F, $($A,)+ $R> F1<$N> for $alias<F, $($A,)+ $R>
+      where F: $f<$($A),+, Ret = $R>,
+            $($D: Clone),*
+    {
+      fn call(&self, $next: $N) -> $R {
+        self.f.call($(self.$d.0.clone(),)* $next)
+      }
+    }
+
+    impl<F, $($A,)+ $R> F1Once<$N> for $alias<F, $($A,)+ $R> where F: $fonce<$($A),+, Ret = $R>
+    {
+      type Ret = $R;
+
+      fn call1(self, $next: $N) -> $R {
+        $fonce::call1(self.f, $(self.$d.0,)* $next)
+      }
+    }
+  };
+  (
+    @step $n:literal $curry:ident $fonce:ident $f:ident ($($A:ident),+) -> $R:ident;
+    [$($d:ident: $D:ident),*] [$next:ident: $N:ident, $($rest:ident: $RT:ident),+];
+    $k:literal $alias:ident, $k2:literal $alias2:ident $(, $ks:literal $aliases:ident)*
+  ) => {
+    #[doc = concat!("A curried function that accepts ", $n, " arguments and has been called with ", $k, " of them.")]
+    pub type $alias<F, $($A,)+ $R> = $curry<F, $(Just<$D>,)* Nothing<$N>, $(Nothing<$RT>,)+ $R>;
+
+    impl<F, $($A,)+ $R> F1<$N> for $alias<F, $($A,)+ $R>
+      where F: Clone + $f<$($A),+, Ret = $R>,
+            $($D: Clone),*
+    {
+      fn call(&self, $next: $N) -> $alias2<F, $($A,)+ $R> {
+        $curry { f: self.f.clone(),
+                 $($d: self.$d.clone(),)*
+                 $next: Just($next),
+                 $($rest: Nothing::new(),)+
+                 _r: PhantomData }
+      }
+    }
+
+    impl<F, $($A,)+ $R> F1Once<$N> for $alias<F, $($A,)+ $R> where F: $fonce<$($A),+, Ret = $R>
+    {
+      type Ret = $alias2<F, $($A,)+ $R>;
+
+      fn call1(self, $next: $N) -> $alias2<F, $($A,)+ $R> {
+        $curry { f: self.f,
+                 $($d: self.$d,)*
+                 $next: Just($next),
+                 $($rest: Nothing::new(),)+
+                 _r: PhantomData }
+      }
+    }
+
+    curry!(@step $n $curry $fonce $f ($($A),+) -> $R;
+           [$($d: $D,)* $next: $N] [$($rest: $RT),+];
+           $k2 $alias2 $(, $ks $aliases)*);
+  };
+}
+
+/// Generate `FnOnce`, `Fn` traits for some number of arguments,
+/// along with their blanket impls for closures.
+macro_rules! fn_traits {
+  ($n:literal $fonce:ident $f:ident $curry_mod:ident ($($a:ident: $A:ident),+) -> $R:ident) => {
+    #[doc = concat!("A function that accepts ", $n, " arguments\nand can be called at most once.")]
+    pub trait $fonce<$($A),+>: Sized {
+      /// The type returned by this function
+      type Ret;
+
+      /// The concrete type that `curry` returns.
+      type Curried;
+
+      /// Call the function
+      #[allow(clippy::too_many_arguments)]
+      fn call1(self, $($a: $A),+) -> Self::Ret;
+
+      /// Curry this function, transforming it from
+      #[doc = concat!("`fn(", stringify!($($A),+), ") -> ", stringify!($R), "`")]
+      /// to a chain of functions that each accept 1 argument.
+      fn curry(self) -> Self::Curried;
+    }
+
+    #[doc = concat!("A function that accepts ", $n, " arguments\nand can be called any number of times.")]
+    pub trait $f<$($A),+>: $fonce<$($A),+> {
+      /// Call the function with all arguments
+      #[allow(clippy::too_many_arguments)]
+      fn call(&self, $($a: $A),+) -> Self::Ret;
+    }
+
+    impl<F, $($A,)+ $R> $f<$($A),+> for F where F: Fn($($A),+) -> $R
+    {
+      #[allow(clippy::too_many_arguments)]
+      fn call(&self, $($a: $A),+) -> $R {
+        self($($a),+)
+      }
+    }
+
+    impl<F, $($A,)+ $R> $fonce<$($A),+> for F where F: FnOnce($($A),+) -> $R
+    {
+      type Ret = $R;
+      type Curried = $curry_mod::Applied0<Self, $($A,)+ $R>;
+
+      #[allow(clippy::too_many_arguments)]
+      fn call1(self, $($a: $A),+) -> $R {
+        self($($a),+)
+      }
+
+      fn curry(self) -> Self::Curried {
+        $curry_mod::Applied0::curry(self)
+      }
+    }
+  };
+}
+
+/// Generate a `tupleN` function
+macro_rules! tuple_fn {
+  ($name:ident $n:literal ($($a:ident: $A:ident),+)) => {
+    #[doc = concat!("Create a ", $n, "-tuple")]
+    ///
+    /// See [`tuple2`] for an example.
+    #[allow(clippy::too_many_arguments)]
+    pub fn $name<$($A),+>($($a: $A),+) -> ($($A,)+) {
+      ($($a,)+)
+    }
+  };
+}
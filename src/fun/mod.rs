@@ -6,15 +6,59 @@ use curry3::Curry3;
 
 use self::compose::Compose;
 
+#[macro_use]
+mod macros;
+
+/// Type-erased functions
+#[cfg(feature = "alloc")]
+pub mod boxed;
+
 /// Function composition
 pub mod compose;
 
+/// flip, const_, Identity, on, uncurry, apply_to
+pub mod combinators;
+
 /// Currying functions with 2 arguments
 pub mod curry2;
 
 /// Currying functions with 3 arguments
 pub mod curry3;
 
+/// Currying functions with 4 arguments
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// fn connect(host: &str, port: u16, secure: bool, retries: u8) -> String {
+///   format!("{host}:{port} secure={secure} retries={retries}")
+/// }
+///
+/// let localhost = connect.curry().call("localhost");
+/// let http = localhost.call(80).call(false);
+///
+/// // partially applied functions are `Copy` when the function and arguments are
+/// let (a, b) = (http, http);
+///
+/// assert_eq!(a.call(0), "localhost:80 secure=false retries=0");
+/// assert_eq!(b.call(3), "localhost:80 secure=false retries=3");
+/// assert_eq!(connect.curry().uncurry()("example.com", 443, true, 1),
+///            "example.com:443 secure=true retries=1");
+/// ```
+pub mod curry4;
+
+/// Currying functions with 5 arguments
+pub mod curry5;
+
+/// Currying functions with 6 arguments
+pub mod curry6;
+
+/// Currying functions with 7 arguments
+pub mod curry7;
+
+/// Currying functions with 8 arguments
+pub mod curry8;
+
 /// Create a tuple
 ///
 /// ```
@@ -48,7 +92,13 @@ pub fn tuple3<A, B, C>(a: A, b: B, c: C) -> (A, B, C) {
   (a, b, c)
 }
 
-pub(self) mod arg {
+tuple_fn!(tuple4 4 (a: A, b: B, c: C, d: D));
+tuple_fn!(tuple5 5 (a: A, b: B, c: C, d: D, e: E));
+tuple_fn!(tuple6 6 (a: A, b: B, c: C, d: D, e: E, g: G));
+tuple_fn!(tuple7 7 (a: A, b: B, c: C, d: D, e: E, g: G, h: H));
+tuple_fn!(tuple8 8 (a: A, b: B, c: C, d: D, e: E, g: G, h: H, i: I));
+
+mod arg {
   #[allow(unreachable_pub)]
   pub trait Arg {
     /// The type of the argument
@@ -79,10 +129,12 @@ impl<T> Default for Nothing<T> {
 
 impl<T> Clone for Nothing<T> {
   fn clone(&self) -> Self {
-    Self::new()
+    *self
   }
 }
 
+impl<T> Copy for Nothing<T> {}
+
 impl<T> arg::Arg for Just<T> {
   type T = T;
 }
@@ -248,6 +300,25 @@ pub trait F1Once<A> {
     Compose::compose(self,
                      (call_deref as call_deref<G, Self::Ret, C>).curry().call(g))
   }
+
+  /// Erase the type of this function, see [`BoxF1Once`](boxed::BoxF1Once).
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let steps: Vec<BoxF1Once<String, String>> =
+  ///   vec![(|s: String| s + "a").boxed(),
+  ///        (|s: String| s.to_uppercase()).chain(|s: String| s + "b").boxed()];
+  ///
+  /// let out = steps.into_iter().fold(String::new(), |s, step| step.call1(s));
+  /// assert_eq!(out, "Ab");
+  /// ```
+  #[cfg(feature = "alloc")]
+  fn boxed<'a>(self) -> boxed::BoxF1Once<'a, A, Self::Ret>
+    where Self: Sized + 'a
+  {
+    boxed::BoxF1Once::new(self)
+  }
 }
 
 /// A function that accepts 2 arguments
@@ -362,3 +433,9 @@ impl<F, A, B, C, D> F3Once<A, B, C> for F where F: FnOnce(A, B, C) -> D
     Curry3::curry(self)
   }
 }
+
+fn_traits!(4 F4Once F4 curry4 (a: A, b: B, c: C, d: D) -> E);
+fn_traits!(5 F5Once F5 curry5 (a: A, b: B, c: C, d: D, e: E) -> G);
+fn_traits!(6 F6Once F6 curry6 (a: A, b: B, c: C, d: D, e: E, g: G) -> H);
+fn_traits!(7 F7Once F7 curry7 (a: A, b: B, c: C, d: D, e: E, g: G, h: H) -> I);
+fn_traits!(8 F8Once F8 curry8 (a: A, b: B, c: C, d: D, e: E, g: G, h: H, i: I) -> J);
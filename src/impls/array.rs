@@ -0,0 +1,230 @@
+use core::ops::ControlFlow;
+
+use crate::prelude::*;
+
+/// Array Kinds
+pub mod hkt {
+  use crate::prelude::*;
+
+  /// Fixed-size arrays `[A; N]` lifted to an HKT1
+  ///
+  /// (Kind `Type -> Type`)
+  pub struct Array<const N: usize>;
+  impl<const N: usize> HKT1 for Array<N> {
+    type T<A> = [A; N];
+  }
+}
+
+impl<A, const N: usize> Functor<hkt::Array<N>, A> for [A; N] {
+  fn fmap<AB, B>(self, f: AB) -> [B; N]
+    where AB: F1<A, Ret = B>
+  {
+    self.map(|a| f.call(a))
+  }
+}
+
+impl<A, const N: usize> FoldableIndexed<hkt::Array<N>, usize, A> for [A; N] {
+  fn foldl_idx<B, BAB>(self, f: BAB, b: B) -> B
+    where BAB: F3<B, usize, A, Ret = B>
+  {
+    self.into_iter()
+        .enumerate()
+        .fold(b, |b, (ix, a)| f.call(b, ix, a))
+  }
+
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// [0, 1, 2].foldl_idx(|(), ix, val| assert_eq!(ix, val), ());
+  /// [0, 1, 2].foldr_idx(|ix, val, ()| assert_eq!(ix, val), ());
+  /// ```
+  fn foldr_idx<B, ABB>(self, f: ABB, b: B) -> B
+    where ABB: F3<usize, A, B, Ret = B>
+  {
+    self.into_iter()
+        .enumerate()
+        .rfold(b, |b, (ix, a)| f.call(ix, a, b))
+  }
+
+  fn foldl_idx_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> B
+    where BAB: F3<B, usize, &'a A, Ret = B>,
+          A: 'a
+  {
+    self.iter()
+        .enumerate()
+        .fold(b, |b, (ix, a)| f.call(b, ix, a))
+  }
+
+  fn foldr_idx_ref<'a, B, ABB>(&'a self, f: ABB, b: B) -> B
+    where ABB: F3<usize, &'a A, B, Ret = B>,
+          A: 'a
+  {
+    self.iter()
+        .enumerate()
+        .rfold(b, |b, (ix, a)| f.call(ix, a, b))
+  }
+
+  fn try_foldl_idx<B, BAB>(self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F3<B, usize, A, Ret = ControlFlow<B, B>>
+  {
+    self.into_iter()
+        .enumerate()
+        .try_fold(b, |b, (ix, a)| f.call(b, ix, a))
+  }
+
+  fn try_foldl_idx_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F3<B, usize, &'a A, Ret = ControlFlow<B, B>>,
+          A: 'a
+  {
+    self.iter()
+        .enumerate()
+        .try_fold(b, |b, (ix, a)| f.call(b, ix, a))
+  }
+}
+
+impl<A, const N: usize> Foldable<hkt::Array<N>, A> for [A; N] {
+  fn foldl<B, BAB>(self, f: BAB, b: B) -> B
+    where BAB: F2<B, A, Ret = B>
+  {
+    self.into_iter().fold(b, |b, a| f.call(b, a))
+  }
+
+  fn foldr<B, ABB>(self, f: ABB, b: B) -> B
+    where ABB: F2<A, B, Ret = B>
+  {
+    self.into_iter().rfold(b, |b, a| f.call(a, b))
+  }
+
+  fn foldl_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> B
+    where BAB: F2<B, &'a A, Ret = B>,
+          A: 'a
+  {
+    self.iter().fold(b, |b, a| f.call(b, a))
+  }
+
+  fn foldr_ref<'a, B, ABB>(&'a self, f: ABB, b: B) -> B
+    where ABB: F2<&'a A, B, Ret = B>,
+          A: 'a
+  {
+    self.iter().rfold(b, |b, a| f.call(a, b))
+  }
+
+  fn try_foldl<B, BAB>(self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F2<B, A, Ret = ControlFlow<B, B>>
+  {
+    self.into_iter().try_fold(b, |b, a| f.call(b, a))
+  }
+
+  fn try_foldl_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F2<B, &'a A, Ret = ControlFlow<B, B>>,
+          A: 'a
+  {
+    self.iter().try_fold(b, |b, a| f.call(b, a))
+  }
+}
+
+/// An array that is filled one element at a time.
+///
+/// This is the `TF` type of [`Traversable`] for arrays; calling it
+/// with an element pushes that element, which allows the array to be
+/// rebuilt inside of an [`Applicative`] without allocating.
+#[derive(Debug, Clone)]
+pub struct ArrayFill<B, const N: usize> {
+  items: [Option<B>; N],
+  len: usize,
+}
+
+impl<B, const N: usize> ArrayFill<B, N> {
+  /// Create an `ArrayFill` with no elements
+  pub fn new() -> Self {
+    Self { items: [(); N].map(|_| None),
+           len: 0 }
+  }
+
+  /// Get the filled array
+  ///
+  /// # Panics
+  /// If fewer than `N` elements were pushed
+  pub fn fill(self) -> [B; N] {
+    self.items
+        .map(|b| b.expect("ArrayFill::fill called before all elements were pushed"))
+  }
+}
+
+impl<B, const N: usize> Default for ArrayFill<B, N> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<B, const N: usize> F1Once<B> for ArrayFill<B, N> {
+  type Ret = Self;
+
+  /// Push an element
+  ///
+  /// Once `N` elements have been pushed the array is full,
+  /// and further elements are ignored.
+  ///
+  /// ```
+  /// use naan::impls::array::ArrayFill;
+  /// use naan::prelude::*;
+  ///
+  /// let full = ArrayFill::<u8, 2>::new().call1(1).call1(2).call1(3);
+  /// assert_eq!(full.fill(), [1, 2]);
+  /// ```
+  fn call1(mut self, b: B) -> Self {
+    if let Some(slot) = self.items.get_mut(self.len) {
+      *slot = Some(b);
+      self.len += 1;
+    }
+    self
+  }
+}
+
+impl<B, const N: usize> F1<B> for ArrayFill<B, N> where B: Clone
+{
+  fn call(&self, b: B) -> Self {
+    self.clone().call1(b)
+  }
+}
+
+impl<A, B, const N: usize> Traversable<hkt::Array<N>, A, B, ArrayFill<B, N>> for [A; N]
+  where hkt::Array<N>: HKT1<T<B> = [B; N]>
+{
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let parse = |ss: [&str; 3]| ss.traverse::<hkt::Option, _>(|s: &str| s.parse::<u8>().ok());
+  ///
+  /// assert_eq!(parse(["1", "2", "3"]), Some([1, 2, 3]));
+  /// assert_eq!(parse(["1", "two", "3"]), None);
+  /// ```
+  fn traversem1<Ap, AtoApOfB>(self, f: AtoApOfB) -> Ap::T<[B; N]>
+    where Ap: HKT1,
+          Self: Foldable<hkt::Array<N>, A>,
+          Ap::T<B>: Applicative<Ap, B> + ApplyOnce<Ap, B>,
+          Ap::T<ArrayFill<B, N>>: Applicative<Ap, ArrayFill<B, N>> + ApplyOnce<Ap, ArrayFill<B, N>>,
+          Ap::T<[B; N]>: Applicative<Ap, [B; N]> + ApplyOnce<Ap, [B; N]>,
+          AtoApOfB: F1<A, Ret = Ap::T<B>>,
+          hkt::Array<N>: HKT1<T<A> = Self>
+  {
+    self.foldl(|ap: Ap::T<ArrayFill<B, N>>, a| ap.apply1(f.call(a)),
+               Ap::T::pure(ArrayFill::new()))
+        .fmap(ArrayFill::fill)
+  }
+
+  fn traversemm<Ap, AtoApOfB>(self, f: AtoApOfB) -> Ap::T<[B; N]>
+    where Ap: HKT1,
+          Self: Foldable<hkt::Array<N>, A>,
+          B: Clone,
+          Ap::T<B>: Applicative<Ap, B>,
+          Ap::T<ArrayFill<B, N>>: Applicative<Ap, ArrayFill<B, N>>,
+          Ap::T<[B; N]>: Applicative<Ap, [B; N]>,
+          AtoApOfB: F1<A, Ret = Ap::T<B>>,
+          hkt::Array<N>: HKT1<T<A> = Self>
+  {
+    self.foldl(|ap: Ap::T<ArrayFill<B, N>>, a| ap.apply(f.call(a)),
+               Ap::T::pure(ArrayFill::new()))
+        .fmap(ArrayFill::fill)
+  }
+}
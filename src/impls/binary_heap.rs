@@ -0,0 +1,136 @@
+use core::ops::ControlFlow;
+
+use std_alloc::collections::BinaryHeap;
+
+use crate::prelude::*;
+
+/// BinaryHeap Kinds
+pub mod hkt {
+  use super::*;
+
+  /// [`std::collections::BinaryHeap`] lifted to an HKT1
+  ///
+  /// (Kind `Type -> Type`)
+  pub struct BinaryHeap;
+  impl HKT1C for BinaryHeap {
+    type T<A> = ::std_alloc::collections::BinaryHeap<A>;
+    type Item<A> = A;
+  }
+
+  impl<A> Constraint<BinaryHeap> for A where A: Ord
+  {
+    fn collect<I>(items: I) -> ::std_alloc::collections::BinaryHeap<A>
+      where I: IntoIterator<Item = A>
+    {
+      items.into_iter().collect()
+    }
+  }
+
+  impl HKT1 for BinaryHeap {
+    type T<A> = ::std_alloc::collections::BinaryHeap<A>;
+  }
+}
+
+impl<A> Semigroup for BinaryHeap<A> where A: Ord
+{
+  /// Push all of the elements of `b` into `self`
+  ///
+  /// ```
+  /// use std::collections::BinaryHeap;
+  ///
+  /// use naan::prelude::*;
+  ///
+  /// let heap = BinaryHeap::from([1, 3]).append(BinaryHeap::from([2]));
+  /// assert_eq!(heap.into_sorted_vec(), vec![1, 2, 3]);
+  /// ```
+  fn append(mut self, b: Self) -> Self {
+    self.extend(b);
+    self
+  }
+}
+
+impl<A> Monoid for BinaryHeap<A> where A: Ord
+{
+  fn identity() -> Self {
+    BinaryHeap::new()
+  }
+}
+
+impl<A> Foldable<hkt::BinaryHeap, A> for BinaryHeap<A> {
+  fn foldl<B, BAB>(self, f: BAB, b: B) -> B
+    where BAB: F2<B, A, Ret = B>
+  {
+    self.into_iter().fold(b, |b, a| f.call(b, a))
+  }
+
+  fn foldr<B, ABB>(self, f: ABB, b: B) -> B
+    where ABB: F2<A, B, Ret = B>
+  {
+    self.into_iter().rfold(b, |b, a| f.call(a, b))
+  }
+
+  fn foldl_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> B
+    where BAB: F2<B, &'a A, Ret = B>,
+          A: 'a
+  {
+    self.iter().fold(b, |b, a| f.call(b, a))
+  }
+
+  fn foldr_ref<'a, B, ABB>(&'a self, f: ABB, b: B) -> B
+    where ABB: F2<&'a A, B, Ret = B>,
+          A: 'a
+  {
+    self.iter().rfold(b, |b, a| f.call(a, b))
+  }
+
+  fn try_foldl<B, BAB>(self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F2<B, A, Ret = ControlFlow<B, B>>
+  {
+    self.into_iter().try_fold(b, |b, a| f.call(b, a))
+  }
+
+  fn try_foldl_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F2<B, &'a A, Ret = ControlFlow<B, B>>,
+          A: 'a
+  {
+    self.iter().try_fold(b, |b, a| f.call(b, a))
+  }
+}
+
+impl<A> CFunctor<hkt::BinaryHeap, A> for BinaryHeap<A> {
+  fn cfmap<AB, B>(self, f: AB) -> BinaryHeap<B>
+    where AB: F1<A, Ret = B>,
+          B: Constraint<hkt::BinaryHeap>
+  {
+    B::collect(self.into_iter().map(|a| f.call(a)))
+  }
+}
+
+impl<A> CFoldable<hkt::BinaryHeap, A> for BinaryHeap<A> {
+  fn cfoldl<B, BAB>(self, f: BAB, b: B) -> B
+    where BAB: F2<B, A, Ret = B>
+  {
+    self.foldl(f, b)
+  }
+
+  fn cfoldr<B, ABB>(self, f: ABB, b: B) -> B
+    where ABB: F2<A, B, Ret = B>
+  {
+    self.foldr(f, b)
+  }
+}
+
+impl<A> CMonad<hkt::BinaryHeap, A> for BinaryHeap<A> {
+  fn cpure(a: A) -> BinaryHeap<A>
+    where A: Constraint<hkt::BinaryHeap>
+  {
+    A::collect(core::iter::once(a))
+  }
+
+  fn cbind<B, AMB>(self, f: AMB) -> BinaryHeap<B>
+    where AMB: F1<A, Ret = BinaryHeap<B>>,
+          B: Constraint<hkt::BinaryHeap>
+  {
+    B::collect(self.into_iter().flat_map(|a| f.call(a)))
+  }
+}
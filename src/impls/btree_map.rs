@@ -1,3 +1,5 @@
+use core::ops::ControlFlow;
+
 use std_alloc::collections::BTreeMap;
 
 use crate::prelude::*;
@@ -27,6 +29,26 @@ pub mod hkt {
   {
     type T<A> = std_alloc::collections::BTreeMap<K, A>;
   }
+
+  /// [`std::collections::BTreeMap`] lifted to an HKT1C
+  /// with a fixed value type, allowing the keys to be mapped.
+  ///
+  /// (Kind `Type -> Type`)
+  pub struct BTreeMapKeys<V>(PhantomData<V>);
+
+  impl<V> HKT1C for BTreeMapKeys<V> {
+    type T<K> = std_alloc::collections::BTreeMap<K, V>;
+    type Item<K> = (K, V);
+  }
+
+  impl<K, V> Constraint<BTreeMapKeys<V>> for K where K: Ord
+  {
+    fn collect<I>(items: I) -> std_alloc::collections::BTreeMap<K, V>
+      where I: IntoIterator<Item = (K, V)>
+    {
+      items.into_iter().collect()
+    }
+  }
 }
 
 impl<K, A> Functor<hkt::BTreeMapValues<K>, A> for BTreeMap<K, A> where K: Ord
@@ -64,7 +86,7 @@ impl<K, A> Alt<hkt::BTreeMapValues<K>, A> for BTreeMap<K, A> where K: Ord
   /// assert_eq!(a_union_b, BTreeMap::from([("a", 1), ("b", 2), ("c", 3)]))
   /// ```
   fn alt(self, b: Self) -> Self {
-    b.into_iter().chain(self.into_iter()).collect()
+    b.into_iter().chain(self).collect()
   }
 }
 
@@ -116,6 +138,19 @@ impl<A, K> Foldable<hkt::BTreeMapValues<K>, A> for BTreeMap<K, A> where K: Ord
   {
     self.iter().fold(b, |b, (_, a)| f.call(a, b))
   }
+
+  fn try_foldl<B, BAB>(self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F2<B, A, Ret = ControlFlow<B, B>>
+  {
+    self.into_iter().try_fold(b, |b, (_, a)| f.call(b, a))
+  }
+
+  fn try_foldl_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F2<B, &'a A, Ret = ControlFlow<B, B>>,
+          A: 'a
+  {
+    self.iter().try_fold(b, |b, (_, a)| f.call(b, a))
+  }
 }
 
 impl<A, K> FoldableIndexed<hkt::BTreeMapValues<K>, K, A> for BTreeMap<K, A> where K: Ord + Clone
@@ -145,6 +180,19 @@ impl<A, K> FoldableIndexed<hkt::BTreeMapValues<K>, K, A> for BTreeMap<K, A> wher
   {
     self.iter().fold(b, |b, (k, a)| f.call(k.clone(), a, b))
   }
+
+  fn try_foldl_idx<B, BAB>(self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F3<B, K, A, Ret = ControlFlow<B, B>>
+  {
+    self.into_iter().try_fold(b, |b, (k, a)| f.call(b, k, a))
+  }
+
+  fn try_foldl_idx_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F3<B, K, &'a A, Ret = ControlFlow<B, B>>,
+          A: 'a
+  {
+    self.iter().try_fold(b, |b, (k, a)| f.call(b, k.clone(), a))
+  }
 }
 
 #[allow(non_camel_case_types)]
@@ -207,3 +255,80 @@ impl<K, A, B> Traversable<hkt::BTreeMapValues<K>, A, B, insert2<K, B>> for BTree
                    Ap::T::pure(BTreeMap::<K, B>::empty()))
   }
 }
+
+impl<K, A> Bifoldable<hkt::BTreeMap, K, A> for BTreeMap<K, A> {
+  fn bifoldl<C, CKC, CAC>(self, fk: CKC, fa: CAC, c: C) -> C
+    where CKC: F2<C, K, Ret = C>,
+          CAC: F2<C, A, Ret = C>
+  {
+    self.into_iter()
+        .fold(c, |c, (k, a)| fa.call(fk.call(c, k), a))
+  }
+
+  fn bifoldr<C, KCC, ACC>(self, fk: KCC, fa: ACC, c: C) -> C
+    where KCC: F2<K, C, Ret = C>,
+          ACC: F2<A, C, Ret = C>
+  {
+    self.into_iter()
+        .rfold(c, |c, (k, a)| fk.call(k, fa.call(a, c)))
+  }
+}
+
+impl<K, A, K2, A2> Bitraversable<hkt::BTreeMap, K, A, K2, A2, insert1<K2, A2>, insert2<K2, A2>>
+  for BTreeMap<K, A> where K2: Ord
+{
+  fn bitraverse<Ap, FK, FA>(self, fk: FK, fa: FA) -> Ap::T<BTreeMap<K2, A2>>
+    where Ap: HKT1,
+          Ap::T<K2>: Applicative<Ap, K2> + ApplyOnce<Ap, K2>,
+          Ap::T<A2>: Applicative<Ap, A2> + ApplyOnce<Ap, A2>,
+          Ap::T<insert1<K2, A2>>: Applicative<Ap, insert1<K2, A2>> + ApplyOnce<Ap, insert1<K2, A2>>,
+          Ap::T<insert2<K2, A2>>: Applicative<Ap, insert2<K2, A2>> + ApplyOnce<Ap, insert2<K2, A2>>,
+          Ap::T<BTreeMap<K2, A2>>:
+            Applicative<Ap, BTreeMap<K2, A2>> + ApplyOnce<Ap, BTreeMap<K2, A2>>,
+          FK: F1<K, Ret = Ap::T<K2>>,
+          FA: F1<A, Ret = Ap::T<A2>>
+  {
+    self.into_iter()
+        .fold(Ap::T::pure(BTreeMap::new()), |ap, (k, a)| {
+          fk.call(k)
+            .fmap((insert as insert<K2, A2>).curry())
+            .apply1(fa.call(a))
+            .apply1(ap)
+        })
+  }
+}
+
+impl<K, V> CFunctor<hkt::BTreeMapKeys<V>, K> for BTreeMap<K, V> {
+  /// Map the keys of the map. If two keys map to the same key,
+  /// the value of the last one is kept.
+  ///
+  /// ```
+  /// use std::collections::BTreeMap;
+  ///
+  /// use naan::prelude::*;
+  ///
+  /// let m = BTreeMap::from([("a", 1), ("b", 2)]).cfmap(|k: &str| k.to_uppercase());
+  ///
+  /// assert_eq!(m, BTreeMap::from([(format!("A"), 1), (format!("B"), 2)]));
+  /// ```
+  fn cfmap<AB, B>(self, f: AB) -> BTreeMap<B, V>
+    where AB: F1<K, Ret = B>,
+          B: Constraint<hkt::BTreeMapKeys<V>>
+  {
+    B::collect(self.into_iter().map(|(k, v)| (f.call(k), v)))
+  }
+}
+
+impl<K, V> CFoldable<hkt::BTreeMapKeys<V>, K> for BTreeMap<K, V> {
+  fn cfoldl<B, BKB>(self, f: BKB, b: B) -> B
+    where BKB: F2<B, K, Ret = B>
+  {
+    self.into_keys().fold(b, |b, k| f.call(b, k))
+  }
+
+  fn cfoldr<B, KBB>(self, f: KBB, b: B) -> B
+    where KBB: F2<K, B, Ret = B>
+  {
+    self.into_keys().rfold(b, |b, k| f.call(k, b))
+  }
+}
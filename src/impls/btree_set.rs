@@ -0,0 +1,136 @@
+use core::ops::ControlFlow;
+
+use std_alloc::collections::BTreeSet;
+
+use crate::prelude::*;
+
+/// BTreeSet Kinds
+pub mod hkt {
+  use super::*;
+
+  /// [`std::collections::BTreeSet`] lifted to an HKT1
+  ///
+  /// (Kind `Type -> Type`)
+  pub struct BTreeSet;
+  impl HKT1C for BTreeSet {
+    type T<A> = ::std_alloc::collections::BTreeSet<A>;
+    type Item<A> = A;
+  }
+
+  impl<A> Constraint<BTreeSet> for A where A: Ord
+  {
+    fn collect<I>(items: I) -> ::std_alloc::collections::BTreeSet<A>
+      where I: IntoIterator<Item = A>
+    {
+      items.into_iter().collect()
+    }
+  }
+
+  impl HKT1 for BTreeSet {
+    type T<A> = ::std_alloc::collections::BTreeSet<A>;
+  }
+}
+
+impl<A> Semigroup for BTreeSet<A> where A: Ord
+{
+  /// The union of both sets
+  ///
+  /// ```
+  /// use std::collections::BTreeSet;
+  ///
+  /// use naan::prelude::*;
+  ///
+  /// assert_eq!(BTreeSet::from([1, 2]).append(BTreeSet::from([2, 3])),
+  ///            BTreeSet::from([1, 2, 3]));
+  /// ```
+  fn append(mut self, b: Self) -> Self {
+    self.extend(b);
+    self
+  }
+}
+
+impl<A> Monoid for BTreeSet<A> where A: Ord
+{
+  fn identity() -> Self {
+    BTreeSet::new()
+  }
+}
+
+impl<A> Foldable<hkt::BTreeSet, A> for BTreeSet<A> {
+  fn foldl<B, BAB>(self, f: BAB, b: B) -> B
+    where BAB: F2<B, A, Ret = B>
+  {
+    self.into_iter().fold(b, |b, a| f.call(b, a))
+  }
+
+  fn foldr<B, ABB>(self, f: ABB, b: B) -> B
+    where ABB: F2<A, B, Ret = B>
+  {
+    self.into_iter().rfold(b, |b, a| f.call(a, b))
+  }
+
+  fn foldl_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> B
+    where BAB: F2<B, &'a A, Ret = B>,
+          A: 'a
+  {
+    self.iter().fold(b, |b, a| f.call(b, a))
+  }
+
+  fn foldr_ref<'a, B, ABB>(&'a self, f: ABB, b: B) -> B
+    where ABB: F2<&'a A, B, Ret = B>,
+          A: 'a
+  {
+    self.iter().rfold(b, |b, a| f.call(a, b))
+  }
+
+  fn try_foldl<B, BAB>(self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F2<B, A, Ret = ControlFlow<B, B>>
+  {
+    self.into_iter().try_fold(b, |b, a| f.call(b, a))
+  }
+
+  fn try_foldl_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F2<B, &'a A, Ret = ControlFlow<B, B>>,
+          A: 'a
+  {
+    self.iter().try_fold(b, |b, a| f.call(b, a))
+  }
+}
+
+impl<A> CFunctor<hkt::BTreeSet, A> for BTreeSet<A> {
+  fn cfmap<AB, B>(self, f: AB) -> BTreeSet<B>
+    where AB: F1<A, Ret = B>,
+          B: Constraint<hkt::BTreeSet>
+  {
+    B::collect(self.into_iter().map(|a| f.call(a)))
+  }
+}
+
+impl<A> CFoldable<hkt::BTreeSet, A> for BTreeSet<A> {
+  fn cfoldl<B, BAB>(self, f: BAB, b: B) -> B
+    where BAB: F2<B, A, Ret = B>
+  {
+    self.foldl(f, b)
+  }
+
+  fn cfoldr<B, ABB>(self, f: ABB, b: B) -> B
+    where ABB: F2<A, B, Ret = B>
+  {
+    self.foldr(f, b)
+  }
+}
+
+impl<A> CMonad<hkt::BTreeSet, A> for BTreeSet<A> {
+  fn cpure(a: A) -> BTreeSet<A>
+    where A: Constraint<hkt::BTreeSet>
+  {
+    A::collect(core::iter::once(a))
+  }
+
+  fn cbind<B, AMB>(self, f: AMB) -> BTreeSet<B>
+    where AMB: F1<A, Ret = BTreeSet<B>>,
+          B: Constraint<hkt::BTreeSet>
+  {
+    B::collect(self.into_iter().flat_map(|a| f.call(a)))
+  }
+}
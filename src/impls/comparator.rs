@@ -0,0 +1,171 @@
+use core::cmp::Ordering;
+
+use std_alloc::boxed::Box;
+
+use crate::prelude::*;
+
+/// Comparator Kinds
+pub mod hkt {
+  use core::marker::PhantomData;
+
+  use super::*;
+
+  /// [`Comparator`](super::Comparator) lifted to an HKT1
+  ///
+  /// (Kind `Type -> Type`)
+  pub struct Comparator<'a>(PhantomData<&'a ()>);
+  impl<'a> HKT1 for Comparator<'a> {
+    type T<A> = super::Comparator<'a, A>;
+  }
+}
+
+/// A total ordering of values of type `A`
+///
+/// Comparators are [`Contravariant`]; a comparator of `A` can be
+/// adapted to a comparator of `B` given a function from `B -> A`.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// let by_len = Comparator::new(|a: usize, b: usize| a.cmp(&b)).contramap(|s: &str| s.len());
+///
+/// let mut words = vec!["abc", "a", "ab"];
+/// words.sort_by(|a, b| by_len.compare(a, b));
+///
+/// assert_eq!(words, vec!["a", "ab", "abc"]);
+/// ```
+pub struct Comparator<'a, A>(pub Box<dyn Fn(A, A) -> Ordering + 'a>);
+
+impl<'a, A> Comparator<'a, A> {
+  /// Create a `Comparator` from a function
+  pub fn new<F>(f: F) -> Self
+    where F: F2<A, A, Ret = Ordering> + 'a
+  {
+    Comparator(Box::new(move |a, b| f.call(a, b)))
+  }
+
+  /// Compare values by the [`Ord`] of a key extracted from them
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let by_len = Comparator::comparing(|s: &str| s.len());
+  ///
+  /// let mut words = vec!["abc", "a", "ab"];
+  /// words.sort_by(|a, b| by_len.compare(a, b));
+  ///
+  /// assert_eq!(words, vec!["a", "ab", "abc"]);
+  /// ```
+  pub fn comparing<F, K>(f: F) -> Self
+    where F: F1<A, Ret = K> + 'a,
+          K: Ord
+  {
+    Comparator(Box::new(move |a, b| f.call(a).cmp(&f.call(b))))
+  }
+
+  /// Compare `a` to `b`
+  pub fn compare(&self, a: A, b: A) -> Ordering {
+    (self.0)(a, b)
+  }
+
+  /// Flip the ordering of this comparator
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let largest_first = Comparator::comparing(|n: u32| n).reversed();
+  ///
+  /// let mut ns = vec![1, 3, 2];
+  /// ns.sort_by(|a, b| largest_first.compare(*a, *b));
+  ///
+  /// assert_eq!(ns, vec![3, 2, 1]);
+  /// ```
+  pub fn reversed(self) -> Self
+    where A: 'a
+  {
+    Comparator(Box::new(move |a, b| self.compare(b, a)))
+  }
+
+  /// Break ties in this comparator by comparing a key
+  /// extracted from the values.
+  ///
+  /// Shorthand for `self.append(Comparator::comparing(f))`.
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let by_len_then_alpha = Comparator::comparing(|s: &str| s.len()).then_comparing(str::to_string);
+  ///
+  /// let mut words = vec!["bc", "c", "ab", "a"];
+  /// words.sort_by(|a, b| by_len_then_alpha.compare(a, b));
+  ///
+  /// assert_eq!(words, vec!["a", "c", "ab", "bc"]);
+  /// ```
+  pub fn then_comparing<F, K>(self, f: F) -> Self
+    where A: Clone + 'a,
+          F: F1<A, Ret = K> + 'a,
+          K: Ord
+  {
+    self.append(Comparator::comparing(f))
+  }
+}
+
+/// Compare with `self`, breaking ties with `b`
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Person {
+///   name: &'static str,
+///   age: u8,
+/// }
+///
+/// let people = vec![Person { name: "bob",
+///                            age: 30 },
+///                   Person { name: "alice",
+///                            age: 29 },
+///                   Person { name: "alex",
+///                            age: 30 }];
+///
+/// let by_age_then_name = vec![Comparator::comparing(|p: &Person| p.age).reversed(),
+///                             Comparator::comparing(|p: &Person| p.name)].fold_map(|c| c);
+///
+/// let mut refs = people.iter().collect::<Vec<_>>();
+/// refs.sort_by(|a, b| by_age_then_name.compare(*a, *b));
+///
+/// assert_eq!(refs.into_iter().map(|p| p.name).collect::<Vec<_>>(),
+///            vec!["alex", "bob", "alice"]);
+/// ```
+impl<'a, A> Semigroup for Comparator<'a, A> where A: Clone + 'a
+{
+  fn append(self, b: Self) -> Self {
+    Comparator(Box::new(move |x: A, y: A| {
+                 self.compare(x.clone(), y.clone())
+                     .then_with(|| b.compare(x, y))
+               }))
+  }
+}
+
+/// The identity considers all values equal
+impl<'a, A> Monoid for Comparator<'a, A> where A: Clone + 'a
+{
+  fn identity() -> Self {
+    Comparator(Box::new(|_, _| Ordering::Equal))
+  }
+}
+
+impl<'a, A> Contravariant<'a, hkt::Comparator<'a>, A> for Comparator<'a, A> where A: 'a
+{
+  fn contramap<BA, B>(self, f: BA) -> Comparator<'a, B>
+    where BA: F1<B, Ret = A> + 'a
+  {
+    Comparator(Box::new(move |a, b| self.compare(f.call(a), f.call(b))))
+  }
+}
+
+impl<'a, A> core::fmt::Debug for Comparator<'a, A> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    f.write_str("Comparator(..)")
+  }
+}
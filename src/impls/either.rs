@@ -0,0 +1,249 @@
+use crate::prelude::*;
+
+/// Either Kinds
+pub mod hkt {
+  use core::marker::PhantomData;
+
+  use crate::prelude::*;
+
+  /// [`Either`](super::Either) lifted to an HKT1
+  /// with the left type pinned to some `L`.
+  ///
+  /// (Kind `Type -> Type`)
+  pub struct EitherRight<L>(PhantomData<L>);
+  impl<L> HKT1 for EitherRight<L> {
+    type T<R> = super::Either<L, R>;
+  }
+
+  /// [`Either`](super::Either) lifted to an HKT2
+  ///
+  /// (Kind `Type -> Type -> Type`)
+  pub struct Either;
+  impl HKT2 for Either {
+    type T<L, R> = super::Either<L, R>;
+  }
+}
+
+/// A value that is one of two types, with neither meaning success or failure.
+///
+/// `Either` is shaped like [`Result`] (and converts to and from it, `Ok` being `Right`),
+/// but is useful when both sides are equally valid outcomes.
+///
+/// Like most Haskell-inspired libraries, `Either` is right-biased; [`Functor`] and [`Monad`]
+/// act on the `Right` value and pass a `Left` through untouched.
+///
+/// ```
+/// use naan::impls::either::Either::{Left, Right};
+/// use naan::prelude::*;
+///
+/// fn parse(s: &str) -> Either<&str, u32> {
+///   s.parse().ok().map(Right).unwrap_or(Left(s))
+/// }
+///
+/// assert_eq!(parse("12").fmap(|n| n * 2), Right(24));
+/// assert_eq!(parse("twelve").fmap(|n| n * 2), Left("twelve"));
+///
+/// assert_eq!(parse("12").bimap(|s: &str| s.len(), |n| n as usize),
+///            Right(12));
+/// assert_eq!(parse("twelve").bimap(|s: &str| s.len(), |n| n as usize),
+///            Left(6));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Either<L, R> {
+  /// The left value
+  Left(L),
+  /// The right value
+  Right(R),
+}
+
+impl<L, R> Either<L, R> {
+  /// Is this `Left`?
+  pub fn is_left(&self) -> bool {
+    matches!(self, Either::Left(_))
+  }
+
+  /// Is this `Right`?
+  pub fn is_right(&self) -> bool {
+    !self.is_left()
+  }
+
+  /// Get the `Left` value, if there is one
+  pub fn left(self) -> Option<L> {
+    match self {
+      | Either::Left(l) => Some(l),
+      | Either::Right(_) => None,
+    }
+  }
+
+  /// Get the `Right` value, if there is one
+  pub fn right(self) -> Option<R> {
+    match self {
+      | Either::Left(_) => None,
+      | Either::Right(r) => Some(r),
+    }
+  }
+
+  /// Swap `Left` and `Right`
+  pub fn swap(self) -> Either<R, L> {
+    match self {
+      | Either::Left(l) => Either::Right(l),
+      | Either::Right(r) => Either::Left(r),
+    }
+  }
+
+  /// Collapse both sides into one value
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let e = Either::<u8, &str>::Right("abc");
+  ///
+  /// assert_eq!(e.either(|n: u8| n as usize, |s: &str| s.len()), 3);
+  /// ```
+  pub fn either<T, FL, FR>(self, fl: FL, fr: FR) -> T
+    where FL: F1Once<L, Ret = T>,
+          FR: F1Once<R, Ret = T>
+  {
+    match self {
+      | Either::Left(l) => fl.call1(l),
+      | Either::Right(r) => fr.call1(r),
+    }
+  }
+
+  /// Convert this `Either` to a [`Result`], `Right` becoming `Ok`
+  /// and `Left` becoming `Err`.
+  pub fn into_result(self) -> Result<R, L> {
+    match self {
+      | Either::Left(l) => Err(l),
+      | Either::Right(r) => Ok(r),
+    }
+  }
+
+  /// Borrow the contents of this `Either`
+  pub fn as_ref(&self) -> Either<&L, &R> {
+    match self {
+      | Either::Left(l) => Either::Left(l),
+      | Either::Right(r) => Either::Right(r),
+    }
+  }
+}
+
+impl<L, R> From<Result<R, L>> for Either<L, R> {
+  fn from(r: Result<R, L>) -> Self {
+    match r {
+      | Ok(r) => Either::Right(r),
+      | Err(l) => Either::Left(l),
+    }
+  }
+}
+
+impl<L, R> From<Either<L, R>> for Result<R, L> {
+  fn from(e: Either<L, R>) -> Self {
+    e.into_result()
+  }
+}
+
+impl<L, R> FunctorOnce<hkt::EitherRight<L>, R> for Either<L, R> {
+  fn fmap1<AB, B>(self, f: AB) -> Either<L, B>
+    where AB: F1Once<R, Ret = B>
+  {
+    match self {
+      | Either::Left(l) => Either::Left(l),
+      | Either::Right(r) => Either::Right(f.call1(r)),
+    }
+  }
+}
+deriving!(impl<L> Functor<hkt::EitherRight<L>, A> for Either<L, A> {..FunctorOnce});
+
+impl<L, AB> ApplyOnce<hkt::EitherRight<L>, AB> for Either<L, AB> {
+  fn apply1<A, B>(self, a: Either<L, A>) -> Either<L, B>
+    where AB: F1Once<A, Ret = B>
+  {
+    match self {
+      | Either::Right(f) => a.fmap1(f),
+      | Either::Left(l) => Either::Left(l),
+    }
+  }
+}
+deriving!(impl<L> Apply<hkt::EitherRight<L>, AB> for Either<L, AB> {..ApplyOnce});
+
+impl<L, R> Applicative<hkt::EitherRight<L>, R> for Either<L, R> {
+  fn pure(r: R) -> Either<L, R> {
+    Either::Right(r)
+  }
+}
+
+impl<L, R> Alt<hkt::EitherRight<L>, R> for Either<L, R> {
+  /// Yield the first `Right`, or `b` if `self` is `Left`
+  fn alt(self, b: Self) -> Self {
+    match self {
+      | Either::Right(r) => Either::Right(r),
+      | Either::Left(_) => b,
+    }
+  }
+}
+
+impl<L, R> MonadOnce<hkt::EitherRight<L>, R> for Either<L, R> {
+  fn bind1<B, AMB>(self, f: AMB) -> Either<L, B>
+    where AMB: F1Once<R, Ret = Either<L, B>>
+  {
+    match self {
+      | Either::Left(l) => Either::Left(l),
+      | Either::Right(r) => f.call1(r),
+    }
+  }
+}
+deriving!(impl<L> Monad<hkt::EitherRight<L>, A> for Either<L, A> {..MonadOnce});
+deriving!(impl<L> MonadHKT for hkt::EitherRight<L> {..Monad});
+
+impl<L, R> BifunctorOnce<hkt::Either, L, R> for Either<L, R> {
+  fn bimap1<L2, R2, FL, FR>(self, fl: FL, fr: FR) -> Either<L2, R2>
+    where FL: F1Once<L, Ret = L2>,
+          FR: F1Once<R, Ret = R2>
+  {
+    match self {
+      | Either::Left(l) => Either::Left(fl.call1(l)),
+      | Either::Right(r) => Either::Right(fr.call1(r)),
+    }
+  }
+}
+deriving!(impl Bifunctor<hkt::Either, L, R> for Either<L, R> {..BifunctorOnce});
+
+impl<L, R> Bifoldable<hkt::Either, L, R> for Either<L, R> {
+  fn bifoldl<C, CLC, CRC>(self, fl: CLC, fr: CRC, c: C) -> C
+    where CLC: F2<C, L, Ret = C>,
+          CRC: F2<C, R, Ret = C>
+  {
+    match self {
+      | Either::Left(l) => fl.call(c, l),
+      | Either::Right(r) => fr.call(c, r),
+    }
+  }
+
+  fn bifoldr<C, LCC, RCC>(self, fl: LCC, fr: RCC, c: C) -> C
+    where LCC: F2<L, C, Ret = C>,
+          RCC: F2<R, C, Ret = C>
+  {
+    match self {
+      | Either::Left(l) => fl.call(l, c),
+      | Either::Right(r) => fr.call(r, c),
+    }
+  }
+}
+
+impl<L, R, L2, R2> Bitraversable<hkt::Either, L, R, L2, R2, (), ()> for Either<L, R> {
+  fn bitraverse<Ap, FL, FR>(self, fl: FL, fr: FR) -> Ap::T<Either<L2, R2>>
+    where Ap: HKT1,
+          Ap::T<L2>: Applicative<Ap, L2> + ApplyOnce<Ap, L2>,
+          Ap::T<R2>: Applicative<Ap, R2> + ApplyOnce<Ap, R2>,
+          Ap::T<()>: Applicative<Ap, ()> + ApplyOnce<Ap, ()>,
+          Ap::T<Either<L2, R2>>: Applicative<Ap, Either<L2, R2>> + ApplyOnce<Ap, Either<L2, R2>>,
+          FL: F1<L, Ret = Ap::T<L2>>,
+          FR: F1<R, Ret = Ap::T<R2>>
+  {
+    match self {
+      | Either::Left(l) => fl.call(l).fmap(Either::Left),
+      | Either::Right(r) => fr.call(r).fmap(Either::Right),
+    }
+  }
+}
@@ -0,0 +1,62 @@
+use std_alloc::boxed::Box;
+
+use crate::prelude::*;
+
+/// Equivalence Kinds
+pub mod hkt {
+  use core::marker::PhantomData;
+
+  use super::*;
+
+  /// [`Equivalence`](super::Equivalence) lifted to an HKT1
+  ///
+  /// (Kind `Type -> Type`)
+  pub struct Equivalence<'a>(PhantomData<&'a ()>);
+  impl<'a> HKT1 for Equivalence<'a> {
+    type T<A> = super::Equivalence<'a, A>;
+  }
+}
+
+/// A test of whether two values of type `A` should be considered equal
+///
+/// Equivalences are [`Contravariant`]; an equivalence on `A` can be
+/// adapted to an equivalence on `B` given a function from `B -> A`.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// let same_len = Equivalence::new(|a: usize, b: usize| a == b).contramap(|s: &str| s.len());
+///
+/// assert!(same_len.equiv("abc", "def"));
+/// assert!(!same_len.equiv("abc", "de"));
+/// ```
+pub struct Equivalence<'a, A>(pub Box<dyn Fn(A, A) -> bool + 'a>);
+
+impl<'a, A> Equivalence<'a, A> {
+  /// Create an `Equivalence` from a function
+  pub fn new<F>(f: F) -> Self
+    where F: F2<A, A, Ret = bool> + 'a
+  {
+    Equivalence(Box::new(move |a, b| f.call(a, b)))
+  }
+
+  /// Should `a` and `b` be considered equal?
+  pub fn equiv(&self, a: A, b: A) -> bool {
+    (self.0)(a, b)
+  }
+}
+
+impl<'a, A> Contravariant<'a, hkt::Equivalence<'a>, A> for Equivalence<'a, A> where A: 'a
+{
+  fn contramap<BA, B>(self, f: BA) -> Equivalence<'a, B>
+    where BA: F1<B, Ret = A> + 'a
+  {
+    Equivalence(Box::new(move |a, b| self.equiv(f.call(a), f.call(b))))
+  }
+}
+
+impl<'a, A> core::fmt::Debug for Equivalence<'a, A> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    f.write_str("Equivalence(..)")
+  }
+}
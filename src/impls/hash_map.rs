@@ -1,4 +1,5 @@
 use core::hash::Hash;
+use core::ops::ControlFlow;
 use std::collections::HashMap;
 
 use crate::prelude::*;
@@ -28,6 +29,26 @@ pub mod hkt {
   {
     type T<A> = std::collections::HashMap<K, A>;
   }
+
+  /// [`std::collections::HashMap`] lifted to an HKT1C
+  /// with a fixed value type, allowing the keys to be mapped.
+  ///
+  /// (Kind `Type -> Type`)
+  pub struct HashMapKeys<V>(PhantomData<V>);
+
+  impl<V> HKT1C for HashMapKeys<V> {
+    type T<K> = std::collections::HashMap<K, V>;
+    type Item<K> = (K, V);
+  }
+
+  impl<K, V> Constraint<HashMapKeys<V>> for K where K: Hash + Eq
+  {
+    fn collect<I>(items: I) -> std::collections::HashMap<K, V>
+      where I: IntoIterator<Item = (K, V)>
+    {
+      items.into_iter().collect()
+    }
+  }
 }
 
 impl<K, A> Functor<hkt::HashMapValues<K>, A> for HashMap<K, A> where K: Hash + Eq
@@ -65,7 +86,7 @@ impl<K, A> Alt<hkt::HashMapValues<K>, A> for HashMap<K, A> where K: Eq + Hash
   /// assert_eq!(a_union_b, HashMap::from([("a", 1), ("b", 2), ("c", 3)]))
   /// ```
   fn alt(self, b: Self) -> Self {
-    b.into_iter().chain(self.into_iter()).collect()
+    b.into_iter().chain(self).collect()
   }
 }
 
@@ -117,6 +138,19 @@ impl<A, K> Foldable<hkt::HashMapValues<K>, A> for HashMap<K, A> where K: Eq + Ha
   {
     self.iter().fold(b, |b, (_, a)| f.call(a, b))
   }
+
+  fn try_foldl<B, BAB>(self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F2<B, A, Ret = ControlFlow<B, B>>
+  {
+    self.into_iter().try_fold(b, |b, (_, a)| f.call(b, a))
+  }
+
+  fn try_foldl_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F2<B, &'a A, Ret = ControlFlow<B, B>>,
+          A: 'a
+  {
+    self.iter().try_fold(b, |b, (_, a)| f.call(b, a))
+  }
 }
 
 impl<A, K> FoldableIndexed<hkt::HashMapValues<K>, K, A> for HashMap<K, A> where K: Eq + Hash + Clone
@@ -146,6 +180,19 @@ impl<A, K> FoldableIndexed<hkt::HashMapValues<K>, K, A> for HashMap<K, A> where
   {
     self.iter().fold(b, |b, (k, a)| f.call(k.clone(), a, b))
   }
+
+  fn try_foldl_idx<B, BAB>(self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F3<B, K, A, Ret = ControlFlow<B, B>>
+  {
+    self.into_iter().try_fold(b, |b, (k, a)| f.call(b, k, a))
+  }
+
+  fn try_foldl_idx_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F3<B, K, &'a A, Ret = ControlFlow<B, B>>,
+          A: 'a
+  {
+    self.iter().try_fold(b, |b, (k, a)| f.call(b, k.clone(), a))
+  }
 }
 
 #[allow(non_camel_case_types)]
@@ -208,3 +255,79 @@ impl<K, A, B> Traversable<hkt::HashMapValues<K>, A, B, insert2<K, B>> for HashMa
                    Ap::T::pure(HashMap::<K, B>::empty()))
   }
 }
+
+impl<K, A> Bifoldable<hkt::HashMap, K, A> for HashMap<K, A> {
+  fn bifoldl<C, CKC, CAC>(self, fk: CKC, fa: CAC, c: C) -> C
+    where CKC: F2<C, K, Ret = C>,
+          CAC: F2<C, A, Ret = C>
+  {
+    self.into_iter()
+        .fold(c, |c, (k, a)| fa.call(fk.call(c, k), a))
+  }
+
+  fn bifoldr<C, KCC, ACC>(self, fk: KCC, fa: ACC, c: C) -> C
+    where KCC: F2<K, C, Ret = C>,
+          ACC: F2<A, C, Ret = C>
+  {
+    self.into_iter()
+        .fold(c, |c, (k, a)| fk.call(k, fa.call(a, c)))
+  }
+}
+
+impl<K, A, K2, A2> Bitraversable<hkt::HashMap, K, A, K2, A2, insert1<K2, A2>, insert2<K2, A2>>
+  for HashMap<K, A> where K2: Hash + Eq
+{
+  fn bitraverse<Ap, FK, FA>(self, fk: FK, fa: FA) -> Ap::T<HashMap<K2, A2>>
+    where Ap: HKT1,
+          Ap::T<K2>: Applicative<Ap, K2> + ApplyOnce<Ap, K2>,
+          Ap::T<A2>: Applicative<Ap, A2> + ApplyOnce<Ap, A2>,
+          Ap::T<insert1<K2, A2>>: Applicative<Ap, insert1<K2, A2>> + ApplyOnce<Ap, insert1<K2, A2>>,
+          Ap::T<insert2<K2, A2>>: Applicative<Ap, insert2<K2, A2>> + ApplyOnce<Ap, insert2<K2, A2>>,
+          Ap::T<HashMap<K2, A2>>: Applicative<Ap, HashMap<K2, A2>> + ApplyOnce<Ap, HashMap<K2, A2>>,
+          FK: F1<K, Ret = Ap::T<K2>>,
+          FA: F1<A, Ret = Ap::T<A2>>
+  {
+    self.into_iter()
+        .fold(Ap::T::pure(HashMap::new()), |ap, (k, a)| {
+          fk.call(k)
+            .fmap((insert as insert<K2, A2>).curry())
+            .apply1(fa.call(a))
+            .apply1(ap)
+        })
+  }
+}
+
+impl<K, V> CFunctor<hkt::HashMapKeys<V>, K> for HashMap<K, V> {
+  /// Map the keys of the map. If two keys map to the same key,
+  /// the value of the last one is kept.
+  ///
+  /// ```
+  /// use std::collections::HashMap;
+  ///
+  /// use naan::prelude::*;
+  ///
+  /// let m = HashMap::from([("a", 1), ("b", 2)]).cfmap(|k: &str| k.to_uppercase());
+  ///
+  /// assert_eq!(m, HashMap::from([(format!("A"), 1), (format!("B"), 2)]));
+  /// ```
+  fn cfmap<AB, B>(self, f: AB) -> HashMap<B, V>
+    where AB: F1<K, Ret = B>,
+          B: Constraint<hkt::HashMapKeys<V>>
+  {
+    B::collect(self.into_iter().map(|(k, v)| (f.call(k), v)))
+  }
+}
+
+impl<K, V> CFoldable<hkt::HashMapKeys<V>, K> for HashMap<K, V> {
+  fn cfoldl<B, BKB>(self, f: BKB, b: B) -> B
+    where BKB: F2<B, K, Ret = B>
+  {
+    self.into_keys().fold(b, |b, k| f.call(b, k))
+  }
+
+  fn cfoldr<B, KBB>(self, f: KBB, b: B) -> B
+    where KBB: F2<K, B, Ret = B>
+  {
+    self.into_keys().fold(b, |b, k| f.call(k, b))
+  }
+}
@@ -0,0 +1,136 @@
+use core::hash::Hash;
+use core::ops::ControlFlow;
+use std::collections::HashSet;
+
+use crate::prelude::*;
+
+/// HashSet Kinds
+pub mod hkt {
+  use super::*;
+
+  /// [`std::collections::HashSet`] lifted to an HKT1
+  ///
+  /// (Kind `Type -> Type`)
+  pub struct HashSet;
+  impl HKT1C for HashSet {
+    type T<A> = std::collections::HashSet<A>;
+    type Item<A> = A;
+  }
+
+  impl<A> Constraint<HashSet> for A where A: Hash + Eq
+  {
+    fn collect<I>(items: I) -> std::collections::HashSet<A>
+      where I: IntoIterator<Item = A>
+    {
+      items.into_iter().collect()
+    }
+  }
+
+  impl HKT1 for HashSet {
+    type T<A> = std::collections::HashSet<A>;
+  }
+}
+
+impl<A> Semigroup for HashSet<A> where A: Hash + Eq
+{
+  /// The union of both sets
+  ///
+  /// ```
+  /// use std::collections::HashSet;
+  ///
+  /// use naan::prelude::*;
+  ///
+  /// assert_eq!(HashSet::from([1, 2]).append(HashSet::from([2, 3])),
+  ///            HashSet::from([1, 2, 3]));
+  /// ```
+  fn append(mut self, b: Self) -> Self {
+    self.extend(b);
+    self
+  }
+}
+
+impl<A> Monoid for HashSet<A> where A: Hash + Eq
+{
+  fn identity() -> Self {
+    HashSet::new()
+  }
+}
+
+impl<A> Foldable<hkt::HashSet, A> for HashSet<A> {
+  fn foldl<B, BAB>(self, f: BAB, b: B) -> B
+    where BAB: F2<B, A, Ret = B>
+  {
+    self.into_iter().fold(b, |b, a| f.call(b, a))
+  }
+
+  fn foldr<B, ABB>(self, f: ABB, b: B) -> B
+    where ABB: F2<A, B, Ret = B>
+  {
+    self.into_iter().fold(b, |b, a| f.call(a, b))
+  }
+
+  fn foldl_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> B
+    where BAB: F2<B, &'a A, Ret = B>,
+          A: 'a
+  {
+    self.iter().fold(b, |b, a| f.call(b, a))
+  }
+
+  fn foldr_ref<'a, B, ABB>(&'a self, f: ABB, b: B) -> B
+    where ABB: F2<&'a A, B, Ret = B>,
+          A: 'a
+  {
+    self.iter().fold(b, |b, a| f.call(a, b))
+  }
+
+  fn try_foldl<B, BAB>(self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F2<B, A, Ret = ControlFlow<B, B>>
+  {
+    self.into_iter().try_fold(b, |b, a| f.call(b, a))
+  }
+
+  fn try_foldl_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F2<B, &'a A, Ret = ControlFlow<B, B>>,
+          A: 'a
+  {
+    self.iter().try_fold(b, |b, a| f.call(b, a))
+  }
+}
+
+impl<A> CFunctor<hkt::HashSet, A> for HashSet<A> {
+  fn cfmap<AB, B>(self, f: AB) -> HashSet<B>
+    where AB: F1<A, Ret = B>,
+          B: Constraint<hkt::HashSet>
+  {
+    B::collect(self.into_iter().map(|a| f.call(a)))
+  }
+}
+
+impl<A> CFoldable<hkt::HashSet, A> for HashSet<A> {
+  fn cfoldl<B, BAB>(self, f: BAB, b: B) -> B
+    where BAB: F2<B, A, Ret = B>
+  {
+    self.foldl(f, b)
+  }
+
+  fn cfoldr<B, ABB>(self, f: ABB, b: B) -> B
+    where ABB: F2<A, B, Ret = B>
+  {
+    self.foldr(f, b)
+  }
+}
+
+impl<A> CMonad<hkt::HashSet, A> for HashSet<A> {
+  fn cpure(a: A) -> HashSet<A>
+    where A: Constraint<hkt::HashSet>
+  {
+    A::collect(core::iter::once(a))
+  }
+
+  fn cbind<B, AMB>(self, f: AMB) -> HashSet<B>
+    where AMB: F1<A, Ret = HashSet<B>>,
+          B: Constraint<hkt::HashSet>
+  {
+    B::collect(self.into_iter().flat_map(|a| f.call(a)))
+  }
+}
@@ -1,6 +1,6 @@
 #[allow(unused_imports)]
 use core::fmt::Debug;
-use core::ops::{Add, Div, Mul, Neg, Not};
+use core::ops::{Add, ControlFlow, Div, Mul, Neg, Not};
 
 use crate::prelude::*;
 
@@ -126,6 +126,21 @@ impl<T> MonadOnce<hkt::Id, T> for Id<T> {
   }
 }
 deriving!(impl Monad<hkt::Id, A> for Id<A> {..MonadOnce});
+deriving!(impl MonadHKT for hkt::Id {..Monad});
+
+impl<T> MonadRec<hkt::Id, T> for Id<T> {
+  fn tail_rec_m<S, F>(seed: S, f: F) -> Id<T>
+    where F: F1<S, Ret = Id<ControlFlow<T, S>>>
+  {
+    let mut s = seed;
+    loop {
+      match f.call(s).0 {
+        | ControlFlow::Break(t) => return Id(t),
+        | ControlFlow::Continue(next) => s = next,
+      }
+    }
+  }
+}
 
 impl<T> ApplyOnce<hkt::Id, T> for Id<T> {
   fn apply1<A, B>(self, a: Id<A>) -> Id<B>
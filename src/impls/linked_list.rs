@@ -0,0 +1,188 @@
+use core::iter;
+use core::ops::ControlFlow;
+
+use std_alloc::collections::LinkedList;
+
+use crate::prelude::*;
+
+/// LinkedList Kinds
+pub mod hkt {
+  use super::*;
+
+  /// [`std::collections::LinkedList`] lifted to an HKT1
+  ///
+  /// (Kind `Type -> Type`)
+  pub struct LinkedList;
+  impl HKT1 for LinkedList {
+    type T<A> = ::std_alloc::collections::LinkedList<A>;
+  }
+}
+
+impl<A> Functor<hkt::LinkedList, A> for LinkedList<A> {
+  fn fmap<AB, B>(self, f: AB) -> LinkedList<B>
+    where AB: F1<A, Ret = B>
+  {
+    self.into_iter().map(|a| f.call(a)).collect()
+  }
+}
+
+impl<AB> Apply<hkt::LinkedList, AB> for LinkedList<AB> {
+  fn apply_with<A, B, Cloner>(self,
+                              a: <hkt::LinkedList as HKT1>::T<A>,
+                              cloner: Cloner)
+                              -> <hkt::LinkedList as HKT1>::T<B>
+    where AB: F1<A, Ret = B>,
+          Cloner: for<'a> F1<&'a A, Ret = A>
+  {
+    self.into_iter()
+        .flat_map(move |f| {
+          a.iter()
+           .map(|a| f.call(cloner.call(a)))
+           .collect::<LinkedList<B>>()
+        })
+        .collect()
+  }
+}
+
+impl<A> Applicative<hkt::LinkedList, A> for LinkedList<A> {
+  fn pure(a: A) -> LinkedList<A> {
+    iter::once(a).collect()
+  }
+}
+
+impl<A> Alt<hkt::LinkedList, A> for LinkedList<A> {
+  fn alt(mut self, mut b: Self) -> Self {
+    LinkedList::append(&mut self, &mut b);
+    self
+  }
+}
+deriving!(impl Plus<hkt::LinkedList, A> for LinkedList<A> {..Default});
+
+deriving!(impl<A> Semigroup for LinkedList<A> {..Alt});
+deriving!(impl<A> Monoid for LinkedList<A> {..Default});
+
+impl<A> FoldableIndexed<hkt::LinkedList, usize, A> for LinkedList<A> {
+  fn foldl_idx<B, BAB>(self, f: BAB, b: B) -> B
+    where BAB: F3<B, usize, A, Ret = B>
+  {
+    self.into_iter()
+        .enumerate()
+        .fold(b, |b, (ix, a)| f.call(b, ix, a))
+  }
+
+  fn foldr_idx<B, ABB>(self, f: ABB, b: B) -> B
+    where ABB: F3<usize, A, B, Ret = B>
+  {
+    self.into_iter()
+        .enumerate()
+        .rfold(b, |b, (ix, a)| f.call(ix, a, b))
+  }
+
+  fn foldl_idx_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> B
+    where BAB: F3<B, usize, &'a A, Ret = B>,
+          A: 'a
+  {
+    self.iter()
+        .enumerate()
+        .fold(b, |b, (ix, a)| f.call(b, ix, a))
+  }
+
+  fn foldr_idx_ref<'a, B, ABB>(&'a self, f: ABB, b: B) -> B
+    where ABB: F3<usize, &'a A, B, Ret = B>,
+          A: 'a
+  {
+    self.iter()
+        .enumerate()
+        .rfold(b, |b, (ix, a)| f.call(ix, a, b))
+  }
+
+  fn try_foldl_idx<B, BAB>(self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F3<B, usize, A, Ret = ControlFlow<B, B>>
+  {
+    self.into_iter()
+        .enumerate()
+        .try_fold(b, |b, (ix, a)| f.call(b, ix, a))
+  }
+
+  fn try_foldl_idx_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F3<B, usize, &'a A, Ret = ControlFlow<B, B>>,
+          A: 'a
+  {
+    self.iter()
+        .enumerate()
+        .try_fold(b, |b, (ix, a)| f.call(b, ix, a))
+  }
+}
+deriving!(impl Foldable<hkt::LinkedList, A> for LinkedList<A> {..FoldableIndexed});
+
+#[allow(non_camel_case_types)]
+type push<T> = fn(LinkedList<T>, T) -> LinkedList<T>;
+
+/// curried [`fn@push`] waiting for both arguments
+#[allow(non_camel_case_types)]
+pub type push0<T> = curry2::Curry2<push<T>, Nothing<LinkedList<T>>, Nothing<T>, LinkedList<T>>;
+
+/// curried [`fn@push`] that has a LinkedList and is waiting for the T to push to it
+#[allow(non_camel_case_types)]
+pub type push1<T> = curry2::Curry2<push<T>, Just<LinkedList<T>>, Nothing<T>, LinkedList<T>>;
+
+/// Push an element to the back of a LinkedList
+pub fn push<T>(mut v: LinkedList<T>, t: T) -> LinkedList<T> {
+  v.push_back(t);
+  v
+}
+
+impl<A, B> Traversable<hkt::LinkedList, A, B, push1<B>> for LinkedList<A> {
+  /// ```
+  /// use std::collections::LinkedList;
+  ///
+  /// use naan::prelude::*;
+  ///
+  /// let parse = |ss: LinkedList<&str>| ss.traverse::<hkt::Option, _>(|s: &str| s.parse::<u8>().ok());
+  ///
+  /// assert_eq!(parse(LinkedList::from(["1", "2"])),
+  ///            Some(LinkedList::from([1, 2])));
+  /// assert_eq!(parse(LinkedList::from(["1", "two"])), None);
+  /// ```
+  fn traversem1<Ap, AtoApOfB>(self, f: AtoApOfB) -> Ap::T<LinkedList<B>>
+    where Ap: HKT1,
+          Self: Foldable<hkt::LinkedList, A>,
+          Ap::T<B>: Applicative<Ap, B> + ApplyOnce<Ap, B>,
+          Ap::T<push1<B>>: Applicative<Ap, push1<B>> + ApplyOnce<Ap, push1<B>>,
+          Ap::T<LinkedList<B>>: Applicative<Ap, LinkedList<B>> + ApplyOnce<Ap, LinkedList<B>>,
+          AtoApOfB: F1<A, Ret = Ap::T<B>>,
+          hkt::LinkedList: HKT1<T<A> = Self>
+  {
+    self.foldl(|ap: Ap::T<LinkedList<B>>, a| ap.fmap((push as push<B>).curry()).apply1(f.call(a)),
+               Ap::T::pure(LinkedList::new()))
+  }
+
+  fn traversemm<Ap, AtoApOfB>(self, f: AtoApOfB) -> Ap::T<LinkedList<B>>
+    where Ap: HKT1,
+          Self: Foldable<hkt::LinkedList, A>,
+          B: Clone,
+          Ap::T<B>: Applicative<Ap, B>,
+          Ap::T<push1<B>>: Applicative<Ap, push1<B>>,
+          Ap::T<LinkedList<B>>: Applicative<Ap, LinkedList<B>>,
+          AtoApOfB: F1<A, Ret = Ap::T<B>>,
+          hkt::LinkedList: HKT1<T<A> = Self>
+  {
+    self.foldl(|ap: Ap::T<LinkedList<B>>, a| ap.fmap((push as push<B>).curry()).apply(f.call(a)),
+               Ap::T::pure(LinkedList::new()))
+  }
+}
+
+impl<A> Monad<hkt::LinkedList, A> for LinkedList<A> {
+  fn bind<B, AMB>(self, f: AMB) -> LinkedList<B>
+    where AMB: F1<A, Ret = LinkedList<B>>
+  {
+    let mut out = LinkedList::<B>::new();
+
+    for i in self {
+      LinkedList::append(&mut out, &mut f.call(i));
+    }
+
+    out
+  }
+}
+deriving!(impl MonadHKT for hkt::LinkedList {..Monad});
@@ -1,9 +1,27 @@
+/// Array trait impls
+pub mod array;
+
+/// the Either sum type
+pub mod either;
+
 /// the Identity monad
 pub mod identity;
 
 /// Option trait impls
 pub mod option;
 
+/// the Predicate contravariant functor
+#[cfg(feature = "alloc")]
+pub mod predicate;
+
+/// the Comparator contravariant functor
+#[cfg(feature = "alloc")]
+pub mod comparator;
+
+/// the Equivalence contravariant functor
+#[cfg(feature = "alloc")]
+pub mod equivalence;
+
 /// Result trait impls
 pub mod result;
 
@@ -11,13 +29,49 @@ pub mod result;
 #[cfg(feature = "alloc")]
 pub mod string;
 
+/// the These sum type
+pub mod these;
+
+/// the Validation applicative
+pub mod validation;
+
+/// the Writer monad
+pub mod writer;
+
 /// Unit trait impls
 pub mod unit;
 
+/// Tuple trait impls
+pub mod tuple;
+
 /// Vec trait impls
 #[cfg(feature = "alloc")]
 pub mod vec;
 
+/// the NonEmptyVec collection
+#[cfg(feature = "alloc")]
+pub mod non_empty_vec;
+
+/// [`std::collections::VecDeque`]
+#[cfg(feature = "alloc")]
+pub mod vec_deque;
+
+/// [`std::collections::LinkedList`]
+#[cfg(feature = "alloc")]
+pub mod linked_list;
+
+/// [`std::collections::BinaryHeap`]
+#[cfg(feature = "alloc")]
+pub mod binary_heap;
+
+/// [`std::collections::HashSet`]
+#[cfg(feature = "std")]
+pub mod hash_set;
+
+/// [`std::collections::BTreeSet`]
+#[cfg(feature = "alloc")]
+pub mod btree_set;
+
 /// [`std::collections::HashMap`]
 #[cfg(feature = "std")]
 pub mod hash_map;
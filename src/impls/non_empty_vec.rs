@@ -0,0 +1,339 @@
+use core::iter;
+use core::ops::ControlFlow;
+
+use std_alloc::vec;
+use std_alloc::vec::Vec;
+
+use crate::prelude::*;
+
+/// NonEmptyVec Kinds
+pub mod hkt {
+  use super::*;
+
+  /// [`NonEmptyVec`](super::NonEmptyVec) lifted to an HKT1
+  ///
+  /// (Kind `Type -> Type`)
+  pub struct NonEmptyVec;
+  impl HKT1 for NonEmptyVec {
+    type T<A> = super::NonEmptyVec<A>;
+  }
+}
+
+/// A [`Vec`] that always contains at least one element
+///
+/// Useful for collections where "nothing" is not a meaningful value,
+/// like the errors that caused something to fail or the hosts to retry
+/// a request against.
+///
+/// Since there is no empty `NonEmptyVec`, it is a [`Semigroup`] but not a [`Monoid`],
+/// and it can be collapsed with [`Foldable1`] without an initial value.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// let hosts = NonEmptyVec::new("a.example.com", vec!["b.example.com"]);
+///
+/// assert_eq!(hosts.head(), &"a.example.com");
+/// assert_eq!(hosts.last(), &"b.example.com");
+/// assert_eq!(hosts.fmap(|h: &str| h.len()).reduce(usize::max), 13);
+///
+/// assert_eq!(NonEmptyVec::from_vec(Vec::<u8>::new()), None);
+/// assert_eq!(NonEmptyVec::from_vec(vec![1, 2]),
+///            Some(NonEmptyVec::new(1, vec![2])));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyVec<A> {
+  head: A,
+  tail: Vec<A>,
+}
+
+impl<A> NonEmptyVec<A> {
+  /// Create a `NonEmptyVec` from its first element and the rest
+  pub fn new(head: A, tail: Vec<A>) -> Self {
+    Self { head, tail }
+  }
+
+  /// Create a `NonEmptyVec` containing exactly one element
+  pub fn singleton(head: A) -> Self {
+    Self::new(head, vec![])
+  }
+
+  /// Create a `NonEmptyVec` from a [`Vec`], yielding `None` if the `Vec` is empty.
+  pub fn from_vec(mut vec: Vec<A>) -> Option<Self> {
+    if vec.is_empty() {
+      None
+    } else {
+      let head = vec.remove(0);
+      Some(Self::new(head, vec))
+    }
+  }
+
+  /// Convert into a [`Vec`]
+  pub fn into_vec(self) -> Vec<A> {
+    let mut vec = Vec::with_capacity(self.len());
+    vec.push(self.head);
+    vec.extend(self.tail);
+    vec
+  }
+
+  /// Get the first element
+  pub fn head(&self) -> &A {
+    &self.head
+  }
+
+  /// Get the last element
+  pub fn last(&self) -> &A {
+    self.tail.last().unwrap_or(&self.head)
+  }
+
+  /// Get every element after the first
+  pub fn tail(&self) -> &[A] {
+    &self.tail
+  }
+
+  /// The number of elements (always at least 1)
+  #[allow(clippy::len_without_is_empty)]
+  pub fn len(&self) -> usize {
+    self.tail.len() + 1
+  }
+
+  /// Add an element to the end
+  pub fn push(&mut self, a: A) {
+    self.tail.push(a)
+  }
+
+  /// Iterate over references to the elements
+  pub fn iter(&self) -> impl DoubleEndedIterator<Item = &A> {
+    iter::once(&self.head).chain(self.tail.iter())
+  }
+}
+
+impl<A> IntoIterator for NonEmptyVec<A> {
+  type Item = A;
+  type IntoIter = iter::Chain<iter::Once<A>, vec::IntoIter<A>>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    iter::once(self.head).chain(self.tail)
+  }
+}
+
+impl<A> From<NonEmptyVec<A>> for Vec<A> {
+  fn from(nev: NonEmptyVec<A>) -> Self {
+    nev.into_vec()
+  }
+}
+
+impl<A> Functor<hkt::NonEmptyVec, A> for NonEmptyVec<A> {
+  fn fmap<AB, B>(self, f: AB) -> NonEmptyVec<B>
+    where AB: F1<A, Ret = B>
+  {
+    NonEmptyVec::new(f.call(self.head),
+                     self.tail.into_iter().map(|a| f.call(a)).collect())
+  }
+}
+
+impl<AB> Apply<hkt::NonEmptyVec, AB> for NonEmptyVec<AB> {
+  /// Applies every function to every element, like [`Vec`]
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let fs = NonEmptyVec::new((|n| n + 1) as fn(u8) -> u8, vec![|n| n * 10]);
+  ///
+  /// assert_eq!(fs.apply(NonEmptyVec::new(1, vec![2])),
+  ///            NonEmptyVec::new(2, vec![3, 10, 20]));
+  /// ```
+  fn apply_with<A, B, Cloner>(self, a: NonEmptyVec<A>, cloner: Cloner) -> NonEmptyVec<B>
+    where AB: F1<A, Ret = B>,
+          Cloner: for<'a> F1<&'a A, Ret = A>
+  {
+    let head = self.head.call(cloner.call(&a.head));
+    let head_tail = a.tail.iter().map(|x| self.head.call(cloner.call(x)));
+    let tail = self.tail
+                   .iter()
+                   .flat_map(|f| a.iter().map(|x| f.call(cloner.call(x))));
+
+    NonEmptyVec::new(head, head_tail.chain(tail).collect())
+  }
+}
+
+impl<A> Applicative<hkt::NonEmptyVec, A> for NonEmptyVec<A> {
+  fn pure(a: A) -> NonEmptyVec<A> {
+    NonEmptyVec::singleton(a)
+  }
+}
+
+impl<A> Alt<hkt::NonEmptyVec, A> for NonEmptyVec<A> {
+  fn alt(mut self, b: Self) -> Self {
+    self.tail.extend(b);
+    self
+  }
+}
+
+deriving!(impl<A> Semigroup for NonEmptyVec<A> {..Alt});
+
+impl<A> Monad<hkt::NonEmptyVec, A> for NonEmptyVec<A> {
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let ns = NonEmptyVec::new(1, vec![2]).bind(|n| NonEmptyVec::new(n, vec![n * 10]));
+  ///
+  /// assert_eq!(ns.into_vec(), vec![1, 10, 2, 20]);
+  /// ```
+  fn bind<B, AMB>(self, f: AMB) -> NonEmptyVec<B>
+    where AMB: F1<A, Ret = NonEmptyVec<B>>
+  {
+    let mut out = f.call(self.head);
+
+    for a in self.tail {
+      out.tail.extend(f.call(a));
+    }
+
+    out
+  }
+}
+deriving!(impl MonadHKT for hkt::NonEmptyVec {..Monad});
+
+impl<A> FoldableIndexed<hkt::NonEmptyVec, usize, A> for NonEmptyVec<A> {
+  fn foldl_idx<B, BAB>(self, f: BAB, b: B) -> B
+    where BAB: F3<B, usize, A, Ret = B>
+  {
+    self.into_iter()
+        .enumerate()
+        .fold(b, |b, (ix, a)| f.call(b, ix, a))
+  }
+
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// NonEmptyVec::new(0, vec![1, 2]).foldr_idx(|ix, val, ()| assert_eq!(ix, val), ());
+  /// ```
+  fn foldr_idx<B, ABB>(self, f: ABB, b: B) -> B
+    where ABB: F3<usize, A, B, Ret = B>
+  {
+    let len = self.len();
+    self.into_iter()
+        .rev()
+        .enumerate()
+        .fold(b, |b, (n, a)| f.call(len - 1 - n, a, b))
+  }
+
+  fn foldl_idx_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> B
+    where BAB: F3<B, usize, &'a A, Ret = B>,
+          A: 'a
+  {
+    self.iter()
+        .enumerate()
+        .fold(b, |b, (ix, a)| f.call(b, ix, a))
+  }
+
+  fn foldr_idx_ref<'a, B, ABB>(&'a self, f: ABB, b: B) -> B
+    where ABB: F3<usize, &'a A, B, Ret = B>,
+          A: 'a
+  {
+    let len = self.len();
+    self.iter()
+        .rev()
+        .enumerate()
+        .fold(b, |b, (n, a)| f.call(len - 1 - n, a, b))
+  }
+
+  fn try_foldl_idx<B, BAB>(self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F3<B, usize, A, Ret = ControlFlow<B, B>>
+  {
+    self.into_iter()
+        .enumerate()
+        .try_fold(b, |b, (ix, a)| f.call(b, ix, a))
+  }
+
+  fn try_foldl_idx_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F3<B, usize, &'a A, Ret = ControlFlow<B, B>>,
+          A: 'a
+  {
+    self.iter()
+        .enumerate()
+        .try_fold(b, |b, (ix, a)| f.call(b, ix, a))
+  }
+}
+deriving!(impl Foldable<hkt::NonEmptyVec, A> for NonEmptyVec<A> {..FoldableIndexed});
+
+impl<A> Foldable1<hkt::NonEmptyVec, A> for NonEmptyVec<A> {
+  fn foldl1<B, AB, BAB>(self, init: AB, f: BAB) -> B
+    where AB: F1Once<A, Ret = B>,
+          BAB: F2<B, A, Ret = B>
+  {
+    self.tail
+        .into_iter()
+        .fold(init.call1(self.head), |b, a| f.call(b, a))
+  }
+}
+
+#[allow(non_camel_case_types)]
+type push<T> = fn(NonEmptyVec<T>, T) -> NonEmptyVec<T>;
+
+/// curried [`fn@push`] waiting for both arguments
+#[allow(non_camel_case_types)]
+pub type push0<T> = curry2::Curry2<push<T>, Nothing<NonEmptyVec<T>>, Nothing<T>, NonEmptyVec<T>>;
+
+/// curried [`fn@push`] that has a NonEmptyVec and is waiting for the T to push to it
+#[allow(non_camel_case_types)]
+pub type push1<T> = curry2::Curry2<push<T>, Just<NonEmptyVec<T>>, Nothing<T>, NonEmptyVec<T>>;
+
+/// Push an element to the end of a NonEmptyVec
+pub fn push<T>(mut v: NonEmptyVec<T>, t: T) -> NonEmptyVec<T> {
+  v.push(t);
+  v
+}
+
+impl<A, B> Traversable<hkt::NonEmptyVec, A, B, push1<B>> for NonEmptyVec<A> {
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let parse = |ns: NonEmptyVec<&str>| ns.traverse::<hkt::Option, _>(|s: &str| s.parse::<u8>().ok());
+  ///
+  /// assert_eq!(parse(NonEmptyVec::new("1", vec!["2"])),
+  ///            Some(NonEmptyVec::new(1, vec![2])));
+  /// assert_eq!(parse(NonEmptyVec::new("1", vec!["x"])), None);
+  ///
+  /// // effects happen in order, so errors accumulate in order
+  /// let even = |n: u8| {
+  ///   if n % 2 == 0 {
+  ///     Validation::Valid(n)
+  ///   } else {
+  ///     Validation::Invalid(vec![n])
+  ///   }
+  /// };
+  /// assert_eq!(NonEmptyVec::new(1u8, vec![2, 3]).traverse::<hkt::Validation<Vec<u8>>, _>(even),
+  ///            Validation::Invalid(vec![1, 3]));
+  /// ```
+  fn traversem1<Ap, AtoApOfB>(self, f: AtoApOfB) -> Ap::T<NonEmptyVec<B>>
+    where Ap: HKT1,
+          Self: Foldable<hkt::NonEmptyVec, A>,
+          Ap::T<B>: Applicative<Ap, B> + ApplyOnce<Ap, B>,
+          Ap::T<push1<B>>: Applicative<Ap, push1<B>> + ApplyOnce<Ap, push1<B>>,
+          Ap::T<NonEmptyVec<B>>: Applicative<Ap, NonEmptyVec<B>> + ApplyOnce<Ap, NonEmptyVec<B>>,
+          AtoApOfB: F1<A, Ret = Ap::T<B>>,
+          hkt::NonEmptyVec: HKT1<T<A> = Self>
+  {
+    let head = f.call(self.head).fmap(NonEmptyVec::singleton);
+    self.tail.into_iter().fold(head, |ap, a| {
+                           ap.fmap((push as push<B>).curry()).apply1(f.call(a))
+                         })
+  }
+
+  fn traversemm<Ap, AtoApOfB>(self, f: AtoApOfB) -> Ap::T<NonEmptyVec<B>>
+    where Ap: HKT1,
+          Self: Foldable<hkt::NonEmptyVec, A>,
+          B: Clone,
+          Ap::T<B>: Applicative<Ap, B>,
+          Ap::T<push1<B>>: Applicative<Ap, push1<B>>,
+          Ap::T<NonEmptyVec<B>>: Applicative<Ap, NonEmptyVec<B>>,
+          AtoApOfB: F1<A, Ret = Ap::T<B>>,
+          hkt::NonEmptyVec: HKT1<T<A> = Self>
+  {
+    let head = f.call(self.head).fmap(NonEmptyVec::singleton);
+    self.tail.into_iter().fold(head, |ap, a| {
+                           ap.fmap((push as push<B>).curry()).apply(f.call(a))
+                         })
+  }
+}
@@ -1,3 +1,5 @@
+use core::ops::ControlFlow;
+
 use crate::prelude::*;
 
 /// Option Kinds
@@ -126,3 +128,18 @@ impl<A> MonadOnce<hkt::Option, A> for Option<A> {
   }
 }
 deriving!(impl Monad<hkt::Option, A> for Option<A> {..MonadOnce});
+deriving!(impl MonadHKT for hkt::Option {..Monad});
+
+impl<A> MonadRec<hkt::Option, A> for Option<A> {
+  fn tail_rec_m<S, F>(seed: S, f: F) -> Option<A>
+    where F: F1<S, Ret = Option<ControlFlow<A, S>>>
+  {
+    let mut s = seed;
+    loop {
+      match f.call(s)? {
+        | ControlFlow::Break(a) => return Some(a),
+        | ControlFlow::Continue(next) => s = next,
+      }
+    }
+  }
+}
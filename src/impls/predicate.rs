@@ -0,0 +1,174 @@
+use std_alloc::boxed::Box;
+
+use crate::prelude::*;
+
+/// Predicate Kinds
+pub mod hkt {
+  use core::marker::PhantomData;
+
+  use super::*;
+
+  /// [`Predicate`](super::Predicate) lifted to an HKT1
+  ///
+  /// (Kind `Type -> Type`)
+  pub struct Predicate<'a>(PhantomData<&'a ()>);
+  impl<'a> HKT1 for Predicate<'a> {
+    type T<A> = super::Predicate<'a, A>;
+  }
+}
+
+/// A test that values of type `A` either pass or fail
+///
+/// Predicates are [`Contravariant`]; a predicate on `A` can be
+/// adapted to a predicate on `B` given a function from `B -> A`.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// let even = Predicate::new(|n: u32| n % 2 == 0);
+/// let even_len = even.contramap(|s: &str| s.len() as u32);
+///
+/// let words = vec!["ab", "abc", "abcd"];
+/// let words = words.into_iter()
+///                  .filter(|s| even_len.test(s))
+///                  .collect::<Vec<_>>();
+///
+/// assert_eq!(words, vec!["ab", "abcd"]);
+/// ```
+pub struct Predicate<'a, A>(pub Box<dyn Fn(A) -> bool + 'a>);
+
+impl<'a, A> Predicate<'a, A> {
+  /// Create a `Predicate` from a function
+  pub fn new<F>(f: F) -> Self
+    where F: F1<A, Ret = bool> + 'a
+  {
+    Predicate(Box::new(move |a| f.call(a)))
+  }
+
+  /// Does `a` pass this predicate?
+  pub fn test(&self, a: A) -> bool {
+    (self.0)(a)
+  }
+
+  /// A predicate that passes when both this and `other` pass
+  ///
+  /// `other` is not tested when this predicate fails.
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let small_even = Predicate::new(|n: u32| n % 2 == 0).and(Predicate::new(|n: u32| n < 10));
+  ///
+  /// assert!(small_even.test(4));
+  /// assert!(!small_even.test(5));
+  /// assert!(!small_even.test(12));
+  /// ```
+  pub fn and(self, other: Self) -> Self
+    where A: Clone + 'a
+  {
+    Predicate(Box::new(move |a: A| self.test(a.clone()) && other.test(a)))
+  }
+
+  /// A predicate that passes when either this or `other` pass
+  ///
+  /// `other` is not tested when this predicate passes.
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let blank =
+  ///   Predicate::new(|s: &str| s.is_empty()).or(Predicate::new(|s: &str| s.trim().is_empty()));
+  ///
+  /// assert!(blank.test(""));
+  /// assert!(blank.test("  "));
+  /// assert!(!blank.test(" a "));
+  /// ```
+  pub fn or(self, other: Self) -> Self
+    where A: Clone + 'a
+  {
+    Predicate(Box::new(move |a: A| self.test(a.clone()) || other.test(a)))
+  }
+
+  /// A predicate that passes when this one fails
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let odd = Predicate::new(|n: u32| n % 2 == 0).not();
+  ///
+  /// assert!(odd.test(3));
+  /// assert!(!odd.test(4));
+  /// ```
+  #[allow(clippy::should_implement_trait)]
+  pub fn not(self) -> Self
+    where A: 'a
+  {
+    Predicate(Box::new(move |a| !self.test(a)))
+  }
+}
+
+/// Predicates combined with [`Predicate::or`]
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// let forbidden = vec!["root", "admin"];
+/// let is_forbidden =
+///   forbidden.fold_map(|name: &'static str| Any(Predicate::new(move |s: &str| s == name)));
+///
+/// assert!(is_forbidden.0.test("admin"));
+/// assert!(!is_forbidden.0.test("bob"));
+/// ```
+impl<'a, A> Semigroup for Any<Predicate<'a, A>> where A: Clone + 'a
+{
+  fn append(self, b: Self) -> Self {
+    Any(self.0.or(b.0))
+  }
+}
+
+impl<'a, A> Monoid for Any<Predicate<'a, A>> where A: Clone + 'a
+{
+  fn identity() -> Self {
+    Any(Predicate::new(|_| false))
+  }
+}
+
+/// Predicates combined with [`Predicate::and`]
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// let rules = vec![3usize, 8];
+/// let valid = rules.fold_map(|min: usize| All(Predicate::new(move |s: &str| s.len() >= min)));
+///
+/// assert!(valid.0.test("password"));
+/// assert!(!valid.0.test("pass"));
+/// ```
+impl<'a, A> Semigroup for All<Predicate<'a, A>> where A: Clone + 'a
+{
+  fn append(self, b: Self) -> Self {
+    All(self.0.and(b.0))
+  }
+}
+
+impl<'a, A> Monoid for All<Predicate<'a, A>> where A: Clone + 'a
+{
+  fn identity() -> Self {
+    All(Predicate::new(|_| true))
+  }
+}
+
+impl<'a, A> Contravariant<'a, hkt::Predicate<'a>, A> for Predicate<'a, A> where A: 'a
+{
+  fn contramap<BA, B>(self, f: BA) -> Predicate<'a, B>
+    where BA: F1<B, Ret = A> + 'a
+  {
+    Predicate(Box::new(move |b| self.test(f.call(b))))
+  }
+}
+
+impl<'a, A> core::fmt::Debug for Predicate<'a, A> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    f.write_str("Predicate(..)")
+  }
+}
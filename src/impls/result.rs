@@ -1,3 +1,5 @@
+use core::ops::ControlFlow;
+
 use crate::prelude::*;
 
 /// Result Kinds
@@ -24,6 +26,7 @@ pub mod hkt {
   }
 }
 
+/// Extra utilities for [`Result`]
 pub trait ResultExt<T, E>
   where Self: Sized
 {
@@ -49,6 +52,19 @@ pub trait ResultExt<T, E>
   /// `T` and `R` if the input Result and return value of the function is Ok
   fn zip<R, F>(self, f: F) -> Result<(T, R), E>
     where F: for<'a> F1Once<&'a T, Ret = Result<R, E>>;
+
+  /// Convert this Result to a [`Validation`], `Ok` becoming `Valid`
+  /// and `Err` becoming `Invalid`.
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// assert_eq!(Result::<u8, ()>::Ok(1).into_validation(),
+  ///            Validation::Valid(1));
+  /// assert_eq!(Result::<u8, ()>::Err(()).into_validation(),
+  ///            Validation::Invalid(()));
+  /// ```
+  fn into_validation(self) -> Validation<E, T>;
 }
 
 impl<T, E> ResultExt<T, E> for Result<T, E> {
@@ -97,6 +113,10 @@ impl<T, E> ResultExt<T, E> for Result<T, E> {
   {
     self.bind1(|t| f.call1(&t).map(|r| (t, r)))
   }
+
+  fn into_validation(self) -> Validation<E, T> {
+    Validation::from(self)
+  }
 }
 
 impl<A, E> FunctorOnce<hkt::ResultOk<E>, A> for Result<A, E> {
@@ -187,6 +207,21 @@ impl<A, E> MonadOnce<hkt::ResultOk<E>, A> for Result<A, E> {
   }
 }
 deriving!(impl<E> Monad<hkt::ResultOk<E>, A> for Result<A, E> {..MonadOnce});
+deriving!(impl<E> MonadHKT for hkt::ResultOk<E> {..Monad});
+
+impl<A, E> MonadRec<hkt::ResultOk<E>, A> for Result<A, E> {
+  fn tail_rec_m<S, F>(seed: S, f: F) -> Result<A, E>
+    where F: F1<S, Ret = Result<ControlFlow<A, S>, E>>
+  {
+    let mut s = seed;
+    loop {
+      match f.call(s)? {
+        | ControlFlow::Break(a) => return Ok(a),
+        | ControlFlow::Continue(next) => s = next,
+      }
+    }
+  }
+}
 
 impl<A, E> BifunctorOnce<hkt::Result, A, E> for Result<A, E> {
   fn bimap1<AB, BB, FA, FB>(self, fa: FA, fb: FB) -> <hkt::Result as HKT2>::T<AB, BB>
@@ -200,3 +235,42 @@ impl<A, E> BifunctorOnce<hkt::Result, A, E> for Result<A, E> {
   }
 }
 deriving!(impl Bifunctor<hkt::Result, A, E> for Result<A, E> {..BifunctorOnce});
+
+impl<A, E> Bifoldable<hkt::Result, A, E> for Result<A, E> {
+  fn bifoldl<C, CAC, CEC>(self, fa: CAC, fe: CEC, c: C) -> C
+    where CAC: F2<C, A, Ret = C>,
+          CEC: F2<C, E, Ret = C>
+  {
+    match self {
+      | Ok(a) => fa.call(c, a),
+      | Err(e) => fe.call(c, e),
+    }
+  }
+
+  fn bifoldr<C, ACC, ECC>(self, fa: ACC, fe: ECC, c: C) -> C
+    where ACC: F2<A, C, Ret = C>,
+          ECC: F2<E, C, Ret = C>
+  {
+    match self {
+      | Ok(a) => fa.call(a, c),
+      | Err(e) => fe.call(e, c),
+    }
+  }
+}
+
+impl<A, E, A2, E2> Bitraversable<hkt::Result, A, E, A2, E2, (), ()> for Result<A, E> {
+  fn bitraverse<Ap, FA, FE>(self, fa: FA, fe: FE) -> Ap::T<Result<A2, E2>>
+    where Ap: HKT1,
+          Ap::T<A2>: Applicative<Ap, A2> + ApplyOnce<Ap, A2>,
+          Ap::T<E2>: Applicative<Ap, E2> + ApplyOnce<Ap, E2>,
+          Ap::T<()>: Applicative<Ap, ()> + ApplyOnce<Ap, ()>,
+          Ap::T<Result<A2, E2>>: Applicative<Ap, Result<A2, E2>> + ApplyOnce<Ap, Result<A2, E2>>,
+          FA: F1<A, Ret = Ap::T<A2>>,
+          FE: F1<E, Ret = Ap::T<E2>>
+  {
+    match self {
+      | Ok(a) => fa.call(a).fmap(Ok),
+      | Err(e) => fe.call(e).fmap(Err),
+    }
+  }
+}
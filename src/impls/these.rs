@@ -0,0 +1,338 @@
+use crate::prelude::*;
+
+/// These Kinds
+pub mod hkt {
+  use core::marker::PhantomData;
+
+  use crate::prelude::*;
+
+  /// [`These`](super::These) lifted to an HKT1
+  /// with the `This` type pinned to some `A`.
+  ///
+  /// (Kind `Type -> Type`)
+  pub struct TheseRight<A>(PhantomData<A>);
+  impl<A> HKT1 for TheseRight<A> {
+    type T<B> = super::These<A, B>;
+  }
+
+  /// [`These`](super::These) lifted to an HKT2
+  ///
+  /// (Kind `Type -> Type -> Type`)
+  pub struct These;
+  impl HKT2 for These {
+    type T<A, B> = super::These<A, B>;
+  }
+}
+
+/// A value of type `A`, a value of type `B`, or both.
+///
+/// Where [`Result`] is either a success or a failure, `These` can
+/// also be a success that came with some problems (`Both`).
+///
+/// `These` is right-biased; [`Functor`] and [`Monad`] act on the `B` value,
+/// and sequencing with [`bind`](Monad::bind) or [`apply`](Apply::apply) [`append`](Semigroup::append)s
+/// the `A` values along the way. Unlike [`Result`], the computation only stops early on `This`.
+///
+/// ```
+/// use naan::impls::these::These::{Both, That, This};
+/// use naan::prelude::*;
+///
+/// fn parse_port(s: &str) -> These<Vec<String>, u16> {
+///   match s.parse::<u16>() {
+///     | Ok(p) if p < 1024 => Both(vec![format!("{p} is a privileged port")], p),
+///     | Ok(p) => That(p),
+///     | Err(e) => This(vec![format!("{e}")]),
+///   }
+/// }
+///
+/// fn check_open(p: u16) -> These<Vec<String>, u16> {
+///   if p == 22 {
+///     Both(vec![format!("22 is usually ssh")], p)
+///   } else {
+///     That(p)
+///   }
+/// }
+///
+/// assert_eq!(parse_port("8080").bind(check_open), That(8080));
+/// assert_eq!(parse_port("22").bind(check_open),
+///            Both(vec![format!("22 is a privileged port"),
+///                      format!("22 is usually ssh")],
+///                 22));
+/// assert!(parse_port("x").bind(check_open).is_this());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum These<A, B> {
+  /// Only an `A`
+  This(A),
+  /// Only a `B`
+  That(B),
+  /// Both an `A` and a `B`
+  Both(A, B),
+}
+
+impl<A, B> These<A, B> {
+  /// Is this `This`?
+  pub fn is_this(&self) -> bool {
+    matches!(self, These::This(_))
+  }
+
+  /// Is this `That`?
+  pub fn is_that(&self) -> bool {
+    matches!(self, These::That(_))
+  }
+
+  /// Is this `Both`?
+  pub fn is_both(&self) -> bool {
+    matches!(self, These::Both(_, _))
+  }
+
+  /// Get the `A` value, if there is one
+  pub fn this(self) -> Option<A> {
+    self.into_options().0
+  }
+
+  /// Get the `B` value, if there is one
+  pub fn that(self) -> Option<B> {
+    self.into_options().1
+  }
+
+  /// Split into the `A` and `B` values that are present
+  pub fn into_options(self) -> (Option<A>, Option<B>) {
+    match self {
+      | These::This(a) => (Some(a), None),
+      | These::That(b) => (None, Some(b)),
+      | These::Both(a, b) => (Some(a), Some(b)),
+    }
+  }
+
+  /// Create a `These` from an optional `A` and an optional `B`,
+  /// yielding `None` if neither are present.
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// assert_eq!(These::from_options(Some(1), Some("a")),
+  ///            Some(These::Both(1, "a")));
+  /// assert_eq!(These::<u8, &str>::from_options(None, None), None);
+  /// ```
+  pub fn from_options(a: Option<A>, b: Option<B>) -> Option<Self> {
+    match (a, b) {
+      | (Some(a), Some(b)) => Some(These::Both(a, b)),
+      | (Some(a), None) => Some(These::This(a)),
+      | (None, Some(b)) => Some(These::That(b)),
+      | (None, None) => None,
+    }
+  }
+
+  /// Swap `This` and `That`
+  pub fn swap(self) -> These<B, A> {
+    match self {
+      | These::This(a) => These::That(a),
+      | These::That(b) => These::This(b),
+      | These::Both(a, b) => These::Both(b, a),
+    }
+  }
+
+  /// Borrow the contents of this `These`
+  pub fn as_ref(&self) -> These<&A, &B> {
+    match self {
+      | These::This(a) => These::This(a),
+      | These::That(b) => These::That(b),
+      | These::Both(a, b) => These::Both(a, b),
+    }
+  }
+}
+
+impl<A, B> From<Result<B, A>> for These<A, B> {
+  fn from(r: Result<B, A>) -> Self {
+    match r {
+      | Ok(b) => These::That(b),
+      | Err(a) => These::This(a),
+    }
+  }
+}
+
+impl<A, B> From<Either<A, B>> for These<A, B> {
+  fn from(e: Either<A, B>) -> Self {
+    match e {
+      | Either::Left(a) => These::This(a),
+      | Either::Right(b) => These::That(b),
+    }
+  }
+}
+
+impl<A, B> Semigroup for These<A, B>
+  where A: Semigroup,
+        B: Semigroup
+{
+  /// Combine the `A`s and the `B`s of both sides
+  ///
+  /// ```
+  /// use naan::impls::these::These::{Both, That, This};
+  /// use naan::prelude::*;
+  ///
+  /// type T = These<Vec<u8>, String>;
+  ///
+  /// assert_eq!(T::This(vec![1]).append(T::That(format!("a"))),
+  ///            Both(vec![1], format!("a")));
+  /// assert_eq!(T::Both(vec![1], format!("a")).append(T::Both(vec![2], format!("b"))),
+  ///            Both(vec![1, 2], format!("ab")));
+  /// assert_eq!(T::This(vec![1]).append(T::This(vec![2])), This(vec![1, 2]));
+  /// ```
+  fn append(self, b: Self) -> Self {
+    let (a1, b1) = self.into_options();
+    let (a2, b2) = b.into_options();
+
+    match (a1.append(a2), b1.append(b2)) {
+      | (Some(a), Some(b)) => These::Both(a, b),
+      | (Some(a), None) => These::This(a),
+      | (None, Some(b)) => These::That(b),
+      | (None, None) => unreachable!("both sides contain at least one value"),
+    }
+  }
+}
+
+impl<X, A> FunctorOnce<hkt::TheseRight<X>, A> for These<X, A> {
+  fn fmap1<AB, B>(self, f: AB) -> These<X, B>
+    where AB: F1Once<A, Ret = B>
+  {
+    match self {
+      | These::This(x) => These::This(x),
+      | These::That(a) => These::That(f.call1(a)),
+      | These::Both(x, a) => These::Both(x, f.call1(a)),
+    }
+  }
+}
+deriving!(impl<X> Functor<hkt::TheseRight<X>, A> for These<X, A> {..FunctorOnce});
+
+impl<X, AB> ApplyOnce<hkt::TheseRight<X>, AB> for These<X, AB> where X: Semigroup
+{
+  fn apply1<A, B>(self, a: These<X, A>) -> These<X, B>
+    where AB: F1Once<A, Ret = B>
+  {
+    self.bind1(|f: AB| a.fmap1(f))
+  }
+}
+
+impl<X, AB> Apply<hkt::TheseRight<X>, AB> for These<X, AB> where X: Semigroup
+{
+  fn apply_with<A, B, Cloner>(self, a: These<X, A>, _: Cloner) -> These<X, B>
+    where AB: F1<A, Ret = B>,
+          Cloner: for<'a> F1<&'a A, Ret = A>
+  {
+    self.apply1(a)
+  }
+}
+
+impl<X, A> Applicative<hkt::TheseRight<X>, A> for These<X, A> where X: Semigroup
+{
+  fn pure(a: A) -> These<X, A> {
+    These::That(a)
+  }
+}
+
+impl<X, A> MonadOnce<hkt::TheseRight<X>, A> for These<X, A> where X: Semigroup
+{
+  fn bind1<B, AMB>(self, f: AMB) -> These<X, B>
+    where AMB: F1Once<A, Ret = These<X, B>>
+  {
+    match self {
+      | These::This(x) => These::This(x),
+      | These::That(a) => f.call1(a),
+      | These::Both(x, a) => match f.call1(a) {
+        | These::This(x2) => These::This(x.append(x2)),
+        | These::That(b) => These::Both(x, b),
+        | These::Both(x2, b) => These::Both(x.append(x2), b),
+      },
+    }
+  }
+}
+
+impl<X, A> Monad<hkt::TheseRight<X>, A> for These<X, A> where X: Semigroup
+{
+  fn bind<B, AMB>(self, f: AMB) -> These<X, B>
+    where AMB: F1<A, Ret = These<X, B>>
+  {
+    self.bind1(f)
+  }
+}
+deriving!(impl<X> MonadHKT for hkt::TheseRight<X> where X: Semigroup {..Monad});
+
+impl<A, B> BifunctorOnce<hkt::These, A, B> for These<A, B> {
+  fn bimap1<A2, B2, FA, FB>(self, fa: FA, fb: FB) -> These<A2, B2>
+    where FA: F1Once<A, Ret = A2>,
+          FB: F1Once<B, Ret = B2>
+  {
+    match self {
+      | These::This(a) => These::This(fa.call1(a)),
+      | These::That(b) => These::That(fb.call1(b)),
+      | These::Both(a, b) => These::Both(fa.call1(a), fb.call1(b)),
+    }
+  }
+}
+deriving!(impl Bifunctor<hkt::These, A, B> for These<A, B> {..BifunctorOnce});
+
+impl<A, B> Bifoldable<hkt::These, A, B> for These<A, B> {
+  fn bifoldl<C, CAC, CBC>(self, fa: CAC, fb: CBC, c: C) -> C
+    where CAC: F2<C, A, Ret = C>,
+          CBC: F2<C, B, Ret = C>
+  {
+    match self {
+      | These::This(a) => fa.call(c, a),
+      | These::That(b) => fb.call(c, b),
+      | These::Both(a, b) => fb.call(fa.call(c, a), b),
+    }
+  }
+
+  fn bifoldr<C, ACC, BCC>(self, fa: ACC, fb: BCC, c: C) -> C
+    where ACC: F2<A, C, Ret = C>,
+          BCC: F2<B, C, Ret = C>
+  {
+    match self {
+      | These::This(a) => fa.call(a, c),
+      | These::That(b) => fb.call(b, c),
+      | These::Both(a, b) => fa.call(a, fb.call(b, c)),
+    }
+  }
+}
+
+#[allow(non_camel_case_types)]
+type both<A, B> = fn(A, B) -> These<A, B>;
+
+/// curried [`These::Both`] that has an `A` and is waiting for a `B`
+#[allow(non_camel_case_types)]
+pub type both1<A, B> = curry2::Applied1<both<A, B>, A, B, These<A, B>>;
+
+impl<A, B, A2, B2> Bitraversable<hkt::These, A, B, A2, B2, both1<A2, B2>, ()> for These<A, B> {
+  /// ```
+  /// use naan::impls::these::These::{Both, This};
+  /// use naan::prelude::*;
+  ///
+  /// let parse = |t: These<&str, &str>| {
+  ///   t.bitraverse::<hkt::Option, _, _>(|s: &str| s.parse::<u8>().ok(),
+  ///                                     |s: &str| s.parse::<bool>().ok())
+  /// };
+  ///
+  /// assert_eq!(parse(Both("1", "true")), Some(Both(1, true)));
+  /// assert_eq!(parse(Both("1", "yes")), None);
+  /// assert_eq!(parse(This("1")), Some(This(1)));
+  /// ```
+  fn bitraverse<Ap, FA, FB>(self, fa: FA, fb: FB) -> Ap::T<These<A2, B2>>
+    where Ap: HKT1,
+          Ap::T<A2>: Applicative<Ap, A2> + ApplyOnce<Ap, A2>,
+          Ap::T<B2>: Applicative<Ap, B2> + ApplyOnce<Ap, B2>,
+          Ap::T<both1<A2, B2>>: Applicative<Ap, both1<A2, B2>> + ApplyOnce<Ap, both1<A2, B2>>,
+          Ap::T<()>: Applicative<Ap, ()> + ApplyOnce<Ap, ()>,
+          Ap::T<These<A2, B2>>: Applicative<Ap, These<A2, B2>> + ApplyOnce<Ap, These<A2, B2>>,
+          FA: F1<A, Ret = Ap::T<A2>>,
+          FB: F1<B, Ret = Ap::T<B2>>
+  {
+    match self {
+      | These::This(a) => fa.call(a).fmap(These::This),
+      | These::That(b) => fb.call(b).fmap(These::That),
+      | These::Both(a, b) => fa.call(a)
+                               .fmap((These::Both as both<A2, B2>).curry())
+                               .apply1(fb.call(b)),
+    }
+  }
+}
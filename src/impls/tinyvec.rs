@@ -1,4 +1,4 @@
-use std::convert::identity as id;
+use core::ops::ControlFlow;
 
 use tinyvec::ArrayVec;
 
@@ -34,8 +34,8 @@ impl<const N: usize, AB> Apply<hkt::ArrayVec<N>, AB> for ArrayVec<[Option<AB>; N
           Cloner: for<'a> F1<&'a A, Ret = A>
   {
     self.into_iter()
-        .filter_map(|atob| atob)
-        .map(|atob| {
+        .flatten()
+        .flat_map(|atob| {
           as_.iter()
              .map(|a| {
                a.as_ref()
@@ -44,7 +44,6 @@ impl<const N: usize, AB> Apply<hkt::ArrayVec<N>, AB> for ArrayVec<[Option<AB>; N
              })
              .collect::<ArrayVec<[Option<B>; N]>>()
         })
-        .flatten()
         .collect()
   }
 }
@@ -84,15 +83,13 @@ impl<A, const N: usize> Foldable<hkt::ArrayVec<N>, A> for ArrayVec<[Option<A>; N
   fn foldl<B, BAB>(self, f: BAB, b: B) -> B
     where BAB: F2<B, A, Ret = B>
   {
-    self.into_iter().filter_map(id).fold(b, |b, a| f.call(b, a))
+    self.into_iter().flatten().fold(b, |b, a| f.call(b, a))
   }
 
   fn foldr<B, ABB>(self, f: ABB, b: B) -> B
     where ABB: F2<A, B, Ret = B>
   {
-    self.into_iter()
-        .filter_map(id)
-        .rfold(b, |b, a| f.call(a, b))
+    self.into_iter().flatten().rfold(b, |b, a| f.call(a, b))
   }
 
   fn foldl_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> B
@@ -112,6 +109,21 @@ impl<A, const N: usize> Foldable<hkt::ArrayVec<N>, A> for ArrayVec<[Option<A>; N
         .filter_map(Option::as_ref)
         .rfold(b, |b, a| f.call(a, b))
   }
+
+  fn try_foldl<B, BAB>(self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F2<B, A, Ret = ControlFlow<B, B>>
+  {
+    self.into_iter().flatten().try_fold(b, |b, a| f.call(b, a))
+  }
+
+  fn try_foldl_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F2<B, &'a A, Ret = ControlFlow<B, B>>,
+          A: 'a
+  {
+    self.iter()
+        .filter_map(Option::as_ref)
+        .try_fold(b, |b, a| f.call(b, a))
+  }
 }
 
 #[allow(non_camel_case_types)]
@@ -152,8 +164,14 @@ impl<A, B, const N: usize> Traversable<hkt::ArrayVec<N>, A, B, append1<N, B>>
           AtoApOfB: F1<A, Ret = Ap::T<B>>,
           hkt::ArrayVec<N>: HKT1<T<A> = Self>
   {
-    self.foldl(|ap, a| f.call(a).fmap((append as append<N, B>).curry()).apply1(ap),
+    // Folding from the right keeps `f`'s effects in input order,
+    // but pushes the results in reverse.
+    self.foldr(|a, ap| f.call(a).fmap((append as append<N, B>).curry()).apply1(ap),
                Ap::T::pure(ArrayVec::<[Option<B>; N]>::identity()))
+        .fmap(|mut v: ArrayVec<[Option<B>; N]>| {
+          v.reverse();
+          v
+        })
   }
 
   fn traversemm<Ap, AtoApOfB>(self, f: AtoApOfB) -> Ap::T<ArrayVec<[Option<B>; N]>>
@@ -166,8 +184,14 @@ impl<A, B, const N: usize> Traversable<hkt::ArrayVec<N>, A, B, append1<N, B>>
           AtoApOfB: F1<A, Ret = Ap::T<B>>,
           hkt::ArrayVec<N>: HKT1<T<A> = Self>
   {
-    self.foldl(|ap, a| f.call(a).fmap((append as append<N, B>).curry()).apply(ap),
+    // Folding from the right keeps `f`'s effects in input order,
+    // but pushes the results in reverse.
+    self.foldr(|a, ap| f.call(a).fmap((append as append<N, B>).curry()).apply(ap),
                Ap::T::pure(ArrayVec::<[Option<B>; N]>::identity()))
+        .fmap(|mut v: ArrayVec<[Option<B>; N]>| {
+          v.reverse();
+          v
+        })
   }
 }
 
@@ -177,9 +201,71 @@ impl<A, const N: usize> Monad<hkt::ArrayVec<N>, A> for ArrayVec<[Option<A>; N]>
   {
     let mut out = ArrayVec::empty();
 
-    for i in self {
-      if let Some(i) = i {
-        ArrayVec::append(&mut out, &mut f.call(i));
+    for i in self.into_iter().flatten() {
+      ArrayVec::append(&mut out, &mut f.call(i));
+    }
+
+    out
+  }
+}
+
+impl<A, const N: usize> MonadRec<hkt::ArrayVec<N>, A> for ArrayVec<[Option<A>; N]> {
+  /// Explores the tree of seeds depth-first, yielding results in the same
+  /// order as the equivalent recursive `bind`.
+  ///
+  /// Pending seeds are kept on the heap, so any amount of fan-out is fine.
+  /// Without the `alloc` feature they share a stack of `N` slots, and
+  /// exploration stops early (returning the results found so far) once a
+  /// step's seeds no longer fit.
+  ///
+  /// ```
+  /// use core::ops::ControlFlow::{Break, Continue};
+  ///
+  /// use naan::prelude::*;
+  /// use tinyvec::{array_vec, ArrayVec};
+  ///
+  /// type Steps = ArrayVec<[Option<core::ops::ControlFlow<u8, u8>>; 2]>;
+  ///
+  /// let step = |n: u8| -> Steps {
+  ///   match n {
+  ///     | 0 => array_vec!(_ => Some(Continue(1)), Some(Continue(2))),
+  ///     | 1 => array_vec!(_ => Some(Continue(10)), Some(Continue(10))),
+  ///     | 2 => array_vec!(_ => Some(Break(3))),
+  ///     | _ => array_vec!(),
+  ///   }
+  /// };
+  ///
+  /// let found = ArrayVec::<[Option<u8>; 2]>::tail_rec_m(0, step);
+  /// assert_eq!(found, array_vec!([Option<u8>; 2] => Some(3)));
+  /// ```
+  ///
+  /// Panics if more than `N` results accumulate.
+  fn tail_rec_m<S, F>(seed: S, f: F) -> ArrayVec<[Option<A>; N]>
+    where F: F1<S, Ret = ArrayVec<[Option<ControlFlow<A, S>>; N]>>
+  {
+    let mut out = ArrayVec::empty();
+
+    #[cfg(feature = "alloc")]
+    let mut stack = std_alloc::vec::Vec::new();
+    #[cfg(not(feature = "alloc"))]
+    let mut stack = ArrayVec::<[Option<ControlFlow<A, S>>; N]>::new();
+
+    stack.extend(f.call(seed).into_iter().rev());
+
+    while let Some(step) = stack.pop() {
+      match step {
+        | Some(ControlFlow::Break(a)) => out.push(Some(a)),
+        | Some(ControlFlow::Continue(s)) => {
+          let next = f.call(s);
+
+          #[cfg(not(feature = "alloc"))]
+          if next.len() > N - stack.len() {
+            break;
+          }
+
+          stack.extend(next.into_iter().rev())
+        },
+        | None => (),
       }
     }
 
@@ -187,6 +273,34 @@ impl<A, const N: usize> Monad<hkt::ArrayVec<N>, A> for ArrayVec<[Option<A>; N]>
   }
 }
 
+impl<const N: usize> MonadHKT for hkt::ArrayVec<N> {
+  fn pure<A>(a: A) -> ArrayVec<[Option<A>; N]> {
+    ArrayVec::<[Option<A>; N]>::pure(a)
+  }
+
+  fn fmap<A, B, AB>(ta: ArrayVec<[Option<A>; N]>, f: AB) -> ArrayVec<[Option<B>; N]>
+    where AB: F1<A, Ret = B>
+  {
+    ta.fmap(f)
+  }
+
+  fn apply_with<AB, A, B, Cloner>(tab: ArrayVec<[Option<AB>; N]>,
+                                  ta: ArrayVec<[Option<A>; N]>,
+                                  cloner: Cloner)
+                                  -> ArrayVec<[Option<B>; N]>
+    where AB: F1<A, Ret = B>,
+          Cloner: for<'a> F1<&'a A, Ret = A>
+  {
+    tab.apply_with(ta, cloner)
+  }
+
+  fn bind<A, B, AMB>(ta: ArrayVec<[Option<A>; N]>, f: AMB) -> ArrayVec<[Option<B>; N]>
+    where AMB: F1<A, Ret = ArrayVec<[Option<B>; N]>>
+  {
+    ta.bind(f)
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -201,7 +315,7 @@ mod tests {
     let v = v.append(V::pure(1)).append(V::pure(2)).append(V::pure(3));
     assert_eq!(v, tinyvec::array_vec!(_ => Some(1), Some(2), Some(3)));
 
-    let sum = v.clone().foldl(|sum, n| sum + n, 0);
+    let sum = v.foldl(|sum, n| sum + n, 0);
     assert_eq!(sum, 6);
 
     type R = Result<u32, ()>;
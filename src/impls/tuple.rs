@@ -0,0 +1,295 @@
+use crate::prelude::*;
+
+/// Tuple Kinds
+pub mod hkt {
+  use core::marker::PhantomData;
+
+  use crate::prelude::*;
+
+  /// 2-tuples lifted to an HKT1 with the left type
+  /// pinned to some `L`.
+  ///
+  /// Like [`Writer`](crate::prelude::Writer), this is right-biased and
+  /// [`append`](Semigroup::append)s the left values when sequenced.
+  ///
+  /// (Kind `Type -> Type`)
+  pub struct Tuple2<L>(PhantomData<L>);
+  impl<L> HKT1 for Tuple2<L> {
+    type T<A> = (L, A);
+  }
+
+  /// 2-tuples lifted to an HKT2
+  ///
+  /// (Kind `Type -> Type -> Type`)
+  pub struct Pair;
+  impl HKT2 for Pair {
+    type T<A, B> = (A, B);
+  }
+}
+
+macro_rules! tuple_semigroup {
+  ($($t:ident $a:ident $b:ident),+) => {
+    impl<$($t),+> Semigroup for ($($t,)+) where $($t: Semigroup),+
+    {
+      fn append(self, b: Self) -> Self {
+        let ($($a,)+) = self;
+        let ($($b,)+) = b;
+        ($($a.append($b),)+)
+      }
+    }
+
+    impl<$($t),+> Monoid for ($($t,)+) where $($t: Monoid),+
+    {
+      fn identity() -> Self {
+        ($($t::identity(),)+)
+      }
+    }
+  };
+}
+
+tuple_semigroup!(A a0 b0, B a1 b1);
+tuple_semigroup!(A a0 b0, B a1 b1, C a2 b2);
+tuple_semigroup!(A a0 b0, B a1 b1, C a2 b2, D a3 b3);
+tuple_semigroup!(A a0 b0, B a1 b1, C a2 b2, D a3 b3, E a4 b4);
+tuple_semigroup!(A a0 b0, B a1 b1, C a2 b2, D a3 b3, E a4 b4, F a5 b5);
+tuple_semigroup!(A a0 b0, B a1 b1, C a2 b2, D a3 b3, E a4 b4, F a5 b5, G a6 b6);
+tuple_semigroup!(A a0 b0, B a1 b1, C a2 b2, D a3 b3, E a4 b4, F a5 b5, G a6 b6, H a7 b7);
+tuple_semigroup!(A a0 b0, B a1 b1, C a2 b2, D a3 b3, E a4 b4, F a5 b5, G a6 b6, H a7 b7, I a8 b8);
+tuple_semigroup!(A a0 b0, B a1 b1, C a2 b2, D a3 b3, E a4 b4, F a5 b5, G a6 b6, H a7 b7, I a8 b8, J a9 b9);
+tuple_semigroup!(A a0 b0, B a1 b1, C a2 b2, D a3 b3, E a4 b4, F a5 b5, G a6 b6, H a7 b7, I a8 b8, J a9 b9, K a10 b10);
+tuple_semigroup!(A a0 b0, B a1 b1, C a2 b2, D a3 b3, E a4 b4, F a5 b5, G a6 b6, H a7 b7, I a8 b8, J a9 b9, K a10 b10, L a11 b11);
+
+impl<A, B> BifunctorOnce<hkt::Pair, A, B> for (A, B) {
+  fn bimap1<A2, B2, FA, FB>(self, fa: FA, fb: FB) -> (A2, B2)
+    where FA: F1Once<A, Ret = A2>,
+          FB: F1Once<B, Ret = B2>
+  {
+    (fa.call1(self.0), fb.call1(self.1))
+  }
+}
+deriving!(impl Bifunctor<hkt::Pair, A, B> for (A, B) {..BifunctorOnce});
+
+impl<A, B> Bifoldable<hkt::Pair, A, B> for (A, B) {
+  fn bifoldl<C, CAC, CBC>(self, fa: CAC, fb: CBC, c: C) -> C
+    where CAC: F2<C, A, Ret = C>,
+          CBC: F2<C, B, Ret = C>
+  {
+    fb.call(fa.call(c, self.0), self.1)
+  }
+
+  fn bifoldr<C, ACC, BCC>(self, fa: ACC, fb: BCC, c: C) -> C
+    where ACC: F2<A, C, Ret = C>,
+          BCC: F2<B, C, Ret = C>
+  {
+    fa.call(self.0, fb.call(self.1, c))
+  }
+}
+
+#[allow(non_camel_case_types)]
+type tuple2<A, B> = fn(A, B) -> (A, B);
+
+/// curried [`fn@crate::fun::tuple2`] that has an `A` and is waiting for a `B`
+#[allow(non_camel_case_types)]
+pub type tuple2_1<A, B> = curry2::Applied1<tuple2<A, B>, A, B, (A, B)>;
+
+impl<A, B, A2, B2> Bitraversable<hkt::Pair, A, B, A2, B2, tuple2_1<A2, B2>, ()> for (A, B) {
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let parse = |kv: (&str, &str)| {
+  ///   kv.bitraverse::<hkt::Option, _, _>(|k: &str| Some(k.to_uppercase()).filter(|k| !k.is_empty()),
+  ///                                      |v: &str| v.parse::<u16>().ok())
+  /// };
+  ///
+  /// assert_eq!(parse(("port", "8080")), Some((format!("PORT"), 8080)));
+  /// assert_eq!(parse(("port", "http")), None);
+  /// ```
+  fn bitraverse<Ap, FA, FB>(self, fa: FA, fb: FB) -> Ap::T<(A2, B2)>
+    where Ap: HKT1,
+          Ap::T<A2>: Applicative<Ap, A2> + ApplyOnce<Ap, A2>,
+          Ap::T<B2>: Applicative<Ap, B2> + ApplyOnce<Ap, B2>,
+          Ap::T<tuple2_1<A2, B2>>:
+            Applicative<Ap, tuple2_1<A2, B2>> + ApplyOnce<Ap, tuple2_1<A2, B2>>,
+          Ap::T<()>: Applicative<Ap, ()> + ApplyOnce<Ap, ()>,
+          Ap::T<(A2, B2)>: Applicative<Ap, (A2, B2)> + ApplyOnce<Ap, (A2, B2)>,
+          FA: F1<A, Ret = Ap::T<A2>>,
+          FB: F1<B, Ret = Ap::T<B2>>
+  {
+    fa.call(self.0)
+      .fmap((crate::fun::tuple2 as tuple2<A2, B2>).curry())
+      .apply1(fb.call(self.1))
+  }
+}
+
+impl<L, A> FunctorOnce<hkt::Tuple2<L>, A> for (L, A) {
+  fn fmap1<AB, B>(self, f: AB) -> (L, B)
+    where AB: F1Once<A, Ret = B>
+  {
+    (self.0, f.call1(self.1))
+  }
+}
+deriving!(impl<L> Functor<hkt::Tuple2<L>, A> for (L, A) {..FunctorOnce});
+
+impl<L, AB> ApplyOnce<hkt::Tuple2<L>, AB> for (L, AB) where L: Semigroup
+{
+  fn apply1<A, B>(self, a: (L, A)) -> (L, B)
+    where AB: F1Once<A, Ret = B>
+  {
+    (self.0.append(a.0), self.1.call1(a.1))
+  }
+}
+
+impl<L, AB> Apply<hkt::Tuple2<L>, AB> for (L, AB) where L: Semigroup
+{
+  fn apply_with<A, B, Cloner>(self, a: (L, A), _: Cloner) -> (L, B)
+    where AB: F1<A, Ret = B>,
+          Cloner: for<'a> F1<&'a A, Ret = A>
+  {
+    self.apply1(a)
+  }
+}
+
+impl<L, A> Applicative<hkt::Tuple2<L>, A> for (L, A) where L: Monoid
+{
+  fn pure(a: A) -> (L, A) {
+    (L::identity(), a)
+  }
+}
+
+impl<L, A> MonadOnce<hkt::Tuple2<L>, A> for (L, A) where L: Monoid
+{
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let counted = |n: u8| (Sum(1u32), n * 2);
+  ///
+  /// assert_eq!((Sum(0), 1).bind1(counted).bind1(counted), (Sum(2), 4));
+  /// ```
+  fn bind1<B, AMB>(self, f: AMB) -> (L, B)
+    where AMB: F1Once<A, Ret = (L, B)>
+  {
+    let (l, b) = f.call1(self.1);
+    (self.0.append(l), b)
+  }
+}
+
+impl<L, A> Monad<hkt::Tuple2<L>, A> for (L, A) where L: Monoid
+{
+  fn bind<B, AMB>(self, f: AMB) -> (L, B)
+    where AMB: F1<A, Ret = (L, B)>
+  {
+    self.bind1(f)
+  }
+}
+deriving!(impl<L> MonadHKT for hkt::Tuple2<L> where L: Monoid {..Monad});
+
+impl<L, A> FoldableOnce<hkt::Tuple2<L>, A> for (L, A) {
+  fn fold1<B, BAB>(self, f: BAB, b: B) -> B
+    where BAB: F2Once<B, A, Ret = B>
+  {
+    f.call1(b, self.1)
+  }
+
+  fn fold1_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> B
+    where BAB: F2Once<B, &'a A, Ret = B>,
+          A: 'a
+  {
+    f.call1(b, &self.1)
+  }
+}
+deriving!(impl<L> Foldable<hkt::Tuple2<L>, A> for (L, A) {..FoldableOnce});
+
+impl<L, A> FoldableIndexed<hkt::Tuple2<L>, L, A> for (L, A) where L: Clone
+{
+  /// Fold the right value, using the left value as its index
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// assert_eq!(("a", 1).foldl_idx(|s: String, k, v| format!("{s}{k}={v}"), String::new()),
+  ///            format!("a=1"));
+  /// ```
+  fn foldl_idx<B, BAB>(self, f: BAB, b: B) -> B
+    where BAB: F3<B, L, A, Ret = B>
+  {
+    f.call(b, self.0, self.1)
+  }
+
+  fn foldr_idx<B, ABB>(self, f: ABB, b: B) -> B
+    where ABB: F3<L, A, B, Ret = B>
+  {
+    f.call(self.0, self.1, b)
+  }
+
+  fn foldl_idx_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> B
+    where BAB: F3<B, L, &'a A, Ret = B>,
+          A: 'a,
+          L: 'a
+  {
+    f.call(b, self.0.clone(), &self.1)
+  }
+
+  fn foldr_idx_ref<'a, B, ABB>(&'a self, f: ABB, b: B) -> B
+    where ABB: F3<L, &'a A, B, Ret = B>,
+          A: 'a,
+          L: 'a
+  {
+    f.call(self.0.clone(), &self.1, b)
+  }
+}
+
+impl<L, A, B> TraversableOnce<hkt::Tuple2<L>, A, B, ()> for (L, A)
+  where L: Clone,
+        hkt::Tuple2<L>: HKT1<T<B> = (L, B)> + HKT1<T<A> = (L, A)>
+{
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// assert_eq!(("port", "8080").traverse::<hkt::Option, _>(|s: &str| s.parse::<u16>().ok()),
+  ///            Some(("port", 8080)));
+  /// ```
+  fn traverse1m<Ap, AtoApOfB>(self, f: AtoApOfB) -> Ap::T<(L, B)>
+    where Ap: HKT1,
+          Ap::T<B>: Applicative<Ap, B>,
+          Ap::T<(L, B)>: Applicative<Ap, (L, B)>,
+          AtoApOfB: F1Once<A, Ret = Ap::T<B>>
+  {
+    let (l, a) = self;
+    f.call1(a).fmap(|b| (l.clone(), b))
+  }
+
+  fn traverse11<Ap, AtoApOfB>(self, f: AtoApOfB) -> Ap::T<(L, B)>
+    where Ap: HKT1,
+          Ap::T<B>: Applicative<Ap, B> + ApplyOnce<Ap, B>,
+          Ap::T<()>: Applicative<Ap, ()> + ApplyOnce<Ap, ()>,
+          Ap::T<(L, B)>: Applicative<Ap, (L, B)> + ApplyOnce<Ap, (L, B)>,
+          AtoApOfB: F1Once<A, Ret = Ap::T<B>>
+  {
+    self.traverse1m::<Ap, AtoApOfB>(f)
+  }
+}
+
+impl<L, A, B> Traversable<hkt::Tuple2<L>, A, B, ()> for (L, A)
+  where L: Clone,
+        hkt::Tuple2<L>: HKT1<T<B> = (L, B)> + HKT1<T<A> = (L, A)>
+{
+  fn traversem1<Ap, AtoApOfB>(self, f: AtoApOfB) -> Ap::T<(L, B)>
+    where Ap: HKT1,
+          Ap::T<B>: Applicative<Ap, B> + ApplyOnce<Ap, B>,
+          Ap::T<()>: Applicative<Ap, ()> + ApplyOnce<Ap, ()>,
+          Ap::T<(L, B)>: Applicative<Ap, (L, B)> + ApplyOnce<Ap, (L, B)>,
+          AtoApOfB: F1<A, Ret = Ap::T<B>>
+  {
+    self.traverse11::<Ap, AtoApOfB>(f)
+  }
+
+  fn traversemm<Ap, AtoApOfB>(self, f: AtoApOfB) -> Ap::T<(L, B)>
+    where Ap: HKT1,
+          Ap::T<B>: Applicative<Ap, B>,
+          Ap::T<()>: Applicative<Ap, ()>,
+          Ap::T<(L, B)>: Applicative<Ap, (L, B)>,
+          AtoApOfB: F1<A, Ret = Ap::T<B>>
+  {
+    self.traverse1m::<Ap, AtoApOfB>(f)
+  }
+}
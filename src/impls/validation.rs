@@ -0,0 +1,286 @@
+use crate::prelude::*;
+
+/// Validation Kinds
+pub mod hkt {
+  use core::marker::PhantomData;
+
+  use crate::prelude::*;
+
+  /// [`Validation`](super::Validation) lifted to an HKT1
+  /// with the error type pinned to some `E`.
+  ///
+  /// (Kind `Type -> Type`)
+  pub struct Validation<E>(PhantomData<E>);
+  impl<E> HKT1 for Validation<E> {
+    type T<A> = super::Validation<E, A>;
+  }
+
+  /// [`Validation`](super::Validation) lifted to an HKT2
+  ///
+  /// (Kind `Type -> Type -> Type`)
+  pub struct Validation2;
+  impl HKT2 for Validation2 {
+    type T<E, A> = super::Validation<E, A>;
+  }
+}
+
+/// Like [`Result`], but [`apply`](Apply::apply)ing two `Invalid`s
+/// combines their errors with [`Semigroup::append`] instead of
+/// short-circuiting on the first.
+///
+/// This makes `Validation` useful for checking many independent things
+/// and reporting every problem at once, rather than only the first.
+///
+/// `Validation` is **not** a [`Monad`]; a `bind`ed function depends on the
+/// valid value, so there would be no errors to accumulate if it were missing.
+/// Convert to a [`Result`] with [`Validation::into_result`] when sequencing is needed.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct User {
+///   name: String,
+///   age: u8,
+/// }
+///
+/// fn name(s: &str) -> Validation<Vec<String>, String> {
+///   Some(s.to_string()).filter(|s| !s.is_empty())
+///                      .ok_or(vec![format!("name must not be empty")])
+///                      .into_validation()
+/// }
+///
+/// fn age(n: u8) -> Validation<Vec<String>, u8> {
+///   if n >= 18 {
+///     Validation::Valid(n)
+///   } else {
+///     Validation::Invalid(vec![format!("{n} is too young")])
+///   }
+/// }
+///
+/// let user = |name, age| User { name, age };
+///
+/// assert_eq!(Validation::pure(user.curry()).apply(name("Bob"))
+///                                          .apply(age(30)),
+///            Validation::Valid(User { name: "Bob".into(),
+///                                     age: 30 }));
+///
+/// assert_eq!(Validation::pure(user.curry()).apply(name(""))
+///                                          .apply(age(12)),
+///            Validation::Invalid(vec![format!("name must not be empty"),
+///                                     format!("12 is too young")]));
+/// ```
+///
+/// [`traverse`](Traversable::traverse)ing with a `Validation` collects every
+/// error, in the order the elements were visited:
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// fn even(n: u8) -> Validation<Vec<u8>, u8> {
+///   if n % 2 == 0 {
+///     Validation::Valid(n)
+///   } else {
+///     Validation::Invalid(vec![n])
+///   }
+/// }
+///
+/// assert_eq!(vec![2u8, 4, 6].traverse::<hkt::Validation<Vec<u8>>, _>(even),
+///            Validation::Valid(vec![2, 4, 6]));
+/// assert_eq!(vec![1u8, 2, 3, 4, 5].traverse::<hkt::Validation<Vec<u8>>, _>(even),
+///            Validation::Invalid(vec![1, 3, 5]));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Validation<E, A> {
+  /// Contains the error(s)
+  Invalid(E),
+  /// Contains the valid value
+  Valid(A),
+}
+
+impl<E, A> Validation<E, A> {
+  /// Is this `Valid`?
+  pub fn is_valid(&self) -> bool {
+    matches!(self, Validation::Valid(_))
+  }
+
+  /// Is this `Invalid`?
+  pub fn is_invalid(&self) -> bool {
+    !self.is_valid()
+  }
+
+  /// Convert this `Validation` to a [`Result`], `Valid` becoming `Ok`
+  /// and `Invalid` becoming `Err`.
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let ok = Result::<u8, ()>::Ok(1);
+  /// let err = Result::<u8, ()>::Err(());
+  ///
+  /// assert_eq!(ok.into_validation().into_result(), ok);
+  /// assert_eq!(err.into_validation().into_result(), err);
+  /// assert_eq!(Validation::from(ok), Validation::Valid(1));
+  /// assert_eq!(Result::from(Validation::<(), u8>::Invalid(())), err);
+  /// ```
+  pub fn into_result(self) -> Result<A, E> {
+    match self {
+      | Validation::Valid(a) => Ok(a),
+      | Validation::Invalid(e) => Err(e),
+    }
+  }
+
+  /// Borrow the contents of this `Validation`
+  pub fn as_ref(&self) -> Validation<&E, &A> {
+    match self {
+      | Validation::Valid(a) => Validation::Valid(a),
+      | Validation::Invalid(e) => Validation::Invalid(e),
+    }
+  }
+}
+
+impl<E, A> From<Result<A, E>> for Validation<E, A> {
+  fn from(r: Result<A, E>) -> Self {
+    match r {
+      | Ok(a) => Validation::Valid(a),
+      | Err(e) => Validation::Invalid(e),
+    }
+  }
+}
+
+impl<E, A> From<Validation<E, A>> for Result<A, E> {
+  fn from(v: Validation<E, A>) -> Self {
+    v.into_result()
+  }
+}
+
+impl<E, A> FunctorOnce<hkt::Validation<E>, A> for Validation<E, A> {
+  fn fmap1<AB, B>(self, f: AB) -> Validation<E, B>
+    where AB: F1Once<A, Ret = B>
+  {
+    match self {
+      | Validation::Valid(a) => Validation::Valid(f.call1(a)),
+      | Validation::Invalid(e) => Validation::Invalid(e),
+    }
+  }
+}
+deriving!(impl<E> Functor<hkt::Validation<E>, A> for Validation<E, A> {..FunctorOnce});
+
+impl<AB, E> ApplyOnce<hkt::Validation<E>, AB> for Validation<E, AB> where E: Semigroup
+{
+  /// Apply the function in `self` to the value in `a`.
+  ///
+  /// When both are `Invalid`, the errors in `self` are
+  /// [`append`](Semigroup::append)ed with the errors in `a`.
+  fn apply1<A, B>(self, a: Validation<E, A>) -> Validation<E, B>
+    where AB: F1Once<A, Ret = B>
+  {
+    match (self, a) {
+      | (Validation::Valid(f), Validation::Valid(a)) => Validation::Valid(f.call1(a)),
+      | (Validation::Invalid(e1), Validation::Invalid(e2)) => Validation::Invalid(e1.append(e2)),
+      | (Validation::Invalid(e), _) | (_, Validation::Invalid(e)) => Validation::Invalid(e),
+    }
+  }
+}
+
+impl<AB, E> Apply<hkt::Validation<E>, AB> for Validation<E, AB> where E: Semigroup
+{
+  fn apply_with<A, B, Cloner>(self, a: Validation<E, A>, _: Cloner) -> Validation<E, B>
+    where AB: F1<A, Ret = B>,
+          Cloner: for<'a> F1<&'a A, Ret = A>
+  {
+    self.apply1(a)
+  }
+}
+
+impl<A, E> Applicative<hkt::Validation<E>, A> for Validation<E, A> where E: Semigroup
+{
+  fn pure(a: A) -> Validation<E, A> {
+    Validation::Valid(a)
+  }
+}
+
+impl<A, E> Alt<hkt::Validation<E>, A> for Validation<E, A> where E: Semigroup
+{
+  /// Yield the first `Valid`, combining the errors
+  /// when neither is `Valid`.
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// type V = Validation<Vec<&'static str>, u8>;
+  ///
+  /// assert_eq!(V::Invalid(vec!["a"]).alt(V::Valid(1)), V::Valid(1));
+  /// assert_eq!(V::Invalid(vec!["a"]).alt(V::Invalid(vec!["b"])),
+  ///            V::Invalid(vec!["a", "b"]));
+  /// ```
+  fn alt(self, b: Self) -> Self {
+    match (self, b) {
+      | (Validation::Invalid(e1), Validation::Invalid(e2)) => Validation::Invalid(e1.append(e2)),
+      | (Validation::Valid(a), _) | (_, Validation::Valid(a)) => Validation::Valid(a),
+    }
+  }
+}
+
+impl<A, E> FoldableOnce<hkt::Validation<E>, A> for Validation<E, A> {
+  fn fold1<B, BAB>(self, f: BAB, b: B) -> B
+    where BAB: F2Once<B, A, Ret = B>
+  {
+    match self {
+      | Validation::Valid(a) => f.call1(b, a),
+      | Validation::Invalid(_) => b,
+    }
+  }
+
+  fn fold1_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> B
+    where BAB: F2Once<B, &'a A, Ret = B>,
+          A: 'a
+  {
+    match self {
+      | Validation::Valid(a) => f.call1(b, a),
+      | Validation::Invalid(_) => b,
+    }
+  }
+}
+deriving!(impl<E> Foldable<hkt::Validation<E>, A> for Validation<E, A> {..FoldableOnce});
+
+impl<A, B, E> TraversableOnce<hkt::Validation<E>, A, B, ()> for Validation<E, A>
+  where hkt::Validation<E>: HKT1<T<B> = Validation<E, B>> + HKT1<T<A> = Validation<E, A>>
+{
+  fn traverse1m<Ap, AtoApOfB>(self, f: AtoApOfB) -> Ap::T<Validation<E, B>>
+    where Ap: HKT1,
+          Ap::T<B>: Applicative<Ap, B>,
+          Ap::T<Validation<E, B>>: Applicative<Ap, Validation<E, B>>,
+          AtoApOfB: F1Once<A, Ret = Ap::T<B>>
+  {
+    match self {
+      | Validation::Valid(a) => f.call1(a).fmap(Validation::Valid),
+      | Validation::Invalid(e) => Ap::T::pure(Validation::Invalid(e)),
+    }
+  }
+
+  fn traverse11<Ap, AtoApOfB>(self, f: AtoApOfB) -> Ap::T<Validation<E, B>>
+    where Ap: HKT1,
+          Ap::T<B>: Applicative<Ap, B> + ApplyOnce<Ap, B>,
+          Ap::T<()>: Applicative<Ap, ()> + ApplyOnce<Ap, ()>,
+          Ap::T<Validation<E, B>>:
+            Applicative<Ap, Validation<E, B>> + ApplyOnce<Ap, Validation<E, B>>,
+          AtoApOfB: F1Once<A, Ret = Ap::T<B>>
+  {
+    self.traverse1m::<Ap, AtoApOfB>(f)
+  }
+}
+deriving!(impl<E> Traversable<hkt::Validation<E>, A, B, ()> for Validation<E, A> {..TraversableOnce});
+
+impl<E, A> BifunctorOnce<hkt::Validation2, E, A> for Validation<E, A> {
+  fn bimap1<E2, A2, FE, FA>(self, fe: FE, fa: FA) -> Validation<E2, A2>
+    where FE: F1Once<E, Ret = E2>,
+          FA: F1Once<A, Ret = A2>
+  {
+    match self {
+      | Validation::Valid(a) => Validation::Valid(fa.call1(a)),
+      | Validation::Invalid(e) => Validation::Invalid(fe.call1(e)),
+    }
+  }
+}
+deriving!(impl Bifunctor<hkt::Validation2, E, A> for Validation<E, A> {..BifunctorOnce});
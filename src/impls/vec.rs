@@ -1,3 +1,5 @@
+use core::ops::ControlFlow;
+
 use std_alloc::vec;
 use std_alloc::vec::Vec;
 
@@ -98,6 +100,23 @@ impl<A> FoldableIndexed<hkt::Vec, usize, A> for Vec<A> {
         .enumerate()
         .rfold(b, |b, (ix, a)| f.call(ix, a, b))
   }
+
+  fn try_foldl_idx<B, BAB>(self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F3<B, usize, A, Ret = ControlFlow<B, B>>
+  {
+    self.into_iter()
+        .enumerate()
+        .try_fold(b, |b, (ix, a)| f.call(b, ix, a))
+  }
+
+  fn try_foldl_idx_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F3<B, usize, &'a A, Ret = ControlFlow<B, B>>,
+          A: 'a
+  {
+    self.iter()
+        .enumerate()
+        .try_fold(b, |b, (ix, a)| f.call(b, ix, a))
+  }
 }
 deriving!(impl Foldable<hkt::Vec, A> for Vec<A> {..FoldableIndexed});
 
@@ -128,8 +147,14 @@ impl<A, B> Traversable<hkt::Vec, A, B, append1<B>> for Vec<A> {
           AtoApOfB: F1<A, Ret = Ap::T<B>>,
           hkt::Vec: HKT1<T<A> = Self>
   {
-    self.foldl(|ap, a| f.call(a).fmap((append as append<B>).curry()).apply1(ap),
+    // Folding from the right keeps `f`'s effects in input order,
+    // but pushes the results in reverse.
+    self.foldr(|a, ap| f.call(a).fmap((append as append<B>).curry()).apply1(ap),
                Ap::T::pure(vec![]))
+        .fmap(|mut v: Vec<B>| {
+          v.reverse();
+          v
+        })
   }
 
   fn traversemm<Ap, AtoApOfB>(self, f: AtoApOfB) -> Ap::T<Vec<B>>
@@ -142,8 +167,14 @@ impl<A, B> Traversable<hkt::Vec, A, B, append1<B>> for Vec<A> {
           AtoApOfB: F1<A, Ret = Ap::T<B>>,
           hkt::Vec: HKT1<T<A> = Self>
   {
-    self.foldl(|ap, a| f.call(a).fmap((append as append<B>).curry()).apply(ap),
+    // Folding from the right keeps `f`'s effects in input order,
+    // but pushes the results in reverse.
+    self.foldr(|a, ap| f.call(a).fmap((append as append<B>).curry()).apply(ap),
                Ap::T::pure(vec![]))
+        .fmap(|mut v: Vec<B>| {
+          v.reverse();
+          v
+        })
   }
 }
 
@@ -160,3 +191,25 @@ impl<A> Monad<hkt::Vec, A> for Vec<A> {
     out
   }
 }
+deriving!(impl MonadHKT for hkt::Vec {..Monad});
+
+impl<A> MonadRec<hkt::Vec, A> for Vec<A> {
+  /// Explores the tree of seeds depth-first with a stack on the heap,
+  /// yielding results in the same order as the equivalent recursive `bind`.
+  fn tail_rec_m<S, F>(seed: S, f: F) -> Vec<A>
+    where F: F1<S, Ret = Vec<ControlFlow<A, S>>>
+  {
+    let mut out = Vec::new();
+    let mut stack = f.call(seed);
+    stack.reverse();
+
+    while let Some(step) = stack.pop() {
+      match step {
+        | ControlFlow::Break(a) => out.push(a),
+        | ControlFlow::Continue(s) => stack.extend(f.call(s).into_iter().rev()),
+      }
+    }
+
+    out
+  }
+}
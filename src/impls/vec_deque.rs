@@ -0,0 +1,188 @@
+use core::iter;
+use core::ops::ControlFlow;
+
+use std_alloc::collections::VecDeque;
+
+use crate::prelude::*;
+
+/// VecDeque Kinds
+pub mod hkt {
+  use super::*;
+
+  /// [`std::collections::VecDeque`] lifted to an HKT1
+  ///
+  /// (Kind `Type -> Type`)
+  pub struct VecDeque;
+  impl HKT1 for VecDeque {
+    type T<A> = ::std_alloc::collections::VecDeque<A>;
+  }
+}
+
+impl<A> Functor<hkt::VecDeque, A> for VecDeque<A> {
+  fn fmap<AB, B>(self, f: AB) -> VecDeque<B>
+    where AB: F1<A, Ret = B>
+  {
+    self.into_iter().map(|a| f.call(a)).collect()
+  }
+}
+
+impl<AB> Apply<hkt::VecDeque, AB> for VecDeque<AB> {
+  fn apply_with<A, B, Cloner>(self,
+                              a: <hkt::VecDeque as HKT1>::T<A>,
+                              cloner: Cloner)
+                              -> <hkt::VecDeque as HKT1>::T<B>
+    where AB: F1<A, Ret = B>,
+          Cloner: for<'a> F1<&'a A, Ret = A>
+  {
+    self.into_iter()
+        .flat_map(move |f| {
+          a.iter()
+           .map(|a| f.call(cloner.call(a)))
+           .collect::<VecDeque<B>>()
+        })
+        .collect()
+  }
+}
+
+impl<A> Applicative<hkt::VecDeque, A> for VecDeque<A> {
+  fn pure(a: A) -> VecDeque<A> {
+    iter::once(a).collect()
+  }
+}
+
+impl<A> Alt<hkt::VecDeque, A> for VecDeque<A> {
+  fn alt(mut self, mut b: Self) -> Self {
+    VecDeque::append(&mut self, &mut b);
+    self
+  }
+}
+deriving!(impl Plus<hkt::VecDeque, A> for VecDeque<A> {..Default});
+
+deriving!(impl<A> Semigroup for VecDeque<A> {..Alt});
+deriving!(impl<A> Monoid for VecDeque<A> {..Default});
+
+impl<A> FoldableIndexed<hkt::VecDeque, usize, A> for VecDeque<A> {
+  fn foldl_idx<B, BAB>(self, f: BAB, b: B) -> B
+    where BAB: F3<B, usize, A, Ret = B>
+  {
+    self.into_iter()
+        .enumerate()
+        .fold(b, |b, (ix, a)| f.call(b, ix, a))
+  }
+
+  fn foldr_idx<B, ABB>(self, f: ABB, b: B) -> B
+    where ABB: F3<usize, A, B, Ret = B>
+  {
+    self.into_iter()
+        .enumerate()
+        .rfold(b, |b, (ix, a)| f.call(ix, a, b))
+  }
+
+  fn foldl_idx_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> B
+    where BAB: F3<B, usize, &'a A, Ret = B>,
+          A: 'a
+  {
+    self.iter()
+        .enumerate()
+        .fold(b, |b, (ix, a)| f.call(b, ix, a))
+  }
+
+  fn foldr_idx_ref<'a, B, ABB>(&'a self, f: ABB, b: B) -> B
+    where ABB: F3<usize, &'a A, B, Ret = B>,
+          A: 'a
+  {
+    self.iter()
+        .enumerate()
+        .rfold(b, |b, (ix, a)| f.call(ix, a, b))
+  }
+
+  fn try_foldl_idx<B, BAB>(self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F3<B, usize, A, Ret = ControlFlow<B, B>>
+  {
+    self.into_iter()
+        .enumerate()
+        .try_fold(b, |b, (ix, a)| f.call(b, ix, a))
+  }
+
+  fn try_foldl_idx_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> ControlFlow<B, B>
+    where BAB: F3<B, usize, &'a A, Ret = ControlFlow<B, B>>,
+          A: 'a
+  {
+    self.iter()
+        .enumerate()
+        .try_fold(b, |b, (ix, a)| f.call(b, ix, a))
+  }
+}
+deriving!(impl Foldable<hkt::VecDeque, A> for VecDeque<A> {..FoldableIndexed});
+
+#[allow(non_camel_case_types)]
+type push<T> = fn(VecDeque<T>, T) -> VecDeque<T>;
+
+/// curried [`fn@push`] waiting for both arguments
+#[allow(non_camel_case_types)]
+pub type push0<T> = curry2::Curry2<push<T>, Nothing<VecDeque<T>>, Nothing<T>, VecDeque<T>>;
+
+/// curried [`fn@push`] that has a VecDeque and is waiting for the T to push to it
+#[allow(non_camel_case_types)]
+pub type push1<T> = curry2::Curry2<push<T>, Just<VecDeque<T>>, Nothing<T>, VecDeque<T>>;
+
+/// Push an element to the back of a VecDeque
+pub fn push<T>(mut v: VecDeque<T>, t: T) -> VecDeque<T> {
+  v.push_back(t);
+  v
+}
+
+impl<A, B> Traversable<hkt::VecDeque, A, B, push1<B>> for VecDeque<A> {
+  /// ```
+  /// use std::collections::VecDeque;
+  ///
+  /// use naan::prelude::*;
+  ///
+  /// let parse = |ss: VecDeque<&str>| ss.traverse::<hkt::Option, _>(|s: &str| s.parse::<u8>().ok());
+  ///
+  /// assert_eq!(parse(VecDeque::from(["1", "2"])),
+  ///            Some(VecDeque::from([1, 2])));
+  /// assert_eq!(parse(VecDeque::from(["1", "two"])), None);
+  /// ```
+  fn traversem1<Ap, AtoApOfB>(self, f: AtoApOfB) -> Ap::T<VecDeque<B>>
+    where Ap: HKT1,
+          Self: Foldable<hkt::VecDeque, A>,
+          Ap::T<B>: Applicative<Ap, B> + ApplyOnce<Ap, B>,
+          Ap::T<push1<B>>: Applicative<Ap, push1<B>> + ApplyOnce<Ap, push1<B>>,
+          Ap::T<VecDeque<B>>: Applicative<Ap, VecDeque<B>> + ApplyOnce<Ap, VecDeque<B>>,
+          AtoApOfB: F1<A, Ret = Ap::T<B>>,
+          hkt::VecDeque: HKT1<T<A> = Self>
+  {
+    self.foldl(|ap: Ap::T<VecDeque<B>>, a| ap.fmap((push as push<B>).curry()).apply1(f.call(a)),
+               Ap::T::pure(VecDeque::new()))
+  }
+
+  fn traversemm<Ap, AtoApOfB>(self, f: AtoApOfB) -> Ap::T<VecDeque<B>>
+    where Ap: HKT1,
+          Self: Foldable<hkt::VecDeque, A>,
+          B: Clone,
+          Ap::T<B>: Applicative<Ap, B>,
+          Ap::T<push1<B>>: Applicative<Ap, push1<B>>,
+          Ap::T<VecDeque<B>>: Applicative<Ap, VecDeque<B>>,
+          AtoApOfB: F1<A, Ret = Ap::T<B>>,
+          hkt::VecDeque: HKT1<T<A> = Self>
+  {
+    self.foldl(|ap: Ap::T<VecDeque<B>>, a| ap.fmap((push as push<B>).curry()).apply(f.call(a)),
+               Ap::T::pure(VecDeque::new()))
+  }
+}
+
+impl<A> Monad<hkt::VecDeque, A> for VecDeque<A> {
+  fn bind<B, AMB>(self, f: AMB) -> VecDeque<B>
+    where AMB: F1<A, Ret = VecDeque<B>>
+  {
+    let mut out = VecDeque::<B>::new();
+
+    for i in self {
+      VecDeque::append(&mut out, &mut f.call(i));
+    }
+
+    out
+  }
+}
+deriving!(impl MonadHKT for hkt::VecDeque {..Monad});
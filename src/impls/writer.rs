@@ -0,0 +1,254 @@
+use core::ops::ControlFlow;
+
+use crate::prelude::*;
+
+/// Writer Kinds
+pub mod hkt {
+  use core::marker::PhantomData;
+
+  use crate::prelude::*;
+
+  /// [`Writer`](super::Writer) lifted to an HKT1
+  /// with the log type pinned to some `W`.
+  ///
+  /// (Kind `Type -> Type`)
+  pub struct Writer<W>(PhantomData<W>);
+  impl<W> HKT1 for Writer<W> {
+    type T<A> = super::Writer<W, A>;
+  }
+}
+
+/// The Writer monad
+///
+/// A value `A` paired with a log `W`. Sequencing Writers with
+/// [`bind`](Monad::bind) or [`apply`](Apply::apply) [`append`](Semigroup::append)s
+/// their logs, so `W` is usually a [`Monoid`] like `Vec<Event>` or `String`.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// fn double(n: u32) -> Writer<Vec<String>, u32> {
+///   Writer::tell(vec![format!("doubled {n}")]).fmap(|_| n * 2)
+/// }
+///
+/// fn add(n: u32, m: u32) -> Writer<Vec<String>, u32> {
+///   Writer::new(n + m, vec![format!("added {n} to {m}")])
+/// }
+///
+/// let (n, log) = double(2).bind(|n| add(n, 10)).bind(double).run();
+///
+/// assert_eq!(n, 28);
+/// assert_eq!(log,
+///            vec![format!("doubled 2"),
+///                 format!("added 4 to 10"),
+///                 format!("doubled 14")]);
+///
+/// // Writer is also Foldable and Traversable over its value
+/// assert_eq!(Writer::new(1, format!("a")).traverse::<hkt::Option, _>(|n| Some(n + 1)),
+///            Some(Writer::new(2, format!("a"))));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Writer<W, A>(A, W);
+
+impl<W, A> Writer<W, A> {
+  /// Pair a value with a log
+  pub fn new(a: A, w: W) -> Self {
+    Self(a, w)
+  }
+
+  /// Get the value and the log
+  pub fn run(self) -> (A, W) {
+    (self.0, self.1)
+  }
+
+  /// Get the value, discarding the log
+  pub fn value(self) -> A {
+    self.0
+  }
+
+  /// Get the log, discarding the value
+  pub fn log(self) -> W {
+    self.1
+  }
+
+  /// Make the log so far available in the value
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let w = Writer::new(1, format!("a")).listen();
+  ///
+  /// assert_eq!(w.run(), ((1, format!("a")), format!("a")));
+  /// ```
+  pub fn listen(self) -> Writer<W, (A, W)>
+    where W: Clone
+  {
+    let w = self.1.clone();
+    Writer((self.0, w), self.1)
+  }
+
+  /// Transform the log so far
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let w = Writer::tell(vec!["password=hunter2", "ok"]).censor(|log: Vec<&'static str>| {
+  ///                                                       log.into_iter()
+  ///                                                          .filter(|l| !l.starts_with("password"))
+  ///                                                          .collect()
+  ///                                                     });
+  ///
+  /// assert_eq!(w.log(), vec!["ok"]);
+  /// ```
+  pub fn censor<F>(self, f: F) -> Self
+    where F: F1Once<W, Ret = W>
+  {
+    Writer(self.0, f.call1(self.1))
+  }
+}
+
+impl<W> Writer<W, ()> {
+  /// Write to the log
+  pub fn tell(w: W) -> Self {
+    Writer((), w)
+  }
+}
+
+impl<W, A> FunctorOnce<hkt::Writer<W>, A> for Writer<W, A> {
+  fn fmap1<AB, B>(self, f: AB) -> Writer<W, B>
+    where AB: F1Once<A, Ret = B>
+  {
+    Writer(f.call1(self.0), self.1)
+  }
+}
+deriving!(impl<W> Functor<hkt::Writer<W>, A> for Writer<W, A> {..FunctorOnce});
+
+impl<W, AB> ApplyOnce<hkt::Writer<W>, AB> for Writer<W, AB> where W: Semigroup
+{
+  fn apply1<A, B>(self, a: Writer<W, A>) -> Writer<W, B>
+    where AB: F1Once<A, Ret = B>
+  {
+    Writer(self.0.call1(a.0), self.1.append(a.1))
+  }
+}
+
+impl<W, AB> Apply<hkt::Writer<W>, AB> for Writer<W, AB> where W: Semigroup
+{
+  fn apply_with<A, B, Cloner>(self, a: Writer<W, A>, _: Cloner) -> Writer<W, B>
+    where AB: F1<A, Ret = B>,
+          Cloner: for<'a> F1<&'a A, Ret = A>
+  {
+    self.apply1(a)
+  }
+}
+
+impl<W, A> Applicative<hkt::Writer<W>, A> for Writer<W, A> where W: Monoid
+{
+  fn pure(a: A) -> Writer<W, A> {
+    Writer(a, W::identity())
+  }
+}
+
+impl<W, A> MonadOnce<hkt::Writer<W>, A> for Writer<W, A> where W: Monoid
+{
+  fn bind1<B, AMB>(self, f: AMB) -> Writer<W, B>
+    where AMB: F1Once<A, Ret = Writer<W, B>>
+  {
+    let Writer(b, w) = f.call1(self.0);
+    Writer(b, self.1.append(w))
+  }
+}
+
+impl<W, A> Monad<hkt::Writer<W>, A> for Writer<W, A> where W: Monoid
+{
+  fn bind<B, AMB>(self, f: AMB) -> Writer<W, B>
+    where AMB: F1<A, Ret = Writer<W, B>>
+  {
+    self.bind1(f)
+  }
+}
+deriving!(impl<W> MonadHKT for hkt::Writer<W> where W: Monoid {..Monad});
+
+impl<W, A> MonadRec<hkt::Writer<W>, A> for Writer<W, A> where W: Monoid
+{
+  fn tail_rec_m<S, F>(seed: S, f: F) -> Writer<W, A>
+    where F: F1<S, Ret = Writer<W, ControlFlow<A, S>>>
+  {
+    let mut s = seed;
+    let mut log = W::identity();
+    loop {
+      let Writer(step, w) = f.call(s);
+      log = log.append(w);
+      match step {
+        | ControlFlow::Break(a) => return Writer(a, log),
+        | ControlFlow::Continue(next) => s = next,
+      }
+    }
+  }
+}
+
+impl<W, A> FoldableOnce<hkt::Writer<W>, A> for Writer<W, A> {
+  fn fold1<B, BAB>(self, f: BAB, b: B) -> B
+    where BAB: F2Once<B, A, Ret = B>
+  {
+    f.call1(b, self.0)
+  }
+
+  fn fold1_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> B
+    where BAB: F2Once<B, &'a A, Ret = B>,
+          A: 'a
+  {
+    f.call1(b, &self.0)
+  }
+}
+deriving!(impl<W> Foldable<hkt::Writer<W>, A> for Writer<W, A> {..FoldableOnce});
+
+impl<W, A, B> TraversableOnce<hkt::Writer<W>, A, B, ()> for Writer<W, A>
+  where W: Clone,
+        hkt::Writer<W>: HKT1<T<B> = Writer<W, B>> + HKT1<T<A> = Writer<W, A>>
+{
+  fn traverse1m<Ap, AtoApOfB>(self, f: AtoApOfB) -> Ap::T<Writer<W, B>>
+    where Ap: HKT1,
+          Ap::T<B>: Applicative<Ap, B>,
+          Ap::T<Writer<W, B>>: Applicative<Ap, Writer<W, B>>,
+          AtoApOfB: F1Once<A, Ret = Ap::T<B>>
+  {
+    let Writer(a, w) = self;
+    f.call1(a).fmap(|b| Writer(b, w.clone()))
+  }
+
+  fn traverse11<Ap, AtoApOfB>(self, f: AtoApOfB) -> Ap::T<Writer<W, B>>
+    where Ap: HKT1,
+          Ap::T<B>: Applicative<Ap, B> + ApplyOnce<Ap, B>,
+          Ap::T<()>: Applicative<Ap, ()> + ApplyOnce<Ap, ()>,
+          Ap::T<Writer<W, B>>: Applicative<Ap, Writer<W, B>> + ApplyOnce<Ap, Writer<W, B>>,
+          AtoApOfB: F1Once<A, Ret = Ap::T<B>>
+  {
+    self.traverse1m::<Ap, AtoApOfB>(f)
+  }
+}
+
+impl<W, A, B> Traversable<hkt::Writer<W>, A, B, ()> for Writer<W, A>
+  where W: Clone,
+        hkt::Writer<W>: HKT1<T<B> = Writer<W, B>> + HKT1<T<A> = Writer<W, A>>
+{
+  fn traversem1<Ap, AtoApOfB>(self, f: AtoApOfB) -> Ap::T<Writer<W, B>>
+    where Ap: HKT1,
+          Ap::T<B>: Applicative<Ap, B> + ApplyOnce<Ap, B>,
+          Ap::T<()>: Applicative<Ap, ()> + ApplyOnce<Ap, ()>,
+          Ap::T<Writer<W, B>>: Applicative<Ap, Writer<W, B>> + ApplyOnce<Ap, Writer<W, B>>,
+          AtoApOfB: F1<A, Ret = Ap::T<B>>
+  {
+    self.traverse11::<Ap, AtoApOfB>(f)
+  }
+
+  fn traversemm<Ap, AtoApOfB>(self, f: AtoApOfB) -> Ap::T<Writer<W, B>>
+    where Ap: HKT1,
+          Ap::T<B>: Applicative<Ap, B>,
+          Ap::T<()>: Applicative<Ap, ()>,
+          Ap::T<Writer<W, B>>: Applicative<Ap, Writer<W, B>>,
+          AtoApOfB: F1<A, Ret = Ap::T<B>>
+  {
+    self.traverse1m::<Ap, AtoApOfB>(f)
+  }
+}
@@ -0,0 +1,181 @@
+use core::any::Any;
+use core::marker::PhantomData;
+
+use std_alloc::boxed::Box;
+use std_alloc::vec::Vec;
+
+use crate::prelude::*;
+
+type Value = Box<dyn Any>;
+type Cont = Box<dyn FnOnce(Value) -> Node>;
+
+enum Node {
+  Pure(Value),
+  Suspend(Box<dyn FnOnce() -> Value>),
+  Bind(Box<Node>, Cont),
+}
+
+fn downcast<A: 'static>(v: Value) -> A {
+  match v.downcast::<A>() {
+    | Ok(a) => *a,
+    | Err(_) => unreachable!("IOFree yielded a value of the wrong type"),
+  }
+}
+
+/// Stack-safe lazy managed I/O
+///
+/// [`IO`] builds a stack of structs that is collapsed recursively on
+/// [`exec`](IOLike::exec), so a chain of `bind_`s generated by a loop
+/// or by recursion can overflow the stack.
+///
+/// `IOFree` instead stores each step on the heap and [`exec`](IOLike::exec)
+/// runs them with a loop, using constant stack space no matter how deep
+/// the chain of [`IOFree::bind_`]s is.
+///
+/// Intermediate values are type-erased with [`Any`], so values and
+/// functions stored in an `IOFree` must be `'static`.
+///
+/// `IOFree` is an [`IOLike`], and any `IOLike` can be converted with [`IOFree::from_io`].
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// fn count_down(n: u64) -> IOFree<u64> {
+///   if n == 0 {
+///     IOFree::pure(0)
+///   } else {
+///     IOFree::pure(n).bind_(move |n| count_down(n - 1).map_(move |sum| sum + n))
+///   }
+/// }
+///
+/// assert_eq!(count_down(100_000).exec(), 5_000_050_000);
+///
+/// let doubled = IOFree::from_io(IO::suspend(|()| 21)).map_(|n: u8| n * 2);
+/// assert_eq!(doubled.exec(), 42);
+/// ```
+#[must_use = "IO is not evaluated until `IOLike.exec` invoked"]
+pub struct IOFree<A>(Option<Node>, PhantomData<fn() -> A>);
+
+impl<A> IOFree<A> where A: 'static
+{
+  fn new(node: Node) -> Self {
+    Self(Some(node), PhantomData)
+  }
+
+  fn into_node(mut self) -> Node {
+    self.0.take().expect("IOFree already executed")
+  }
+
+  /// Lift an eager value of type `A` to `IOFree<A>`.
+  pub fn pure(a: A) -> Self {
+    Self::new(Node::Pure(Box::new(a)))
+  }
+
+  /// Store a lazy computation
+  pub fn suspend<F>(f: F) -> Self
+    where F: F1Once<(), Ret = A> + 'static
+  {
+    Self::new(Node::Suspend(Box::new(move || Box::new(f.call1(())) as Value)))
+  }
+
+  /// Convert any [`IOLike`] to an `IOFree`
+  pub fn from_io<I>(io: I) -> Self
+    where I: IOLike<A> + 'static
+  {
+    Self::new(Node::Suspend(Box::new(move || Box::new(io.exec()) as Value)))
+  }
+
+  /// Use the value yielded by this computation to pick the next one
+  ///
+  /// Unlike [`MonadSurrogate::bind_`], this does not grow the stack
+  /// when executed.
+  pub fn bind_<B, F>(self, f: F) -> IOFree<B>
+    where B: 'static,
+          F: F1Once<A, Ret = IOFree<B>> + 'static
+  {
+    let k: Cont = Box::new(move |a: Value| f.call1(downcast::<A>(a)).into_node());
+    IOFree::new(Node::Bind(Box::new(self.into_node()), k))
+  }
+
+  /// Transform the value yielded by this computation
+  pub fn map_<B, F>(self, f: F) -> IOFree<B>
+    where B: 'static,
+          F: F1Once<A, Ret = B> + 'static
+  {
+    self.bind_(move |a| IOFree::pure(f.call1(a)))
+  }
+}
+
+impl<A> Drop for IOFree<A> {
+  fn drop(&mut self) {
+    // unexecuted chains of `bind_` are as deep as they are long,
+    // so unwind them with a loop rather than recursive drops.
+    let mut node = self.0.take();
+    while let Some(Node::Bind(m, _)) = node {
+      node = Some(*m);
+    }
+  }
+}
+
+impl<A> Equiv for IOFree<A> {
+  type To = IO<A>;
+}
+
+impl<A> IOLike<A> for IOFree<A> where A: 'static
+{
+  fn exec(self) -> A {
+    let mut stack: Vec<Cont> = Vec::new();
+    let mut node = self.into_node();
+
+    loop {
+      let v = match node {
+        | Node::Bind(m, k) => {
+          stack.push(k);
+          node = *m;
+          continue;
+        },
+        | Node::Pure(v) => v,
+        | Node::Suspend(f) => f(),
+      };
+
+      match stack.pop() {
+        | Some(k) => node = k(v),
+        | None => return downcast(v),
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn binds_a_million_steps() {
+    let io = (0..1_000_000u32).fold(IOFree::pure(0u32), |io, _| {
+                                io.bind_(|n| IOFree::from_io(IO::pure(n + 1)))
+                              });
+
+    assert_eq!(io.exec(), 1_000_000);
+  }
+
+  #[test]
+  fn recursion_a_million_deep() {
+    fn go(n: u32) -> IOFree<u32> {
+      if n == 0 {
+        IOFree::pure(0)
+      } else {
+        IOFree::suspend(move |()| n - 1).bind_(go).map_(|m| m + 1)
+      }
+    }
+
+    assert_eq!(go(1_000_000).exec(), 1_000_000);
+  }
+
+  #[test]
+  fn drop_a_million_steps() {
+    let io = (0..1_000_000u32).fold(IOFree::pure(0u32), |io, _| io.map_(|n| n + 1));
+
+    drop(io);
+  }
+}
@@ -12,9 +12,23 @@ pub mod apply;
 /// `IO::suspend`
 pub mod suspend;
 
+/// `IO::tail_rec_m`
+pub mod tail_rec;
+
+/// `IOResult`, lazy IO that may fail
+pub mod result;
+
+/// `IOFree`, stack-safe IO
+#[cfg(feature = "alloc")]
+pub mod free;
+
 pub use bind::*;
+#[cfg(feature = "alloc")]
+pub use free::IOFree;
 pub use map::*;
+pub use result::{IOResult, IOResultLike};
 pub use suspend::*;
+pub use tail_rec::TailRecM;
 
 /// IO HKT
 pub mod hkt {
@@ -179,6 +193,48 @@ impl<T> IO<T> {
   {
     Suspend(f)
   }
+
+  /// Run an IO action repeatedly, starting with `seed`, until it yields
+  /// [`ControlFlow::Break`](core::ops::ControlFlow::Break).
+  ///
+  /// This is the IO counterpart of [`MonadRec::tail_rec_m`], and runs
+  /// in constant stack space no matter how many times `f` is called.
+  ///
+  /// ```
+  /// use core::cell::Cell;
+  /// use core::ops::ControlFlow;
+  ///
+  /// use naan::prelude::*;
+  ///
+  /// fn poll(polls: &Cell<u32>) -> impl IOLike<Option<&'static str>> + '_ {
+  ///   IO::suspend(move |()| {
+  ///     polls.set(polls.get() + 1);
+  ///     if polls.get() == 100_000 {
+  ///       Some("ready")
+  ///     } else {
+  ///       None
+  ///     }
+  ///   })
+  /// }
+  ///
+  /// let polls = Cell::new(0);
+  /// let ready = IO::tail_rec_m((), |()| {
+  ///   poll(&polls).map_(|r: Option<&'static str>| match r {
+  ///                 | Some(s) => ControlFlow::Break(s),
+  ///                 | None => ControlFlow::Continue(()),
+  ///               })
+  /// });
+  ///
+  /// assert_eq!(polls.get(), 0);
+  /// assert_eq!(ready.exec(), "ready");
+  /// assert_eq!(polls.get(), 100_000);
+  /// ```
+  pub fn tail_rec_m<S, F>(seed: S, f: F) -> TailRecM<S, F, T>
+    where F: F1<S>,
+          F::Ret: IOLike<core::ops::ControlFlow<T, S>>
+  {
+    TailRecM::new(seed, f)
+  }
 }
 
 impl<A> Equiv for IO<A> {
@@ -190,3 +246,70 @@ impl<A> IOLike<A> for IO<A> {
     self.0
   }
 }
+
+/// `IO` as the base monad of a transformer, e.g. `ResultT<hkt::IO, E, A>`.
+///
+/// An `IO<A>` holds a value that has already been computed, so these run as
+/// soon as they're called. (A suspended `IO` would have to box the functions
+/// given to `fmap`/`bind`, and `MonadHKT` doesn't bound their lifetimes.)
+/// To defer a whole transformer stack until [`IOLike::exec`], build it
+/// inside [`IO::suspend`].
+///
+/// ```
+/// use core::cell::Cell;
+///
+/// use naan::prelude::*;
+///
+/// fn parse(s: &str) -> ResultT<hkt::IO, String, u8> {
+///   ResultT::new(IO::pure(s.parse().map_err(|_| format!("{s:?} isn't a u8"))))
+/// }
+///
+/// let reads = Cell::new(0);
+/// let read = |s: &'static str| {
+///   reads.set(reads.get() + 1);
+///   hkt::ResultT::lift(IO::pure(s))
+/// };
+///
+/// let sum = |a, b| {
+///   IO::suspend(move |()| {
+///     read(a).bind(parse)
+///            .bind(|a| read(b).bind(parse).fmap(move |b| a + b))
+///            .run()
+///            .exec()
+///   })
+/// };
+///
+/// let ok = sum("1", "2");
+/// let bad = sum("x", "2");
+/// assert_eq!(reads.get(), 0);
+///
+/// assert_eq!(ok.exec(), Ok(3));
+/// assert_eq!(reads.get(), 2);
+///
+/// assert_eq!(bad.exec(), Err(format!("\"x\" isn't a u8")));
+/// assert_eq!(reads.get(), 3);
+/// ```
+impl MonadHKT for hkt::IO {
+  fn pure<A>(a: A) -> IO<A> {
+    IO::pure(a)
+  }
+
+  fn fmap<A, B, AB>(ta: IO<A>, f: AB) -> IO<B>
+    where AB: F1<A, Ret = B>
+  {
+    IO::pure(f.call(ta.exec()))
+  }
+
+  fn apply_with<AB, A, B, Cloner>(tab: IO<AB>, ta: IO<A>, _: Cloner) -> IO<B>
+    where AB: F1<A, Ret = B>,
+          Cloner: for<'a> F1<&'a A, Ret = A>
+  {
+    IO::pure(tab.exec().call(ta.exec()))
+  }
+
+  fn bind<A, B, AMB>(ta: IO<A>, f: AMB) -> IO<B>
+    where AMB: F1<A, Ret = IO<B>>
+  {
+    f.call(ta.exec())
+  }
+}
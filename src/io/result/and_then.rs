@@ -0,0 +1,52 @@
+use core::marker::PhantomData;
+
+use super::{IOResult, IOResultLike};
+use crate::prelude::*;
+
+/// A function from `A` to an `IOResultLike<B, E>` waiting to be applied to
+/// the success value of a [computation that may fail](IOResultLike).
+///
+/// This is the result of calling [`MonadSurrogate.bind_`] or
+/// [`IOResultLike.and_then_`] on an [`IOResultLike`].
+#[must_use = "IO is not evaluated until `IOResultLike.exec` invoked"]
+pub struct AndThen<F, A, B, IOA>(F, IOA, PhantomData<(A, B)>);
+
+impl<F, A, B, IOA> AndThen<F, A, B, IOA> {
+  /// Create a new AndThen
+  pub fn new(f: F, ioa: IOA) -> Self {
+    Self(f, ioa, PhantomData)
+  }
+}
+
+impl<F, A, B, IOA> Clone for AndThen<F, A, B, IOA>
+  where F: Clone,
+        IOA: Clone
+{
+  fn clone(&self) -> Self {
+    Self(self.0.clone(), self.1.clone(), PhantomData)
+  }
+}
+
+impl<F, A, B, IOA> Copy for AndThen<F, A, B, IOA>
+  where F: Copy,
+        IOA: Copy
+{
+}
+
+impl<F, A, B, E, IOA> Equiv for AndThen<F, A, B, IOA>
+  where F: F1Once<A>,
+        F::Ret: Equiv<To = IOResult<B, E>>,
+        IOA: Equiv<To = IOResult<A, E>>
+{
+  type To = IOResult<B, E>;
+}
+
+impl<F, A, B, E, IOA> IOResultLike<B, E> for AndThen<F, A, B, IOA>
+  where F: F1Once<A>,
+        F::Ret: IOResultLike<B, E>,
+        IOA: IOResultLike<A, E>
+{
+  fn exec(self) -> Result<B, E> {
+    self.0.call1(self.1.exec()?).exec()
+  }
+}
@@ -0,0 +1,35 @@
+use core::marker::PhantomData;
+
+use super::{IOResult, IOResultLike};
+use crate::prelude::*;
+
+/// An [`IOResultLike`] that, when executed, will apply the function yielded by
+/// `IOAB` to the value yielded by `IOA`, stopping at the first failure.
+///
+/// This is the result of calling [`ApplySurrogate.apply_`] on an [`IOResultLike`].
+#[must_use = "IO is not evaluated until `IOResultLike.exec` invoked"]
+pub struct Apply<A, B, AB, IOA, IOAB>(IOAB, IOA, PhantomData<(A, B, AB)>);
+
+impl<A, B, AB, IOA, IOAB> Apply<A, B, AB, IOA, IOAB> {
+  /// Create a new Apply
+  pub fn new(ioab: IOAB, ioa: IOA) -> Self {
+    Self(ioab, ioa, PhantomData)
+  }
+}
+
+impl<A, B, AB, E, IOA, IOAB> Equiv for Apply<A, B, AB, IOA, IOAB>
+  where IOAB: Equiv<To = IOResult<AB, E>>
+{
+  type To = IOResult<B, E>;
+}
+
+impl<A, B, AB, E, IOA, IOAB> IOResultLike<B, E> for Apply<A, B, AB, IOA, IOAB>
+  where AB: F1Once<A, Ret = B>,
+        IOA: IOResultLike<A, E>,
+        IOAB: IOResultLike<AB, E>
+{
+  fn exec(self) -> Result<B, E> {
+    let ab = self.0.exec()?;
+    Ok(ab.call1(self.1.exec()?))
+  }
+}
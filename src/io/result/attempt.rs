@@ -0,0 +1,28 @@
+use super::{IOResult, IOResultLike};
+use crate::prelude::*;
+
+/// An [`IOResultLike`] viewed as an [`IOLike`] yielding its [`Result`]
+///
+/// This is the result of calling [`IOResultLike.attempt`].
+#[derive(Debug, Clone, Copy)]
+#[must_use = "IO is not evaluated until `IOLike.exec` invoked"]
+pub struct Attempt<I>(I);
+
+impl<I> Attempt<I> {
+  /// See [`IOResultLike.attempt`]
+  pub fn new(io: I) -> Self {
+    Self(io)
+  }
+}
+
+impl<I, A, E> Equiv for Attempt<I> where I: Equiv<To = IOResult<A, E>>
+{
+  type To = IO<Result<A, E>>;
+}
+
+impl<I, A, E> IOLike<Result<A, E>> for Attempt<I> where I: IOResultLike<A, E>
+{
+  fn exec(self) -> Result<A, E> {
+    IOResultLike::exec(self.0)
+  }
+}
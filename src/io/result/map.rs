@@ -0,0 +1,49 @@
+use core::marker::PhantomData;
+
+use super::{IOResult, IOResultLike};
+use crate::prelude::*;
+
+/// A function from `X` to some `A` waiting to be applied to
+/// the success value of a [computation that may fail](IOResultLike).
+///
+/// This is the result of calling [`FunctorSurrogate.map_`] on an [`IOResultLike`].
+#[must_use = "IO is not evaluated until `IOResultLike.exec` invoked"]
+pub struct Map<F, X, A, IOX>(F, IOX, PhantomData<(X, A)>);
+
+impl<F, X, A, IOX> Map<F, X, A, IOX> {
+  /// Create a new Map
+  pub fn new(f: F, iox: IOX) -> Self {
+    Self(f, iox, PhantomData)
+  }
+}
+
+impl<F, X, A, IOX> Clone for Map<F, X, A, IOX>
+  where F: Clone,
+        IOX: Clone
+{
+  fn clone(&self) -> Self {
+    Self(self.0.clone(), self.1.clone(), PhantomData)
+  }
+}
+
+impl<F, X, A, IOX> Copy for Map<F, X, A, IOX>
+  where F: Copy,
+        IOX: Copy
+{
+}
+
+impl<F, X, A, E, IOX> Equiv for Map<F, X, A, IOX>
+  where F: F1Once<X, Ret = A>,
+        IOX: Equiv<To = IOResult<X, E>>
+{
+  type To = IOResult<A, E>;
+}
+
+impl<F, X, A, E, IOX> IOResultLike<A, E> for Map<F, X, A, IOX>
+  where F: F1Once<X, Ret = A>,
+        IOX: IOResultLike<X, E>
+{
+  fn exec(self) -> Result<A, E> {
+    self.1.exec().map(|x| self.0.call1(x))
+  }
+}
@@ -0,0 +1,49 @@
+use core::marker::PhantomData;
+
+use super::{IOResult, IOResultLike};
+use crate::prelude::*;
+
+/// A function from `E` to some `E2` waiting to be applied to
+/// the error of a [computation that may fail](IOResultLike).
+///
+/// This is the result of calling [`IOResultLike.map_err_`].
+#[must_use = "IO is not evaluated until `IOResultLike.exec` invoked"]
+pub struct MapErr<F, E, E2, IOA>(F, IOA, PhantomData<(E, E2)>);
+
+impl<F, E, E2, IOA> MapErr<F, E, E2, IOA> {
+  /// Create a new MapErr
+  pub fn new(f: F, ioa: IOA) -> Self {
+    Self(f, ioa, PhantomData)
+  }
+}
+
+impl<F, E, E2, IOA> Clone for MapErr<F, E, E2, IOA>
+  where F: Clone,
+        IOA: Clone
+{
+  fn clone(&self) -> Self {
+    Self(self.0.clone(), self.1.clone(), PhantomData)
+  }
+}
+
+impl<F, E, E2, IOA> Copy for MapErr<F, E, E2, IOA>
+  where F: Copy,
+        IOA: Copy
+{
+}
+
+impl<F, A, E, E2, IOA> Equiv for MapErr<F, E, E2, IOA>
+  where F: F1Once<E, Ret = E2>,
+        IOA: Equiv<To = IOResult<A, E>>
+{
+  type To = IOResult<A, E2>;
+}
+
+impl<F, A, E, E2, IOA> IOResultLike<A, E2> for MapErr<F, E, E2, IOA>
+  where F: F1Once<E, Ret = E2>,
+        IOA: IOResultLike<A, E>
+{
+  fn exec(self) -> Result<A, E2> {
+    self.1.exec().map_err(|e| self.0.call1(e))
+  }
+}
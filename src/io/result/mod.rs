@@ -0,0 +1,267 @@
+use crate::prelude::*;
+
+/// `IOResult.map_`
+pub mod map;
+
+/// `IOResultLike.map_err_`
+pub mod map_err;
+
+/// `IOResult.bind_`, `IOResultLike.and_then_`
+pub mod and_then;
+
+/// `IOResultLike.recover_`
+pub mod recover;
+
+/// `IOResult.apply_`
+pub mod apply;
+
+/// `IOResult::suspend`, `IOResult::from_io`
+pub mod suspend;
+
+/// `IOResultLike.attempt`
+pub mod attempt;
+
+pub use and_then::AndThen;
+pub use attempt::Attempt;
+pub use map::Map;
+pub use map_err::MapErr;
+pub use recover::Recover;
+pub use suspend::{FromIO, Suspend};
+
+/// IOResult HKT
+pub mod hkt {
+  use core::marker::PhantomData;
+
+  use crate::prelude::*;
+
+  /// `IOResult<A, E>` with the error type pinned to some `E`.
+  ///
+  /// (Kind `Type -> Type`)
+  pub struct IOResult<E>(PhantomData<E>);
+
+  impl<E> HKT1 for IOResult<E> {
+    type T<A> = super::IOResult<A, E>;
+  }
+}
+
+/// A lazy computation that may fail
+pub trait IOResultLike<A, E>
+  where Self: Sized + Equiv<To = IOResult<A, E>>
+{
+  /// Execute this lazy computation
+  fn exec(self) -> Result<A, E>;
+
+  /// Convert to an [`IOLike`] yielding the [`Result`],
+  /// so that `map_` and `bind_` see failures as well as successes.
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let n = IOResult::<u8, &str>::err("oh no").attempt()
+  ///                                           .map_(|r: Result<u8, &str>| r.unwrap_or(0));
+  ///
+  /// assert_eq!(n.exec(), 0);
+  /// ```
+  fn attempt(self) -> Attempt<Self> {
+    Attempt::new(self)
+  }
+
+  /// Transform the error, if this computation fails
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let n = IOResult::<u8, &str>::err("oh no").map_err_(str::len);
+  ///
+  /// assert_eq!(n.exec(), Err(5));
+  /// ```
+  fn map_err_<E2, F>(self, f: F) -> MapErr<F, E, E2, Self>
+    where F: F1Once<E, Ret = E2>
+  {
+    MapErr::new(f, self)
+  }
+
+  /// If this computation fails, use the error to pick
+  /// another computation to run in its place
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let n = IOResult::<u8, &str>::err("oh no").recover_(|_| IOResult::<u8, ()>::ok(0));
+  ///
+  /// assert_eq!(n.exec(), Ok(0));
+  /// ```
+  fn recover_<E2, F>(self, f: F) -> Recover<F, E, E2, Self>
+    where F: F1Once<E>,
+          F::Ret: IOResultLike<A, E2>
+  {
+    Recover::new(f, self)
+  }
+
+  /// [`MonadSurrogate::bind_`], but the function may return
+  /// any [`IOResultLike`] (e.g. [`Suspend`], another [`AndThen`])
+  /// rather than only [`IOResult`].
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let n = IOResult::<u8, &str>::ok(1).and_then_(|n: u8| IOResult::suspend(move |()| Ok(n + 1)));
+  ///
+  /// assert_eq!(n.exec(), Ok(2));
+  /// ```
+  fn and_then_<B, F>(self, f: F) -> AndThen<F, A, B, Self>
+    where F: F1Once<A>,
+          F::Ret: IOResultLike<B, E>
+  {
+    AndThen::new(f, self)
+  }
+}
+
+impl<I, A, E> FunctorSurrogate<hkt::IOResult<E>, A> for I where I: IOResultLike<A, E>
+{
+  type Output<AB, B> = Map<AB, A, B, I>;
+
+  fn map_<AB, B>(self, f: AB) -> Map<AB, A, B, I>
+    where AB: F1<A, Ret = B>
+  {
+    Map::new(f, self)
+  }
+}
+
+impl<I, E, AB, TofA> ApplySurrogate<hkt::IOResult<E>, AB, TofA> for I where I: IOResultLike<AB, E>
+{
+  type ApplyOutput<A, B> = apply::Apply<A, B, AB, TofA, Self>;
+
+  fn apply_<A, B>(self, a: TofA) -> apply::Apply<A, B, AB, TofA, Self>
+    where AB: F1Once<A, Ret = B>
+  {
+    apply::Apply::new(self, a)
+  }
+}
+
+impl<I, A, E> ApplicativeSurrogate<hkt::IOResult<E>, A> for I where I: IOResultLike<A, E>
+{
+  fn pure(a: A) -> IOResult<A, E> {
+    IOResult::ok(a)
+  }
+}
+
+impl<I, A, E> MonadSurrogate<hkt::IOResult<E>, A> for I where I: IOResultLike<A, E>
+{
+  type BindOutput<B, AMB> = AndThen<AMB, A, B, Self>;
+
+  fn bind_<B, AMB>(self, f: AMB) -> Self::BindOutput<B, AMB>
+    where AMB: F1<A, Ret = IOResult<B, E>>
+  {
+    AndThen::new(f, self)
+  }
+}
+
+/// Lazy managed I/O that may fail
+///
+/// [`IOLike::exec`] has no concept of failure, so an `IO` that may fail
+/// would yield a `Result` and every `map_` / `bind_` would need to match on it.
+///
+/// `IOResult` and the types returned by its combinators implement
+/// [`IOResultLike`] instead, whose `map_` / `apply_` / `bind_` act on the
+/// success value and stop at the first failure. Like [`IO`], this is done
+/// by building a stack of structs so no heap allocations are needed.
+///
+/// Primitives are:
+///  - [`IOResult::ok`], [`IOResult::err`], [`IOResult::from_result`] - eager values
+///  - [`IOResult::suspend`] - any function from `()` to `Result<A, E>`
+///  - [`IOResult::from_io`] - any [`IOLike`] yielding `Result<A, E>`
+///
+/// Since [`IOResult::suspend`] accepts closures returning a plain [`Result`],
+/// `?` can be used inside them, including on other `IOResultLike`s that are [`exec`](IOResultLike::exec)uted there.
+///
+/// ```
+/// use std::cell::Cell;
+///
+/// use naan::prelude::*;
+///
+/// fn parse(s: &'static str) -> impl IOResultLike<u32, String> {
+///   IOResult::suspend(move |()| s.parse::<u32>().map_err(|e| format!("{s:?}: {e}")))
+/// }
+///
+/// fn div(a: u32, b: u32) -> impl IOResultLike<u32, String> {
+///   IOResult::suspend(move |()| a.checked_div(b).ok_or(format!("{a} / 0")))
+/// }
+///
+/// let divs = Cell::new(0);
+/// let parse_div = |a, b| {
+///   parse(a).and_then_(move |a| parse(b).map_(move |b| (a, b)))
+///           .and_then_(|(a, b)| {
+///             divs.set(divs.get() + 1);
+///             div(a, b)
+///           })
+/// };
+///
+/// assert_eq!(parse_div("10", "2").exec(), Ok(5));
+/// assert_eq!(parse_div("10", "0").exec(), Err(format!("10 / 0")));
+/// assert_eq!(divs.get(), 2);
+///
+/// assert_eq!(parse_div("x", "2").exec(),
+///            Err(format!("\"x\": invalid digit found in string")));
+/// assert_eq!(divs.get(), 2);
+///
+/// let sum = IOResult::suspend(|()| -> Result<u32, String> {
+///   let a = parse("1").exec()?;
+///   let b = parse("2").exec()?;
+///   Ok(a + b)
+/// });
+///
+/// assert_eq!(sum.exec(), Ok(3));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[must_use = "IO is not evaluated until `IOResultLike.exec` invoked"]
+pub struct IOResult<A, E>(Result<A, E>);
+
+impl<A, E> IOResult<A, E> {
+  /// Lift an eager success `A` to `IOResult<A, E>`.
+  pub fn ok(a: A) -> Self {
+    Self(Ok(a))
+  }
+
+  /// Lift an eager failure `E` to `IOResult<A, E>`.
+  pub fn err(e: E) -> Self {
+    Self(Err(e))
+  }
+
+  /// Lift an eager `Result<A, E>` to `IOResult<A, E>`.
+  pub fn from_result(r: Result<A, E>) -> Self {
+    Self(r)
+  }
+
+  /// Store a lazy computation that may fail
+  pub fn suspend<F>(f: F) -> Suspend<F>
+    where F: F1Once<(), Ret = Result<A, E>>
+  {
+    Suspend::new(f)
+  }
+
+  /// Treat an [`IOLike`] yielding `Result<A, E>` as a computation that may fail
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let io = IO::suspend(|()| "12".parse::<u8>());
+  ///
+  /// assert_eq!(IOResult::from_io(io).map_(|n| n + 1).exec(), Ok(13));
+  /// ```
+  pub fn from_io<I>(io: I) -> FromIO<I>
+    where I: IOLike<Result<A, E>>
+  {
+    FromIO::new(io)
+  }
+}
+
+impl<A, E> Equiv for IOResult<A, E> {
+  type To = IOResult<A, E>;
+}
+
+impl<A, E> IOResultLike<A, E> for IOResult<A, E> {
+  fn exec(self) -> Result<A, E> {
+    self.0
+  }
+}
@@ -0,0 +1,54 @@
+use core::marker::PhantomData;
+
+use super::{IOResult, IOResultLike};
+use crate::prelude::*;
+
+/// A function from `E` to an `IOResultLike<A, E2>` waiting to be applied to
+/// the error of a [computation that may fail](IOResultLike).
+///
+/// This is the result of calling [`IOResultLike.recover_`].
+#[must_use = "IO is not evaluated until `IOResultLike.exec` invoked"]
+pub struct Recover<F, E, E2, IOA>(F, IOA, PhantomData<(E, E2)>);
+
+impl<F, E, E2, IOA> Recover<F, E, E2, IOA> {
+  /// Create a new Recover
+  pub fn new(f: F, ioa: IOA) -> Self {
+    Self(f, ioa, PhantomData)
+  }
+}
+
+impl<F, E, E2, IOA> Clone for Recover<F, E, E2, IOA>
+  where F: Clone,
+        IOA: Clone
+{
+  fn clone(&self) -> Self {
+    Self(self.0.clone(), self.1.clone(), PhantomData)
+  }
+}
+
+impl<F, E, E2, IOA> Copy for Recover<F, E, E2, IOA>
+  where F: Copy,
+        IOA: Copy
+{
+}
+
+impl<F, A, E, E2, IOA> Equiv for Recover<F, E, E2, IOA>
+  where F: F1Once<E>,
+        F::Ret: Equiv<To = IOResult<A, E2>>,
+        IOA: Equiv<To = IOResult<A, E>>
+{
+  type To = IOResult<A, E2>;
+}
+
+impl<F, A, E, E2, IOA> IOResultLike<A, E2> for Recover<F, E, E2, IOA>
+  where F: F1Once<E>,
+        F::Ret: IOResultLike<A, E2>,
+        IOA: IOResultLike<A, E>
+{
+  fn exec(self) -> Result<A, E2> {
+    match self.1.exec() {
+      | Ok(a) => Ok(a),
+      | Err(e) => self.0.call1(e).exec(),
+    }
+  }
+}
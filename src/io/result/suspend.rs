@@ -0,0 +1,55 @@
+use super::{IOResult, IOResultLike};
+use crate::prelude::*;
+
+/// A deferred computation that may fail
+///
+/// See [`IOResult::suspend`]
+#[derive(Debug, Clone, Copy)]
+#[must_use = "IO is not evaluated until `IOResultLike.exec` invoked"]
+pub struct Suspend<F>(F);
+
+impl<F> Suspend<F> {
+  /// See [`IOResult::suspend`]
+  pub fn new(f: F) -> Self {
+    Self(f)
+  }
+}
+
+impl<F, A, E> Equiv for Suspend<F> where F: F1Once<(), Ret = Result<A, E>>
+{
+  /// `Suspend<F>` is conceptually equivalent to `IOResult<A, E>`
+  type To = IOResult<A, E>;
+}
+
+impl<F, A, E> IOResultLike<A, E> for Suspend<F> where F: F1Once<(), Ret = Result<A, E>>
+{
+  fn exec(self) -> Result<A, E> {
+    self.0.call1(())
+  }
+}
+
+/// An [`IOLike`] yielding a [`Result`], treated as a computation that may fail
+///
+/// See [`IOResult::from_io`]
+#[derive(Debug, Clone, Copy)]
+#[must_use = "IO is not evaluated until `IOResultLike.exec` invoked"]
+pub struct FromIO<I>(I);
+
+impl<I> FromIO<I> {
+  /// See [`IOResult::from_io`]
+  pub fn new(io: I) -> Self {
+    Self(io)
+  }
+}
+
+impl<I, A, E> Equiv for FromIO<I> where I: Equiv<To = IO<Result<A, E>>>
+{
+  type To = IOResult<A, E>;
+}
+
+impl<I, A, E> IOResultLike<A, E> for FromIO<I> where I: IOLike<Result<A, E>>
+{
+  fn exec(self) -> Result<A, E> {
+    IOLike::exec(self.0)
+  }
+}
@@ -0,0 +1,51 @@
+use core::marker::PhantomData;
+use core::ops::ControlFlow;
+
+use crate::prelude::*;
+
+/// A loop of IO actions, run in constant stack space
+///
+/// This is the result of calling [`IO::tail_rec_m`].
+#[must_use = "IO is not evaluated until `IOLike.exec` invoked"]
+pub struct TailRecM<S, F, A>(S, F, PhantomData<A>);
+
+impl<S, F, A> TailRecM<S, F, A> {
+  /// Create a new TailRecM
+  pub fn new(seed: S, f: F) -> Self {
+    Self(seed, f, PhantomData)
+  }
+}
+
+impl<S, F, A> Clone for TailRecM<S, F, A>
+  where S: Clone,
+        F: Clone
+{
+  fn clone(&self) -> Self {
+    Self(self.0.clone(), self.1.clone(), PhantomData)
+  }
+}
+
+impl<S, F, A> Copy for TailRecM<S, F, A>
+  where S: Copy,
+        F: Copy
+{
+}
+
+impl<S, F, A> Equiv for TailRecM<S, F, A> {
+  type To = IO<A>;
+}
+
+impl<S, F, A> IOLike<A> for TailRecM<S, F, A>
+  where F: F1<S>,
+        F::Ret: IOLike<ControlFlow<A, S>>
+{
+  fn exec(self) -> A {
+    let Self(mut s, f, _) = self;
+    loop {
+      match f.call(s).exec() {
+        | ControlFlow::Break(a) => return a,
+        | ControlFlow::Continue(next) => s = next,
+      }
+    }
+  }
+}
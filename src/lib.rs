@@ -652,12 +652,27 @@ pub mod alt;
 /// Discard
 pub mod discard;
 
+/// Do-notation
+pub mod do_notation;
+
 /// Apply, Applicative
 pub mod apply;
 
 /// Bifunctor
 pub mod bifunctor;
 
+/// CFunctor, CFoldable, CMonad
+pub mod constrained;
+
+/// Contravariant
+pub mod contravariant;
+
+/// Bifoldable
+pub mod bifold;
+
+/// Bitraversable, Bisequence
+pub mod bitraverse;
+
 /// Monad
 pub mod monad;
 
@@ -670,15 +685,33 @@ pub mod fun;
 /// Functor
 pub mod functor;
 
+/// Profunctor
+pub mod profunctor;
+
 /// Implementors
 pub mod impls;
 
 /// Lazy managed effects
 pub mod io;
 
+/// lift_a2..lift_a8, ado!
+pub mod lift;
+
+/// Lazy computations that read from an environment
+pub mod reader;
+
 /// Semigroup, Monoid
 pub mod semigroup;
 
+/// Newtypes that give a type a particular Monoid (Sum, Max, Any, ...)
+pub mod monoid;
+
+/// Lazy stateful computations
+pub mod state;
+
+/// Monad transformers
+pub mod transformer;
+
 /// Traversable
 pub mod traverse;
 
@@ -686,17 +719,50 @@ pub(crate) enum Never {}
 
 /// Re-exports of HKT markers for types that have provided implementations
 pub mod hkt {
+  pub use crate::impls::array::hkt::Array;
+  #[cfg(feature = "alloc")]
+  pub use crate::impls::binary_heap::hkt::BinaryHeap;
+  #[cfg(feature = "alloc")]
+  pub use crate::impls::btree_map::hkt::{BTreeMap, BTreeMapKeys, BTreeMapValues};
   #[cfg(feature = "alloc")]
-  pub use crate::impls::btree_map::hkt::{BTreeMap, BTreeMapValues};
+  pub use crate::impls::btree_set::hkt::BTreeSet;
+  #[cfg(feature = "alloc")]
+  pub use crate::impls::comparator::hkt::Comparator;
+  pub use crate::impls::either::hkt::{Either, EitherRight};
+  #[cfg(feature = "alloc")]
+  pub use crate::impls::equivalence::hkt::Equivalence;
   #[cfg(feature = "std")]
-  pub use crate::impls::hash_map::hkt::{HashMap, HashMapValues};
+  pub use crate::impls::hash_map::hkt::{HashMap, HashMapKeys, HashMapValues};
+  #[cfg(feature = "std")]
+  pub use crate::impls::hash_set::hkt::HashSet;
   pub use crate::impls::identity::hkt::Id;
+  #[cfg(feature = "alloc")]
+  pub use crate::impls::linked_list::hkt::LinkedList;
+  #[cfg(feature = "alloc")]
+  pub use crate::impls::non_empty_vec::hkt::NonEmptyVec;
   pub use crate::impls::option::hkt::Option;
+  #[cfg(feature = "alloc")]
+  pub use crate::impls::predicate::hkt::Predicate;
   pub use crate::impls::result::hkt::{Result, ResultOk};
+  pub use crate::impls::these::hkt::{These, TheseRight};
   #[cfg(feature = "tinyvec")]
   pub use crate::impls::tinyvec::hkt::ArrayVec;
+  pub use crate::impls::tuple::hkt::{Pair, Tuple2};
+  pub use crate::impls::validation::hkt::{Validation, Validation2};
   #[cfg(feature = "alloc")]
   pub use crate::impls::vec::hkt::Vec;
+  #[cfg(feature = "alloc")]
+  pub use crate::impls::vec_deque::hkt::VecDeque;
+  pub use crate::impls::writer::hkt::Writer;
+  pub use crate::io::hkt::IO;
+  pub use crate::io::result::hkt::IOResult;
+  pub use crate::reader::hkt::Reader;
+  pub use crate::state::hkt::State;
+  pub use crate::transformer::option_t::hkt::OptionT;
+  pub use crate::transformer::reader_t::hkt::ReaderT;
+  pub use crate::transformer::result_t::hkt::ResultT;
+  pub use crate::transformer::state_t::hkt::StateT;
+  pub use crate::transformer::writer_t::hkt::WriterT;
 
   /// std
   #[cfg(feature = "std")]
@@ -709,25 +775,69 @@ pub mod hkt {
   }
 }
 
+#[cfg(feature = "derive")]
+pub use naan_derive::{Foldable, Functor, Monoid, Semigroup, Traversable};
+
 /// Glob import that provides all of the `naan` typeclasses
 pub mod prelude {
+  #[cfg(feature = "derive")]
+  pub use naan_derive::{Foldable, Functor, Monoid, Semigroup, Traversable};
+
   pub use crate::alt::*;
   pub use crate::apply::*;
+  pub use crate::bifold::*;
   pub use crate::bifunctor::*;
+  pub use crate::bitraverse::*;
+  pub use crate::constrained::*;
+  pub use crate::contravariant::*;
   pub use crate::discard::*;
   pub use crate::fold::*;
+  #[cfg(feature = "alloc")]
+  pub use crate::fun::boxed::*;
+  pub use crate::fun::combinators::*;
   pub use crate::fun::compose::*;
-  pub use crate::fun::curry2::*;
-  pub use crate::fun::curry3::*;
+  pub use crate::fun::curry2::{Applied0, Applied1, Curry2};
+  pub use crate::fun::curry3::{Applied2, Curry3};
+  pub use crate::fun::curry4::Curry4;
+  pub use crate::fun::curry5::Curry5;
+  pub use crate::fun::curry6::Curry6;
+  pub use crate::fun::curry7::Curry7;
+  pub use crate::fun::curry8::Curry8;
   pub use crate::fun::*;
   pub use crate::functor::*;
+  #[cfg(feature = "alloc")]
+  pub use crate::impls::comparator::Comparator;
+  pub use crate::impls::either::Either;
+  #[cfg(feature = "alloc")]
+  pub use crate::impls::equivalence::Equivalence;
   pub use crate::impls::identity::*;
+  #[cfg(feature = "alloc")]
+  pub use crate::impls::non_empty_vec::NonEmptyVec;
+  #[cfg(feature = "alloc")]
+  pub use crate::impls::predicate::Predicate;
   pub use crate::impls::result::ResultExt;
+  pub use crate::impls::these::These;
+  pub use crate::impls::validation::Validation;
+  pub use crate::impls::writer::Writer;
   pub use crate::io::*;
+  pub use crate::lift::*;
   pub use crate::monad::*;
+  pub use crate::monoid::*;
+  pub use crate::profunctor::*;
+  pub use crate::reader::{Reader, ReaderLike};
   pub use crate::semigroup::*;
+  pub use crate::state::{State, StateLike};
+  pub use crate::transformer::{MonadHKT,
+                               MonadTrans,
+                               OptionT,
+                               ReaderT,
+                               ReaderTLike,
+                               ResultT,
+                               StateT,
+                               StateTLike,
+                               WriterT};
   pub use crate::traverse::*;
-  pub use crate::{deriving, hkt, Equiv, HKT1, HKT2};
+  pub use crate::{ado, deriving, do_, hkt, mdo, Constraint, Equiv, HKT1, HKT1C, HKT2};
 }
 
 /// An `Equiv` type is one that is conceptually the same as some
@@ -784,6 +894,60 @@ pub trait HKT1 {
   type T<A>;
 }
 
+/// [`HKT1`] for a type with 1 generic parameter that can only
+/// be constructed when the parameter satisfies some constraint,
+/// e.g. `HashSet<A>` requires `A: Hash + Eq`.
+///
+/// The constraint is expressed by implementing [`Constraint`]
+/// for every type that satisfies it.
+///
+/// ```
+/// use std::collections::BTreeSet;
+///
+/// use naan::prelude::*;
+///
+/// struct SortedHKT;
+///
+/// impl HKT1C for SortedHKT {
+///   type T<A> = BTreeSet<A>;
+///   type Item<A> = A;
+/// }
+///
+/// // outside of `naan`, a blanket `impl<A: Ord> Constraint<SortedHKT> for A`
+/// // is rejected by the orphan rules, so each type is listed.
+/// impl Constraint<SortedHKT> for u32 {
+///   fn collect<I>(items: I) -> BTreeSet<u32>
+///     where I: IntoIterator<Item = u32>
+///   {
+///     items.into_iter().collect()
+///   }
+/// }
+///
+/// assert_eq!(<u32 as Constraint<SortedHKT>>::collect([2, 1, 2]),
+///            BTreeSet::from([1, 2]));
+/// ```
+pub trait HKT1C {
+  /// The generic type
+  type T<A>;
+
+  /// The pieces that `T<A>` is built from; usually `A`,
+  /// but e.g. `(A, V)` for a map with keys of type `A`.
+  type Item<A>;
+}
+
+/// Implemented for every type that satisfies the constraint
+/// of the [`HKT1C`] `F`.
+///
+/// Since rust can't (yet) abstract over traits, this also carries
+/// the only operation that needs the constraint; building `F::T<Self>`.
+pub trait Constraint<F>: Sized
+  where F: HKT1C
+{
+  /// Build `F::T<Self>` from its pieces
+  fn collect<I>(items: I) -> F::T<Self>
+    where I: IntoIterator<Item = F::Item<Self>>;
+}
+
 /// A marker that points to a type with 2 generic
 /// parameters.
 ///
@@ -929,6 +1093,24 @@ macro_rules! deriving {
         where ABB: F2<&'a A, B, Ret = B>, A: 'a {
         self.fold1_ref(|a, b| f.call(b, a), b)
       }
+
+      fn try_foldl<B, BAB>(self, f: BAB, b: B) -> ::core::ops::ControlFlow<B, B>
+      where BAB: F2<B, A, Ret = ::core::ops::ControlFlow<B, B>> {
+        self.fold1(|b, a| match b {
+                     ::core::ops::ControlFlow::Continue(b) => f.call(b, a),
+                     done => done,
+                   },
+                   ::core::ops::ControlFlow::Continue(b))
+      }
+
+      fn try_foldl_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> ::core::ops::ControlFlow<B, B>
+      where BAB: F2<B, &'a A, Ret = ::core::ops::ControlFlow<B, B>>, A: 'a {
+        self.fold1_ref(|b, a| match b {
+                         ::core::ops::ControlFlow::Continue(b) => f.call(b, a),
+                         done => done,
+                       },
+                       ::core::ops::ControlFlow::Continue(b))
+      }
     }
   };
   (impl$(<$($vars:ident),+>)? Foldable<$hkt:ty, $a:ident> for $t:ty {..FoldableIndexed}) => {
@@ -952,6 +1134,16 @@ macro_rules! deriving {
         where ABB: F2<&'a A, B, Ret = B>, A: 'a {
         self.foldr_idx_ref(|_, a, b| f.call(a, b), b)
       }
+
+      fn try_foldl<B, BAB>(self, f: BAB, b: B) -> ::core::ops::ControlFlow<B, B>
+      where BAB: F2<B, A, Ret = ::core::ops::ControlFlow<B, B>> {
+        self.try_foldl_idx(|b, _, a| f.call(b, a), b)
+      }
+
+      fn try_foldl_ref<'a, B, BAB>(&'a self, f: BAB, b: B) -> ::core::ops::ControlFlow<B, B>
+      where BAB: F2<B, &'a A, Ret = ::core::ops::ControlFlow<B, B>>, A: 'a {
+        self.try_foldl_idx_ref(|b, _, a| f.call(b, a), b)
+      }
     }
   };
   (impl$(<$($vars:ident),+>)? Traversable<$hkt:ty, $a:ident, $b:ident, $tf:ty> for $t:ty {..TraversableOnce}) => {
@@ -976,6 +1168,27 @@ macro_rules! deriving {
       }
     }
   };
+  (impl$(<$($vars:ident),+>)? MonadHKT for $hkt:ty $(where $($bvar:ident: $bound:ident $(+ $more:ident)*),+)? {..Monad}) => {
+    impl$(<$($vars),+>)? MonadHKT for $hkt $(where $($bvar: $bound $(+ $more)*),+)? {
+      fn pure<A>(a: A) -> <$hkt as HKT1>::T<A> {
+        <<$hkt as HKT1>::T<A> as Applicative<$hkt, A>>::pure(a)
+      }
+
+      fn fmap<A, B, AB>(ta: <$hkt as HKT1>::T<A>, f: AB) -> <$hkt as HKT1>::T<B> where AB: F1<A, Ret = B> {
+        Functor::<$hkt, A>::fmap(ta, f)
+      }
+
+      fn apply_with<AB, A, B, Cloner>(tab: <$hkt as HKT1>::T<AB>, ta: <$hkt as HKT1>::T<A>, cloner: Cloner) -> <$hkt as HKT1>::T<B>
+        where AB: F1<A, Ret = B>,
+              Cloner: for<'a> F1<&'a A, Ret = A> {
+        Apply::<$hkt, AB>::apply_with(tab, ta, cloner)
+      }
+
+      fn bind<A, B, AMB>(ta: <$hkt as HKT1>::T<A>, f: AMB) -> <$hkt as HKT1>::T<B> where AMB: F1<A, Ret = <$hkt as HKT1>::T<B>> {
+        Monad::<$hkt, A>::bind(ta, f)
+      }
+    }
+  };
   (impl$(<$($vars:ident),+>)? Monad<$hkt:ty, $a:ident> for $t:ty {..MonadOnce}) => {
     impl<$a, $($($vars),+)?> Monad<$hkt, $a> for $t {
       fn bind<B, AMB>(self, f: AMB) -> <$hkt as HKT1>::T<B> where AMB: F1<$a, Ret = <$hkt as HKT1>::T<B>> {
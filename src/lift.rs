@@ -0,0 +1,344 @@
+use crate::fun::{curry2, curry3, curry4, curry5, curry6, curry7, curry8};
+use crate::prelude::*;
+
+/// [`Functor::fmap`] for closures, which [`ado!`](crate::ado) desugars
+/// a single binding to so that the closure's argument type is inferred.
+#[doc(hidden)]
+pub fn fmap<F, AB, A, B, FA>(f: AB, fa: FA) -> F::T<B>
+  where F: HKT1<T<A> = FA>,
+        FA: Functor<F, A>,
+        AB: Fn(A) -> B
+{
+  fa.fmap::<AB, B>(f)
+}
+
+/// Lift a function of 2 arguments to a function
+/// of 2 [`Apply`]s
+///
+/// Shorthand for `fa.fmap(f.curry()).apply(fb)`.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// assert_eq!(lift_a2(|a: u8, b: u8| a + b, Some(1), Some(2)), Some(3));
+/// assert_eq!(lift_a2(|a: u8, b: u8| a + b, Some(1), None), None);
+/// assert_eq!(lift_a2(|a: u8, b: char| (a, b), vec![1, 2], vec!['a', 'b']),
+///            vec![(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]);
+/// ```
+pub fn lift_a2<F, AB, A, B, C, FA>(f: AB, fa: FA, fb: F::T<B>) -> F::T<C>
+  where F: HKT1<T<A> = FA>,
+        FA: Functor<F, A>,
+        F::T<curry2::Applied1<AB, A, B, C>>: Apply<F, curry2::Applied1<AB, A, B, C>>,
+        AB: Clone + Fn(A, B) -> C,
+        A: Clone,
+        B: Clone
+{
+  fa.fmap(curry2::Applied0::curry(f))
+    .apply_with(fb, Clone::clone)
+}
+
+/// Lift a function of 3 arguments to a function
+/// of 3 [`Apply`]s
+///
+/// Shorthand for `fa.fmap(f.curry()).apply(fb).apply(fc)`.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// let parse = |s: &str| s.parse::<u8>().map_err(|e| e.to_string());
+/// let sum = |a: u8, b: u8, c: u8| a + b + c;
+///
+/// assert_eq!(lift_a3(sum, parse("1"), parse("2"), parse("3")), Ok(6));
+/// assert!(lift_a3(sum, parse("1"), parse("two"), parse("3")).is_err());
+/// ```
+pub fn lift_a3<F, ABC, A, B, C, D, FA>(f: ABC, fa: FA, fb: F::T<B>, fc: F::T<C>) -> F::T<D>
+  where F: HKT1<T<A> = FA>,
+        FA: Functor<F, A>,
+        F::T<curry3::Applied1<ABC, A, B, C, D>>: Apply<F, curry3::Applied1<ABC, A, B, C, D>>,
+        F::T<curry3::Applied2<ABC, A, B, C, D>>: Apply<F, curry3::Applied2<ABC, A, B, C, D>>,
+        ABC: Clone + Fn(A, B, C) -> D,
+        A: Clone,
+        B: Clone,
+        C: Clone
+{
+  fa.fmap(curry3::Applied0::curry(f))
+    .apply_with(fb, Clone::clone)
+    .apply_with(fc, Clone::clone)
+}
+
+macro_rules! lift_a {
+  (
+    $(#[$m:meta])*
+    $name:ident<$f:ident>($fa:ident: $a:ident $(, $fx:ident: $x:ident)+) -> $r:ident;
+    $curry:ident [$($applied:ident),+]
+  ) => {
+    lift_a!(@go [$(#[$m])* $name<$f>($fa: $a $(, $fx: $x)+) -> $r]
+                $curry [$f, $a, $($x,)+ $r] [$($applied),+] []);
+  };
+  (
+    @go [$($hdr:tt)*]
+        $curry:ident [$($ty:ident),+] [$applied:ident $(, $rest:ident)*]
+        [$($bounds:tt)*]
+  ) => {
+    lift_a!(@go [$($hdr)*]
+                $curry [$($ty),+] [$($rest),*]
+                [$($bounds)*
+                 F::T<$curry::$applied<$($ty),+>>: Apply<F, $curry::$applied<$($ty),+>>,]);
+  };
+  (
+    @go [$(#[$m:meta])* $name:ident<$f:ident>($fa:ident: $a:ident $(, $fx:ident: $x:ident)+) -> $r:ident]
+        $curry:ident [$($ty:ident),+] []
+        [$($bounds:tt)*]
+  ) => {
+    $(#[$m])*
+    #[allow(clippy::too_many_arguments)]
+    pub fn $name<F, $f, $a, $($x,)+ $r, FA>(f: $f, $fa: FA $(, $fx: F::T<$x>)+) -> F::T<$r>
+      where F: HKT1<T<$a> = FA>,
+            FA: Functor<F, $a>,
+            $($bounds)*
+            $f: Clone + Fn($a $(, $x)+) -> $r,
+            $a: Clone,
+            $($x: Clone),+
+    {
+      $fa.fmap($curry::Applied0::curry(f))
+         $(.apply_with($fx, Clone::clone))+
+    }
+  };
+}
+
+lift_a! {
+  /// Lift a function of 4 arguments to a function
+  /// of 4 [`Apply`]s
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// assert_eq!(lift_a4(|a: u8, b: u8, c: u8, d: u8| a + b + c + d,
+  ///                    Some(1),
+  ///                    Some(2),
+  ///                    Some(3),
+  ///                    Some(4)),
+  ///            Some(10));
+  /// ```
+  lift_a4<ABCD>(fa: A, fb: B, fc: C, fd: D) -> E;
+  curry4 [Applied1, Applied2, Applied3]
+}
+
+lift_a! {
+  /// Lift a function of 5 arguments to a function
+  /// of 5 [`Apply`]s
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let parse = |s: &str| s.parse::<u8>().map_err(|e| e.to_string());
+  /// let sum = |a: u8, b: u8, c: u8, d: u8, e: u8| a + b + c + d + e;
+  ///
+  /// assert_eq!(lift_a5(sum, parse("1"), parse("2"), parse("3"), parse("4"), parse("5")),
+  ///            Ok(15));
+  /// assert!(lift_a5(sum, parse("1"), parse("2"), parse("three"), parse("4"), parse("5")).is_err());
+  /// ```
+  lift_a5<ABCDE>(fa: A, fb: B, fc: C, fd: D, fe: E) -> G;
+  curry5 [Applied1, Applied2, Applied3, Applied4]
+}
+
+lift_a! {
+  /// Lift a function of 6 arguments to a function
+  /// of 6 [`Apply`]s
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let word = |a: char, b: char, c: char, d: char, e: char, f: char| {
+  ///   [a, b, c, d, e, f].iter().collect::<String>()
+  /// };
+  ///
+  /// assert_eq!(lift_a6(word,
+  ///                    vec!['a', 'b'],
+  ///                    vec!['x', 'y'],
+  ///                    vec!['1'],
+  ///                    vec!['2'],
+  ///                    vec!['3'],
+  ///                    vec!['4']),
+  ///            vec!["ax1234", "ay1234", "bx1234", "by1234"]);
+  /// ```
+  lift_a6<ABCDEG>(fa: A, fb: B, fc: C, fd: D, fe: E, fg: G) -> H;
+  curry6 [Applied1, Applied2, Applied3, Applied4, Applied5]
+}
+
+lift_a! {
+  /// Lift a function of 7 arguments to a function
+  /// of 7 [`Apply`]s
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let all = |a: bool, b: bool, c: bool, d: bool, e: bool, f: bool, g: bool| {
+  ///   a && b && c && d && e && f && g
+  /// };
+  ///
+  /// assert_eq!(lift_a7(all, Some(true), Some(true), Some(true), Some(true), Some(true), Some(true), Some(false)),
+  ///            Some(false));
+  /// assert_eq!(lift_a7(all, Some(true), None, Some(true), Some(true), Some(true), Some(true), Some(true)),
+  ///            None);
+  /// ```
+  lift_a7<ABCDEGH>(fa: A, fb: B, fc: C, fd: D, fe: E, fg: G, fh: H) -> I;
+  curry7 [Applied1, Applied2, Applied3, Applied4, Applied5, Applied6]
+}
+
+lift_a! {
+  /// Lift a function of 8 arguments to a function
+  /// of 8 [`Apply`]s
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let n = |s: &str| s.parse::<u32>().ok();
+  ///
+  /// assert_eq!(lift_a8(|a, b, c, d, e, f, g, h| a + b + c + d + e + f + g + h,
+  ///                    n("1"),
+  ///                    n("2"),
+  ///                    n("3"),
+  ///                    n("4"),
+  ///                    n("5"),
+  ///                    n("6"),
+  ///                    n("7"),
+  ///                    n("8")),
+  ///            Some(36));
+  /// ```
+  lift_a8<ABCDEGHI>(fa: A, fb: B, fc: C, fd: D, fe: E, fg: G, fh: H, fi: I) -> J;
+  curry8 [Applied1, Applied2, Applied3, Applied4, Applied5, Applied6, Applied7]
+}
+
+/// Applicative-do: combine independent [`Apply`]s
+///
+/// ```text
+/// ado! {
+///   a <- fa;
+///   b <- fb;
+///   f(a, b)
+/// }
+/// ```
+///
+/// desugars to `lift_a2(|a, b| f(a, b), fa, fb)` (and similarly for
+/// up to 8 bindings, see [`lift_a2`]..[`lift_a8`]).
+/// A single binding desugars to [`Functor::fmap`].
+///
+/// Unlike [`mdo!`](crate::mdo), a binding can't depend on the values
+/// bound before it, and the last expression is a plain value rather
+/// than another `F<_>`.
+///
+/// Bindings may be an identifier, `_` or a tuple pattern.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// let parse = |s: &str| s.parse::<u16>().map_err(|e| e.to_string());
+///
+/// let addr = ado! {
+///   host <- Ok::<_, String>("localhost");
+///   port <- parse("8080");
+///   format!("{host}:{port}")
+/// };
+/// assert_eq!(addr, Ok("localhost:8080".to_string()));
+///
+/// let pairs = ado! {
+///   n <- vec![1, 2];
+///   (c, _) <- vec![('a', ()), ('b', ())];
+///   (n, c)
+/// };
+/// assert_eq!(pairs, vec![(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]);
+///
+/// let port = ado! {
+///   port <- parse("8080");
+///   port + 1
+/// };
+/// assert_eq!(port, Ok(8081));
+/// ```
+///
+/// Combining more than 8 bindings is rejected:
+///
+/// ```compile_fail
+/// use naan::prelude::*;
+///
+/// let n = ado! {
+///   a <- Some(1); b <- Some(1); c <- Some(1); d <- Some(1); e <- Some(1);
+///   f <- Some(1); g <- Some(1); h <- Some(1); i <- Some(1);
+///   a + b + c + d + e + f + g + h + i
+/// };
+/// ```
+///
+/// `ado!(io { .. })` combines [`IOLike`]s.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// let sum = ado!(io {
+///   a <- IO::pure(1);
+///   b <- IO::suspend(|()| 2);
+///   a + b
+/// });
+///
+/// assert_eq!(sum.exec(), 3);
+/// ```
+#[macro_export]
+macro_rules! ado {
+  (@acc [$($p:tt)*] [$($e:expr),*] _ <- $fx:expr; $($rest:tt)+) => {
+    $crate::ado!(@acc [$($p)* _] [$($e,)* $fx] $($rest)+)
+  };
+  (@acc [$($p:tt)*] [$($e:expr),*] $x:ident <- $fx:expr; $($rest:tt)+) => {
+    $crate::ado!(@acc [$($p)* $x] [$($e,)* $fx] $($rest)+)
+  };
+  (@acc [$($p:tt)*] [$($e:expr),*] ($($x:tt)*) <- $fx:expr; $($rest:tt)+) => {
+    $crate::ado!(@acc [$($p)* ($($x)*)] [$($e,)* $fx] $($rest)+)
+  };
+  (@acc [$a:tt] $($t:tt)+) => {
+    $crate::ado!(@call fmap [$a] $($t)+)
+  };
+  (@acc [$a:tt $b:tt] $($t:tt)+) => {
+    $crate::ado!(@call lift_a2 [$a $b] $($t)+)
+  };
+  (@acc [$a:tt $b:tt $c:tt] $($t:tt)+) => {
+    $crate::ado!(@call lift_a3 [$a $b $c] $($t)+)
+  };
+  (@acc [$a:tt $b:tt $c:tt $d:tt] $($t:tt)+) => {
+    $crate::ado!(@call lift_a4 [$a $b $c $d] $($t)+)
+  };
+  (@acc [$a:tt $b:tt $c:tt $d:tt $e:tt] $($t:tt)+) => {
+    $crate::ado!(@call lift_a5 [$a $b $c $d $e] $($t)+)
+  };
+  (@acc [$a:tt $b:tt $c:tt $d:tt $e:tt $f:tt] $($t:tt)+) => {
+    $crate::ado!(@call lift_a6 [$a $b $c $d $e $f] $($t)+)
+  };
+  (@acc [$a:tt $b:tt $c:tt $d:tt $e:tt $f:tt $g:tt] $($t:tt)+) => {
+    $crate::ado!(@call lift_a7 [$a $b $c $d $e $f $g] $($t)+)
+  };
+  (@acc [$a:tt $b:tt $c:tt $d:tt $e:tt $f:tt $g:tt $h:tt] $($t:tt)+) => {
+    $crate::ado!(@call lift_a8 [$a $b $c $d $e $f $g $h] $($t)+)
+  };
+  (@acc $($t:tt)*) => {
+    ::core::compile_error!("expected 1 to 8 `pat <- expr;` bindings in ado!, \
+                            followed by an expression")
+  };
+  (@call $lift:ident [$($p:tt)+] [$($e:expr),+] $body:expr) => {
+    $crate::lift::$lift(move |$($p),+| $body, $($e),+)
+  };
+  (@io [$($stmts:tt)*] _ <- $fx:expr; $($rest:tt)+) => {
+    $crate::ado!(@io [$($stmts)* _ <- $fx;] $($rest)+)
+  };
+  (@io [$($stmts:tt)*] $x:ident <- $fx:expr; $($rest:tt)+) => {
+    $crate::ado!(@io [$($stmts)* $x <- $fx;] $($rest)+)
+  };
+  (@io [$($stmts:tt)*] ($($x:tt)*) <- $fx:expr; $($rest:tt)+) => {
+    $crate::ado!(@io [$($stmts)* ($($x)*) <- $fx;] $($rest)+)
+  };
+  (@io [$($stmts:tt)*] $body:expr) => {
+    $crate::mdo!(io { $($stmts)* $crate::io::IO::pure($body) })
+  };
+  (io { $($body:tt)+ }) => {
+    $crate::ado!(@io [] $($body)+)
+  };
+  ($($body:tt)+) => {
+    $crate::ado!(@acc [] [] $($body)+)
+  };
+}
@@ -1,3 +1,5 @@
+use core::ops::ControlFlow;
+
 use crate::prelude::*;
 
 /// [`Monad`], but specialized to know at compile-time
@@ -111,6 +113,59 @@ pub trait Monad<M, A>
   }
 }
 
+/// [`Monad`]s that can run a recursive loop of [`bind`](Monad::bind)s
+/// in constant stack space.
+///
+/// `f` is called with the seed `S`, and then repeatedly with
+/// whatever `S` it yields in [`ControlFlow::Continue`] until it yields
+/// [`ControlFlow::Break`] (or the monad short-circuits, e.g. with `None` or `Err`).
+///
+/// ```
+/// use core::cell::Cell;
+/// use core::ops::ControlFlow;
+///
+/// use naan::prelude::*;
+///
+/// let attempts = Cell::new(0);
+/// let flaky = || {
+///   attempts.set(attempts.get() + 1);
+///   if attempts.get() < 3 {
+///     Err("timed out")
+///   } else {
+///     Ok("pong")
+///   }
+/// };
+///
+/// // retry `flaky` up to 5 times, failing only if every attempt does
+/// let ping = Result::<&str, &str>::tail_rec_m(1, |n: u32| match flaky() {
+///   | Ok(s) => Ok(ControlFlow::Break(s)),
+///   | Err(_) if n < 5 => Ok(ControlFlow::Continue(n + 1)),
+///   | Err(e) => Err(e),
+/// });
+///
+/// assert_eq!(ping, Ok("pong"));
+/// assert_eq!(attempts.get(), 3);
+///
+/// // a million iterations, no stack overflow
+/// let sum = Option::tail_rec_m((0u64, 0u64), |(n, sum): (u64, u64)| {
+///   Some(if n == 1_000_000 {
+///          ControlFlow::Break(sum)
+///        } else {
+///          ControlFlow::Continue((n + 1, sum + n))
+///        })
+/// });
+///
+/// assert_eq!(sum, Some(499_999_500_000));
+/// ```
+pub trait MonadRec<M, A>
+  where Self: Monad<M, A>,
+        M: HKT1<T<A> = Self>
+{
+  /// See [`MonadRec`]
+  fn tail_rec_m<S, F>(seed: S, f: F) -> M::T<A>
+    where F: F1<S, Ret = M::T<ControlFlow<A, S>>>;
+}
+
 /// [`Monad`] but with looser type constraints,
 /// allowing for blanket [`Monad`] implementations
 /// on types [`Equiv`]alent to `M<A>`
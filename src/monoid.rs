@@ -0,0 +1,367 @@
+use core::ops::{Add, Mul};
+
+#[cfg(feature = "alloc")]
+use std_alloc::boxed::Box;
+
+use crate::prelude::*;
+
+/// [`Monoid`] that adds numbers
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// assert_eq!(vec![1, 2, 3].fold_map(Sum), Sum(6));
+/// assert_eq!(Vec::<u8>::new().fold_map(Sum), Sum(0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Sum<T>(pub T);
+
+impl<T> Semigroup for Sum<T> where T: Add<Output = T>
+{
+  fn append(self, b: Self) -> Self {
+    Sum(self.0 + b.0)
+  }
+}
+
+/// [`Monoid`] that multiplies numbers
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// assert_eq!(vec![2, 3, 4].fold_map(Product), Product(24));
+/// assert_eq!(Vec::<u8>::new().fold_map(Product), Product(1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Product<T>(pub T);
+
+impl<T> Semigroup for Product<T> where T: Mul<Output = T>
+{
+  fn append(self, b: Self) -> Self {
+    Product(self.0 * b.0)
+  }
+}
+
+/// [`Semigroup`] that keeps the smallest value
+///
+/// This is a [`Monoid`] for primitives, whose identity is their
+/// largest value. For any other [`Ord`] type, use `Option<Min<T>>`
+/// which is a [`Monoid`] whose identity is `None`.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// assert_eq!(vec![3u8, 1, 2].fold_map(Min), Min(1));
+/// assert_eq!(Vec::<u8>::new().fold_map(Min), Min(u8::MAX));
+///
+/// let shortest = vec!["abc", "a", "ab"].fold_map(|s: &str| Some(Min(s.len())));
+/// assert_eq!(shortest, Some(Min(1)));
+///
+/// let first = vec!["b", "c", "a"].fold_map(|s: &'static str| Some(Min(s)));
+/// assert_eq!(first, Some(Min("a")));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Min<T>(pub T);
+
+impl<T> Semigroup for Min<T> where T: PartialOrd
+{
+  fn append(self, b: Self) -> Self {
+    if b.0 < self.0 {
+      b
+    } else {
+      self
+    }
+  }
+}
+
+/// [`Semigroup`] that keeps the largest value
+///
+/// This is a [`Monoid`] for primitives, whose identity is their
+/// smallest value. For any other [`Ord`] type, use `Option<Max<T>>`
+/// which is a [`Monoid`] whose identity is `None`.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// assert_eq!(vec![1u8, 3, 2].fold_map(Max), Max(3));
+/// assert_eq!(Vec::<u8>::new().fold_map(Max), Max(u8::MIN));
+///
+/// let longest = vec!["abc", "a", "ab"].fold_map(|s: &str| Max(s.len()));
+/// assert_eq!(longest, Max(3));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Max<T>(pub T);
+
+impl<T> Semigroup for Max<T> where T: PartialOrd
+{
+  fn append(self, b: Self) -> Self {
+    if b.0 > self.0 {
+      b
+    } else {
+      self
+    }
+  }
+}
+
+macro_rules! bounded {
+  ($($t:ty: $min:expr, $max:expr);+ $(;)?) => {
+    $(
+      impl Monoid for Min<$t> {
+        fn identity() -> Self {
+          Min($max)
+        }
+      }
+
+      impl Monoid for Max<$t> {
+        fn identity() -> Self {
+          Max($min)
+        }
+      }
+    )+
+  };
+}
+
+macro_rules! numeric {
+  ($($t:ty: $zero:expr, $one:expr);+ $(;)?) => {
+    $(
+      impl Monoid for Sum<$t> {
+        fn identity() -> Self {
+          Sum($zero)
+        }
+      }
+
+      impl Monoid for Product<$t> {
+        fn identity() -> Self {
+          Product($one)
+        }
+      }
+
+      bounded!($t: <$t>::MIN, <$t>::MAX);
+    )+
+  };
+}
+
+numeric! {
+  u8: 0, 1;
+  u16: 0, 1;
+  u32: 0, 1;
+  u64: 0, 1;
+  u128: 0, 1;
+  usize: 0, 1;
+  i8: 0, 1;
+  i16: 0, 1;
+  i32: 0, 1;
+  i64: 0, 1;
+  i128: 0, 1;
+  isize: 0, 1;
+}
+
+impl Monoid for Sum<f32> {
+  fn identity() -> Self {
+    Sum(0.0)
+  }
+}
+
+impl Monoid for Product<f32> {
+  fn identity() -> Self {
+    Product(1.0)
+  }
+}
+
+impl Monoid for Sum<f64> {
+  fn identity() -> Self {
+    Sum(0.0)
+  }
+}
+
+impl Monoid for Product<f64> {
+  fn identity() -> Self {
+    Product(1.0)
+  }
+}
+
+bounded! {
+  f32: f32::NEG_INFINITY, f32::INFINITY;
+  f64: f64::NEG_INFINITY, f64::INFINITY;
+  char: '\0', char::MAX;
+  bool: false, true;
+}
+
+/// [`Semigroup`] that keeps the first value
+///
+/// Use `Option<First<T>>` for a [`Monoid`] whose identity is `None`.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// assert_eq!(First("a").append(First("b")), First("a"));
+/// assert_eq!(vec!["a", "b"].fold_map(|s| Some(First(s))),
+///            Some(First("a")));
+/// assert_eq!(Vec::<&str>::new().fold_map(|s| Some(First(s))), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct First<T>(pub T);
+
+impl<T> Semigroup for First<T> {
+  fn append(self, _: Self) -> Self {
+    self
+  }
+}
+
+/// [`Semigroup`] that keeps the last value
+///
+/// Use `Option<Last<T>>` for a [`Monoid`] whose identity is `None`.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// assert_eq!(Last("a").append(Last("b")), Last("b"));
+/// assert_eq!(vec!["a", "b"].fold_map(|s| Some(Last(s))), Some(Last("b")));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Last<T>(pub T);
+
+impl<T> Semigroup for Last<T> {
+  fn append(self, b: Self) -> Self {
+    b
+  }
+}
+
+/// [`Monoid`] that is `true` if any value is `true`
+///
+/// Also wraps a [`Predicate`](crate::impls::predicate::Predicate)
+/// to combine predicates with `or`.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// assert_eq!(vec![1, 2, 3].fold_map(|n| Any(n > 2)), Any(true));
+/// assert_eq!(Vec::<u8>::new().fold_map(|n| Any(n > 2)), Any(false));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Any<T = bool>(pub T);
+
+impl Semigroup for Any {
+  fn append(self, b: Self) -> Self {
+    Any(self.0 || b.0)
+  }
+}
+
+impl Monoid for Any {
+  fn identity() -> Self {
+    Any(false)
+  }
+}
+
+/// [`Monoid`] that is `true` if every value is `true`
+///
+/// Also wraps a [`Predicate`](crate::impls::predicate::Predicate)
+/// to combine predicates with `and`.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// assert_eq!(vec![1, 2, 3].fold_map(|n| All(n > 2)), All(false));
+/// assert_eq!(Vec::<u8>::new().fold_map(|n| All(n > 2)), All(true));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct All<T = bool>(pub T);
+
+impl Semigroup for All {
+  fn append(self, b: Self) -> Self {
+    All(self.0 && b.0)
+  }
+}
+
+impl Monoid for All {
+  fn identity() -> Self {
+    All(true)
+  }
+}
+
+/// [`Semigroup`] / [`Monoid`] that appends in the opposite order
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// assert_eq!(Dual(vec![1]).append(Dual(vec![2])), Dual(vec![2, 1]));
+/// assert_eq!(vec!["a", "b", "c"].fold_map(|s: &str| Dual(s.to_string())),
+///            Dual("cba".to_string()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Dual<T>(pub T);
+
+impl<T> Semigroup for Dual<T> where T: Semigroup
+{
+  fn append(self, b: Self) -> Self {
+    Dual(b.0.append(self.0))
+  }
+}
+
+impl<T> Monoid for Dual<T> where T: Monoid
+{
+  fn identity() -> Self {
+    Dual(T::identity())
+  }
+}
+
+/// [`Monoid`] of functions from `A` to `A`, appended by composition
+///
+/// `f.append(g)` is a function that calls `g`, then `f`
+/// (like `f . g` in Haskell). The identity is a function that
+/// returns its input.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// let ops = vec![1, 2, 3].fold_map(|n| Endo::new(move |acc: Vec<u8>| acc.append(vec![n])));
+///
+/// // the last function appended runs first
+/// assert_eq!(ops.call1(vec![]), vec![3, 2, 1]);
+///
+/// let ops = vec![1, 2, 3].fold_map(|n| Dual(Endo::new(move |acc: Vec<u8>| acc.append(vec![n]))));
+/// assert_eq!(ops.0.call1(vec![]), vec![1, 2, 3]);
+/// ```
+#[cfg(feature = "alloc")]
+pub struct Endo<'a, A>(pub Box<dyn FnOnce(A) -> A + 'a>);
+
+#[cfg(feature = "alloc")]
+impl<'a, A> Endo<'a, A> {
+  /// Create an `Endo` from a function
+  pub fn new<F>(f: F) -> Self
+    where F: F1Once<A, Ret = A> + 'a
+  {
+    Endo(Box::new(move |a| f.call1(a)))
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, A> F1Once<A> for Endo<'a, A> {
+  type Ret = A;
+
+  fn call1(self, a: A) -> A {
+    (self.0)(a)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, A> Semigroup for Endo<'a, A> where A: 'a
+{
+  fn append(self, b: Self) -> Self {
+    Endo(Box::new(move |a| (self.0)((b.0)(a))))
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, A> Monoid for Endo<'a, A> where A: 'a
+{
+  fn identity() -> Self {
+    Endo(Box::new(|a| a))
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, A> core::fmt::Debug for Endo<'a, A> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    f.write_str("Endo(..)")
+  }
+}
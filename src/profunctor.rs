@@ -0,0 +1,45 @@
+use crate::prelude::*;
+
+/// A Profunctor is contravariant in its input and covariant
+/// in its output, which any function from `A -> B` is.
+///
+/// This is implemented for every [`F1`], and composes
+/// with [`Compose`] rather than boxing.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// let len = |s: String| s.len();
+/// let describe = len.dimap(|n: u32| n.to_string(), |len: usize| format!("{len} digits"));
+///
+/// assert_eq!(describe.call(1234), "4 digits");
+/// assert_eq!(len.lmap(|s: &str| s.to_string()).call("abc"), 3);
+/// assert_eq!(len.rmap(|len: usize| len * 2).call("abc".to_string()), 6);
+/// ```
+pub trait Profunctor<A, B>
+  where Self: Sized + F1<A, Ret = B>
+{
+  /// Transform the input with `f` and the output with `g`
+  fn dimap<A0, C, FA, GB>(self, f: FA, g: GB) -> Compose<Compose<FA, Self, A>, GB, B>
+    where FA: F1<A0, Ret = A>,
+          GB: F1<B, Ret = C>
+  {
+    Compose::compose(Compose::compose(f, self), g)
+  }
+
+  /// Transform the input with `f`
+  fn lmap<A0, FA>(self, f: FA) -> Compose<FA, Self, A>
+    where FA: F1<A0, Ret = A>
+  {
+    Compose::compose(f, self)
+  }
+
+  /// Transform the output with `g`
+  fn rmap<C, GB>(self, g: GB) -> Compose<Self, GB, B>
+    where GB: F1<B, Ret = C>
+  {
+    Compose::compose(self, g)
+  }
+}
+
+impl<F, A, B> Profunctor<A, B> for F where F: F1<A, Ret = B> {}
@@ -0,0 +1,34 @@
+use core::marker::PhantomData;
+
+use super::{Reader, ReaderLike};
+use crate::prelude::*;
+
+/// A [`ReaderLike`] that, when run, will apply the function yielded by
+/// `RAB` to the value yielded by `RA`, passing the environment to both.
+///
+/// This is the result of calling [`ApplySurrogate.apply_`] on a [`ReaderLike`].
+#[must_use = "Reader is not evaluated until `ReaderLike.run` invoked"]
+pub struct Apply<A, B, AB, RA, RAB>(RAB, RA, PhantomData<(A, B, AB)>);
+
+impl<A, B, AB, RA, RAB> Apply<A, B, AB, RA, RAB> {
+  /// Create a new Apply
+  pub fn new(rab: RAB, ra: RA) -> Self {
+    Self(rab, ra, PhantomData)
+  }
+}
+
+impl<R, A, B, AB, RA, RAB> Equiv for Apply<A, B, AB, RA, RAB> where RAB: Equiv<To = Reader<R, AB>>
+{
+  type To = Reader<R, B>;
+}
+
+impl<R, A, B, AB, RA, RAB> ReaderLike<R, B> for Apply<A, B, AB, RA, RAB>
+  where AB: F1Once<A, Ret = B>,
+        RA: ReaderLike<R, A>,
+        RAB: ReaderLike<R, AB>,
+        R: Clone
+{
+  fn run(self, r: R) -> B {
+    self.0.run(r.clone()).call1(self.1.run(r))
+  }
+}
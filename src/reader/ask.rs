@@ -0,0 +1,61 @@
+use core::marker::PhantomData;
+
+use super::{Reader, ReaderLike};
+use crate::prelude::*;
+
+/// Yields the environment
+///
+/// See [`Reader::ask`]
+#[derive(Debug, Clone, Copy)]
+#[must_use = "Reader is not evaluated until `ReaderLike.run` invoked"]
+pub struct Ask<R>(PhantomData<R>);
+
+impl<R> Ask<R> {
+  /// See [`Reader::ask`]
+  pub fn new() -> Self {
+    Self(PhantomData)
+  }
+}
+
+impl<R> Default for Ask<R> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<R> Equiv for Ask<R> {
+  type To = Reader<R, R>;
+}
+
+impl<R> ReaderLike<R, R> for Ask<R> {
+  fn run(self, r: R) -> R {
+    r
+  }
+}
+
+/// A deferred function from the environment to some value
+///
+/// See [`Reader::asks`]
+#[derive(Debug, Clone, Copy)]
+#[must_use = "Reader is not evaluated until `ReaderLike.run` invoked"]
+pub struct Asks<R, F>(F, PhantomData<R>);
+
+impl<R, F> Asks<R, F> {
+  /// See [`Reader::asks`]
+  pub fn new(f: F) -> Self {
+    Self(f, PhantomData)
+  }
+}
+
+impl<R, F> Equiv for Asks<R, F> where F: F1Once<R>
+{
+  /// `Asks<R, F>` is conceptually equivalent to `Reader<R, {return type of F}>`
+  type To = Reader<R, F::Ret>;
+}
+
+impl<R, F> ReaderLike<R, F::Ret> for Asks<R, F> where F: F1Once<R>
+{
+  fn run(self, r: R) -> F::Ret {
+    self.0.call1(r)
+  }
+}
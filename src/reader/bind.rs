@@ -0,0 +1,53 @@
+use core::marker::PhantomData;
+
+use super::{Reader, ReaderLike};
+use crate::prelude::*;
+
+/// A function from `A` to a `ReaderLike<R, B>` waiting to be applied to
+/// the value yielded by a [computation that reads an environment](ReaderLike).
+///
+/// This is the result of calling [`MonadSurrogate.bind_`] or
+/// [`ReaderLike.and_then_`] on a [`ReaderLike`].
+#[must_use = "Reader is not evaluated until `ReaderLike.run` invoked"]
+pub struct Bind<F, A, B, RA>(F, RA, PhantomData<(A, B)>);
+
+impl<F, A, B, RA> Bind<F, A, B, RA> {
+  /// Create a new Bind
+  pub fn new(f: F, ra: RA) -> Self {
+    Self(f, ra, PhantomData)
+  }
+}
+
+impl<F, A, B, RA> Clone for Bind<F, A, B, RA>
+  where F: Clone,
+        RA: Clone
+{
+  fn clone(&self) -> Self {
+    Self(self.0.clone(), self.1.clone(), PhantomData)
+  }
+}
+
+impl<F, A, B, RA> Copy for Bind<F, A, B, RA>
+  where F: Copy,
+        RA: Copy
+{
+}
+
+impl<F, R, A, B, RA> Equiv for Bind<F, A, B, RA>
+  where F: F1Once<A>,
+        F::Ret: Equiv<To = Reader<R, B>>,
+        RA: Equiv<To = Reader<R, A>>
+{
+  type To = Reader<R, B>;
+}
+
+impl<F, R, A, B, RA> ReaderLike<R, B> for Bind<F, A, B, RA>
+  where F: F1Once<A>,
+        F::Ret: ReaderLike<R, B>,
+        RA: ReaderLike<R, A>,
+        R: Clone
+{
+  fn run(self, r: R) -> B {
+    self.0.call1(self.1.run(r.clone())).run(r)
+  }
+}
@@ -0,0 +1,32 @@
+use super::{Reader, ReaderLike};
+use crate::prelude::*;
+
+/// A [`ReaderLike`] that runs in an environment modified by `F`
+///
+/// This is the result of calling [`ReaderLike.local`].
+#[derive(Debug, Clone, Copy)]
+#[must_use = "Reader is not evaluated until `ReaderLike.run` invoked"]
+pub struct Local<F, RA>(F, RA);
+
+impl<F, RA> Local<F, RA> {
+  /// Create a new Local
+  pub fn new(f: F, ra: RA) -> Self {
+    Self(f, ra)
+  }
+}
+
+impl<F, R, A, RA> Equiv for Local<F, RA>
+  where F: F1Once<R, Ret = R>,
+        RA: Equiv<To = Reader<R, A>>
+{
+  type To = Reader<R, A>;
+}
+
+impl<F, R, A, RA> ReaderLike<R, A> for Local<F, RA>
+  where F: F1Once<R, Ret = R>,
+        RA: ReaderLike<R, A>
+{
+  fn run(self, r: R) -> A {
+    self.1.run(self.0.call1(r))
+  }
+}
@@ -0,0 +1,49 @@
+use core::marker::PhantomData;
+
+use super::{Reader, ReaderLike};
+use crate::prelude::*;
+
+/// A function from `X` to some `A` waiting to be applied to
+/// the value yielded by a [computation that reads an environment](ReaderLike).
+///
+/// This is the result of calling [`FunctorSurrogate.map_`] on a [`ReaderLike`].
+#[must_use = "Reader is not evaluated until `ReaderLike.run` invoked"]
+pub struct Map<F, X, A, RX>(F, RX, PhantomData<(X, A)>);
+
+impl<F, X, A, RX> Map<F, X, A, RX> {
+  /// Create a new Map
+  pub fn new(f: F, rx: RX) -> Self {
+    Self(f, rx, PhantomData)
+  }
+}
+
+impl<F, X, A, RX> Clone for Map<F, X, A, RX>
+  where F: Clone,
+        RX: Clone
+{
+  fn clone(&self) -> Self {
+    Self(self.0.clone(), self.1.clone(), PhantomData)
+  }
+}
+
+impl<F, X, A, RX> Copy for Map<F, X, A, RX>
+  where F: Copy,
+        RX: Copy
+{
+}
+
+impl<F, R, X, A, RX> Equiv for Map<F, X, A, RX>
+  where F: F1Once<X, Ret = A>,
+        RX: Equiv<To = Reader<R, X>>
+{
+  type To = Reader<R, A>;
+}
+
+impl<F, R, X, A, RX> ReaderLike<R, A> for Map<F, X, A, RX>
+  where F: F1Once<X, Ret = A>,
+        RX: ReaderLike<R, X>
+{
+  fn run(self, r: R) -> A {
+    self.0.call1(self.1.run(r))
+  }
+}
@@ -0,0 +1,259 @@
+use core::marker::PhantomData;
+
+use crate::prelude::*;
+
+/// `Reader.map_`
+pub mod map;
+
+/// `Reader.bind_`
+pub mod bind;
+
+/// `Reader.apply_`
+pub mod apply;
+
+/// `Reader::ask`, `Reader::asks`
+pub mod ask;
+
+/// `Reader.local`
+pub mod local;
+
+/// `Reader::tail_rec_m`
+pub mod tail_rec;
+
+pub use ask::{Ask, Asks};
+pub use bind::Bind;
+pub use local::Local;
+pub use map::Map;
+pub use tail_rec::TailRecM;
+
+/// Reader HKT
+pub mod hkt {
+  use core::marker::PhantomData;
+
+  use crate::prelude::*;
+
+  /// `Reader<R, A>` with the environment type pinned to some `R`.
+  ///
+  /// (Kind `Type -> Type`)
+  pub struct Reader<R>(PhantomData<R>);
+
+  impl<R> HKT1 for Reader<R> {
+    type T<A> = super::Reader<R, A>;
+  }
+}
+
+/// A lazy computation that reads from an environment `R`
+/// to produce a value `A`
+pub trait ReaderLike<R, A>
+  where Self: Sized + Equiv<To = Reader<R, A>>
+{
+  /// Run this computation with an environment
+  fn run(self, r: R) -> A;
+
+  /// Run this computation in an environment transformed by `f`
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let depth = Reader::asks(|depth: usize| depth);
+  ///
+  /// assert_eq!(depth.local(|d| d + 1).run(0), 1);
+  /// ```
+  fn local<F>(self, f: F) -> Local<F, Self>
+    where F: F1Once<R, Ret = R>
+  {
+    Local::new(f, self)
+  }
+
+  /// [`MonadSurrogate::bind_`], but the function may return
+  /// any [`ReaderLike`] (e.g. [`Asks`], another [`Bind`])
+  /// rather than only [`Reader`].
+  ///
+  /// Requires `R: Clone`, since the environment is passed to both computations.
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let greet = Reader::asks(|name: &str| name.len())
+  ///               .and_then_(|len| Reader::asks(move |name: &str| format!("{name} ({len})")));
+  ///
+  /// assert_eq!(greet.run("naan"), "naan (4)");
+  /// ```
+  fn and_then_<B, AMB>(self, f: AMB) -> Bind<AMB, A, B, Self>
+    where AMB: F1Once<A>,
+          AMB::Ret: ReaderLike<R, B>
+  {
+    Bind::new(f, self)
+  }
+}
+
+impl<I, R, A> FunctorSurrogate<hkt::Reader<R>, A> for I where I: ReaderLike<R, A>
+{
+  type Output<AB, B> = Map<AB, A, B, I>;
+
+  fn map_<AB, B>(self, f: AB) -> Map<AB, A, B, I>
+    where AB: F1<A, Ret = B>
+  {
+    Map::new(f, self)
+  }
+}
+
+impl<I, R, AB, TofA> ApplySurrogate<hkt::Reader<R>, AB, TofA> for I where I: ReaderLike<R, AB>
+{
+  type ApplyOutput<A, B> = apply::Apply<A, B, AB, TofA, Self>;
+
+  fn apply_<A, B>(self, a: TofA) -> apply::Apply<A, B, AB, TofA, Self>
+    where AB: F1Once<A, Ret = B>
+  {
+    apply::Apply::new(self, a)
+  }
+}
+
+impl<I, R, A> ApplicativeSurrogate<hkt::Reader<R>, A> for I where I: ReaderLike<R, A>
+{
+  fn pure(a: A) -> Reader<R, A> {
+    Reader::pure(a)
+  }
+}
+
+impl<I, R, A> MonadSurrogate<hkt::Reader<R>, A> for I where I: ReaderLike<R, A>
+{
+  type BindOutput<B, AMB> = Bind<AMB, A, B, Self>;
+
+  fn bind_<B, AMB>(self, f: AMB) -> Self::BindOutput<B, AMB>
+    where AMB: F1<A, Ret = Reader<R, B>>
+  {
+    Bind::new(f, self)
+  }
+}
+
+/// Lazy computation that depends on some environment
+///
+/// The Reader monad allows you to declare the ways a computation
+/// depends on some shared environment (e.g. configuration, or a struct
+/// of dependencies) without passing it as an argument through each layer.
+///
+/// Like [`IO`] and [`State`], a Reader computation is a stack of structs
+/// that does nothing until it is [`run`](ReaderLike::run) with an environment,
+/// so no heap allocations or dynamic dispatch are needed.
+///
+/// `Reader<R, A>` itself is the computation that ignores the environment
+/// and yields `A`. Other primitives are:
+///  - [`Reader::ask`] - yield the environment
+///  - [`Reader::asks`] - any function from `R` to `A`
+///  - [`ReaderLike::local`] - run a computation in a modified environment
+///
+/// Environments must be [`Clone`] in order to be shared by
+/// [`bind_`](MonadSurrogate)ed or [`apply_`](ApplySurrogate)ed computations,
+/// so it is typical for `R` to be a reference like `&Config`.
+///
+/// For the same reason as [`State`], `Reader` can't implement [`Functor`] or
+/// [`Monad`]; use the surrogates, [`Reader::tail_rec_m`], or
+/// [`mdo!`](crate::mdo)`(reader { .. })`.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// struct Config {
+///   host: String,
+///   port: u16,
+/// }
+///
+/// fn host<'a>() -> impl ReaderLike<&'a Config, String> {
+///   Reader::asks(|c: &Config| c.host.clone())
+/// }
+///
+/// fn port<'a>() -> impl ReaderLike<&'a Config, u16> {
+///   Reader::asks(|c: &Config| c.port)
+/// }
+///
+/// fn addr<'a>() -> impl ReaderLike<&'a Config, String> {
+///   host().map_((|h, p| format!("{h}:{p}")).curry())
+///         .apply_(port())
+/// }
+///
+/// let config = Config { host: "localhost".into(),
+///                       port: 8080 };
+///
+/// assert_eq!(addr().run(&config), "localhost:8080");
+/// ```
+pub struct Reader<R, A>(A, PhantomData<R>);
+
+impl<R, A> Reader<R, A> {
+  /// Lift a value to `Reader`, ignoring the environment.
+  pub fn pure(a: A) -> Self {
+    Self(a, PhantomData)
+  }
+
+  /// Store a lazy function from the environment to a value
+  ///
+  /// Any [`F1Once`] is accepted, including composed and curried functions.
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// fn scale(factor: usize, n: usize) -> usize {
+  ///   factor * n
+  /// }
+  ///
+  /// let len = Reader::asks(str::len);
+  /// let double_len = Reader::asks(str::len.chain(scale.curry().call(2)));
+  ///
+  /// assert_eq!(len.run("abc"), 3);
+  /// assert_eq!(double_len.run("abc"), 6);
+  /// ```
+  pub fn asks<F>(f: F) -> Asks<R, F>
+    where F: F1Once<R, Ret = A>
+  {
+    Asks::new(f)
+  }
+
+  /// Run a computation repeatedly in the same environment, starting with
+  /// `seed`, until it yields [`ControlFlow::Break`](core::ops::ControlFlow::Break).
+  ///
+  /// This is the Reader counterpart of [`MonadRec::tail_rec_m`], and runs
+  /// in constant stack space no matter how many times `f` is called.
+  /// The environment is cloned for each iteration.
+  ///
+  /// ```
+  /// use core::ops::ControlFlow;
+  ///
+  /// use naan::prelude::*;
+  ///
+  /// // how many steps of `step` does it take to reach `target`?
+  /// let steps = Reader::tail_rec_m((0u64, 0u64), |(n, count): (u64, u64)| {
+  ///   Reader::asks(move |(step, target): (u64, u64)| {
+  ///     if n >= target {
+  ///       ControlFlow::Break(count)
+  ///     } else {
+  ///       ControlFlow::Continue((n + step, count + 1))
+  ///     }
+  ///   })
+  /// });
+  ///
+  /// assert_eq!(steps.run((3, 3_000_000)), 1_000_000);
+  /// ```
+  pub fn tail_rec_m<X, F>(seed: X, f: F) -> TailRecM<X, F, A>
+    where F: F1<X>,
+          F::Ret: ReaderLike<R, core::ops::ControlFlow<A, X>>
+  {
+    TailRecM::new(seed, f)
+  }
+}
+
+impl<R> Reader<R, R> {
+  /// Yield the environment
+  pub fn ask() -> Ask<R> {
+    Ask::new()
+  }
+}
+
+impl<R, A> Equiv for Reader<R, A> {
+  type To = Reader<R, A>;
+}
+
+impl<R, A> ReaderLike<R, A> for Reader<R, A> {
+  fn run(self, _: R) -> A {
+    self.0
+  }
+}
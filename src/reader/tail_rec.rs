@@ -0,0 +1,57 @@
+use core::marker::PhantomData;
+use core::ops::ControlFlow;
+
+use super::{Reader, ReaderLike};
+use crate::prelude::*;
+
+/// A loop of computations reading the same environment,
+/// run in constant stack space
+///
+/// This is the result of calling [`Reader::tail_rec_m`].
+#[must_use = "Reader is not evaluated until `ReaderLike.run` invoked"]
+pub struct TailRecM<X, F, A>(X, F, PhantomData<A>);
+
+impl<X, F, A> TailRecM<X, F, A> {
+  /// Create a new TailRecM
+  pub fn new(seed: X, f: F) -> Self {
+    Self(seed, f, PhantomData)
+  }
+}
+
+impl<X, F, A> Clone for TailRecM<X, F, A>
+  where X: Clone,
+        F: Clone
+{
+  fn clone(&self) -> Self {
+    Self(self.0.clone(), self.1.clone(), PhantomData)
+  }
+}
+
+impl<X, F, A> Copy for TailRecM<X, F, A>
+  where X: Copy,
+        F: Copy
+{
+}
+
+impl<R, X, F, A> Equiv for TailRecM<X, F, A>
+  where F: F1<X>,
+        F::Ret: Equiv<To = Reader<R, ControlFlow<A, X>>>
+{
+  type To = Reader<R, A>;
+}
+
+impl<R, X, F, A> ReaderLike<R, A> for TailRecM<X, F, A>
+  where F: F1<X>,
+        F::Ret: ReaderLike<R, ControlFlow<A, X>>,
+        R: Clone
+{
+  fn run(self, r: R) -> A {
+    let Self(mut x, f, _) = self;
+    loop {
+      match f.call(x).run(r.clone()) {
+        | ControlFlow::Break(a) => return a,
+        | ControlFlow::Continue(next) => x = next,
+      }
+    }
+  }
+}
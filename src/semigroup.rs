@@ -4,6 +4,7 @@
 /// # Examples
 /// - `String`'s semigroup implementation concatenates the two strings.
 /// - `Vec`'s semigroup implementation concatenates the two Vecs.
+/// - Tuples (up to 12 elements) append each of their elements.
 ///
 /// # Laws
 /// Implementations of `Semigroup` must be associative, e.g.
@@ -16,6 +17,10 @@
 /// let c = || vec![5u8, 6];
 ///
 /// assert_eq!(a().append(b().append(c())), a().append(b()).append(c()));
+///
+/// let (words, nums) = (vec!["a"], Some(vec![1u8])).append((vec!["b"], None));
+/// assert_eq!(words, vec!["a", "b"]);
+/// assert_eq!(nums, Some(vec![1]));
 /// ```
 pub trait Semigroup {
   /// See [`Semigroup`]
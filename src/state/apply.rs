@@ -0,0 +1,36 @@
+use core::marker::PhantomData;
+
+use super::{State, StateLike};
+use crate::prelude::*;
+
+/// A [`StateLike`] that, when run, will apply the function yielded by
+/// `SAB` to the value yielded by `SA`, threading the state through
+/// `SAB` first and `SA` second.
+///
+/// This is the result of calling [`ApplySurrogate.apply_`] on a [`StateLike`].
+#[must_use = "State is not evaluated until `StateLike.run` invoked"]
+pub struct Apply<A, B, AB, SA, SAB>(SAB, SA, PhantomData<(A, B, AB)>);
+
+impl<A, B, AB, SA, SAB> Apply<A, B, AB, SA, SAB> {
+  /// Create a new Apply
+  pub fn new(sab: SAB, sa: SA) -> Self {
+    Self(sab, sa, PhantomData)
+  }
+}
+
+impl<S, A, B, AB, SA, SAB> Equiv for Apply<A, B, AB, SA, SAB> where SAB: Equiv<To = State<S, AB>>
+{
+  type To = State<S, B>;
+}
+
+impl<S, A, B, AB, SA, SAB> StateLike<S, B> for Apply<A, B, AB, SA, SAB>
+  where AB: F1Once<A, Ret = B>,
+        SA: StateLike<S, A>,
+        SAB: StateLike<S, AB>
+{
+  fn run(self, s: S) -> (B, S) {
+    let (ab, s) = self.0.run(s);
+    let (a, s) = self.1.run(s);
+    (ab.call1(a), s)
+  }
+}
@@ -0,0 +1,53 @@
+use core::marker::PhantomData;
+
+use super::{State, StateLike};
+use crate::prelude::*;
+
+/// A function from `A` to a `StateLike<S, B>` waiting to be applied to
+/// the value yielded by a [stateful computation](StateLike).
+///
+/// This is the result of calling [`MonadSurrogate.bind_`] or
+/// [`StateLike.and_then_`] on a [`StateLike`].
+#[must_use = "State is not evaluated until `StateLike.run` invoked"]
+pub struct Bind<F, A, B, SA>(F, SA, PhantomData<(A, B)>);
+
+impl<F, A, B, SA> Bind<F, A, B, SA> {
+  /// Create a new Bind
+  pub fn new(f: F, sa: SA) -> Self {
+    Self(f, sa, PhantomData)
+  }
+}
+
+impl<F, A, B, SA> Clone for Bind<F, A, B, SA>
+  where F: Clone,
+        SA: Clone
+{
+  fn clone(&self) -> Self {
+    Self(self.0.clone(), self.1.clone(), PhantomData)
+  }
+}
+
+impl<F, A, B, SA> Copy for Bind<F, A, B, SA>
+  where F: Copy,
+        SA: Copy
+{
+}
+
+impl<F, S, A, B, SA> Equiv for Bind<F, A, B, SA>
+  where F: F1Once<A>,
+        F::Ret: Equiv<To = State<S, B>>,
+        SA: Equiv<To = State<S, A>>
+{
+  type To = State<S, B>;
+}
+
+impl<F, S, A, B, SA> StateLike<S, B> for Bind<F, A, B, SA>
+  where F: F1Once<A>,
+        F::Ret: StateLike<S, B>,
+        SA: StateLike<S, A>
+{
+  fn run(self, s: S) -> (B, S) {
+    let (a, s) = self.1.run(s);
+    self.0.call1(a).run(s)
+  }
+}
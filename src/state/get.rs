@@ -0,0 +1,61 @@
+use core::marker::PhantomData;
+
+use super::{State, StateLike};
+use crate::prelude::*;
+
+/// Yields a copy of the current state
+///
+/// See [`State::get`]
+#[derive(Debug, Clone, Copy)]
+#[must_use = "State is not evaluated until `StateLike.run` invoked"]
+pub struct Get<S>(PhantomData<S>);
+
+impl<S> Get<S> {
+  /// See [`State::get`]
+  pub fn new() -> Self {
+    Self(PhantomData)
+  }
+}
+
+impl<S> Default for Get<S> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<S> Equiv for Get<S> {
+  type To = State<S, S>;
+}
+
+impl<S> StateLike<S, S> for Get<S> where S: Clone
+{
+  fn run(self, s: S) -> (S, S) {
+    (s.clone(), s)
+  }
+}
+
+/// Yields a value derived from a reference to the current state
+///
+/// See [`State::gets`]
+#[derive(Debug, Clone, Copy)]
+#[must_use = "State is not evaluated until `StateLike.run` invoked"]
+pub struct Gets<S, F>(F, PhantomData<S>);
+
+impl<S, F> Gets<S, F> {
+  /// See [`State::gets`]
+  pub fn new(f: F) -> Self {
+    Self(f, PhantomData)
+  }
+}
+
+impl<S, A, F> Equiv for Gets<S, F> where F: for<'a> F1Once<&'a S, Ret = A>
+{
+  type To = State<S, A>;
+}
+
+impl<S, A, F> StateLike<S, A> for Gets<S, F> where F: for<'a> F1Once<&'a S, Ret = A>
+{
+  fn run(self, s: S) -> (A, S) {
+    (self.0.call1(&s), s)
+  }
+}
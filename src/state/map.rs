@@ -0,0 +1,50 @@
+use core::marker::PhantomData;
+
+use super::{State, StateLike};
+use crate::prelude::*;
+
+/// A function from `X` to some `A` waiting to be applied to
+/// the value yielded by a [stateful computation](StateLike).
+///
+/// This is the result of calling [`FunctorSurrogate.map_`] on a [`StateLike`].
+#[must_use = "State is not evaluated until `StateLike.run` invoked"]
+pub struct Map<F, X, A, SX>(F, SX, PhantomData<(X, A)>);
+
+impl<F, X, A, SX> Map<F, X, A, SX> {
+  /// Create a new Map
+  pub fn new(f: F, sx: SX) -> Self {
+    Self(f, sx, PhantomData)
+  }
+}
+
+impl<F, X, A, SX> Clone for Map<F, X, A, SX>
+  where F: Clone,
+        SX: Clone
+{
+  fn clone(&self) -> Self {
+    Self(self.0.clone(), self.1.clone(), PhantomData)
+  }
+}
+
+impl<F, X, A, SX> Copy for Map<F, X, A, SX>
+  where F: Copy,
+        SX: Copy
+{
+}
+
+impl<F, S, X, A, SX> Equiv for Map<F, X, A, SX>
+  where F: F1Once<X, Ret = A>,
+        SX: Equiv<To = State<S, X>>
+{
+  type To = State<S, A>;
+}
+
+impl<F, S, X, A, SX> StateLike<S, A> for Map<F, X, A, SX>
+  where F: F1Once<X, Ret = A>,
+        SX: StateLike<S, X>
+{
+  fn run(self, s: S) -> (A, S) {
+    let (x, s) = self.1.run(s);
+    (self.0.call1(x), s)
+  }
+}
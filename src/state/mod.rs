@@ -0,0 +1,277 @@
+use core::marker::PhantomData;
+
+use crate::prelude::*;
+
+/// `State.map_`
+pub mod map;
+
+/// `State.bind_`
+pub mod bind;
+
+/// `State.apply_`
+pub mod apply;
+
+/// `State::suspend`
+pub mod suspend;
+
+/// `State::get`, `State::gets`
+pub mod get;
+
+/// `State::put`, `State::modify`
+pub mod put;
+
+/// `State::tail_rec_m`
+pub mod tail_rec;
+
+pub use bind::Bind;
+pub use get::{Get, Gets};
+pub use map::Map;
+pub use put::{Modify, Put};
+pub use suspend::Suspend;
+pub use tail_rec::TailRecM;
+
+/// State HKT
+pub mod hkt {
+  use core::marker::PhantomData;
+
+  use crate::prelude::*;
+
+  /// `State<S, A>` with the state type pinned to some `S`.
+  ///
+  /// (Kind `Type -> Type`)
+  pub struct State<S>(PhantomData<S>);
+
+  impl<S> HKT1 for State<S> {
+    type T<A> = super::State<S, A>;
+  }
+}
+
+/// A lazy computation that threads a state `S` through
+/// and produces a value `A`
+pub trait StateLike<S, A>
+  where Self: Sized + Equiv<To = State<S, A>>
+{
+  /// Run this computation with an initial state,
+  /// yielding the value and the final state.
+  fn run(self, s: S) -> (A, S);
+
+  /// Run this computation with an initial state,
+  /// discarding the final state.
+  fn eval(self, s: S) -> A {
+    self.run(s).0
+  }
+
+  /// Run this computation with an initial state,
+  /// discarding the value.
+  fn exec(self, s: S) -> S {
+    self.run(s).1
+  }
+
+  /// [`MonadSurrogate::bind_`], but the function may return
+  /// any [`StateLike`] (e.g. [`Get`], [`Put`], another [`Bind`])
+  /// rather than only [`State`].
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let incr = State::get().and_then_(|n: usize| State::put(n + 1));
+  ///
+  /// assert_eq!(incr.exec(1), 2);
+  /// ```
+  fn and_then_<B, AMB>(self, f: AMB) -> Bind<AMB, A, B, Self>
+    where AMB: F1Once<A>,
+          AMB::Ret: StateLike<S, B>
+  {
+    Bind::new(f, self)
+  }
+}
+
+impl<I, S, A> FunctorSurrogate<hkt::State<S>, A> for I where I: StateLike<S, A>
+{
+  type Output<AB, B> = Map<AB, A, B, I>;
+
+  fn map_<AB, B>(self, f: AB) -> Map<AB, A, B, I>
+    where AB: F1<A, Ret = B>
+  {
+    Map::new(f, self)
+  }
+}
+
+impl<I, S, AB, TofA> ApplySurrogate<hkt::State<S>, AB, TofA> for I where I: StateLike<S, AB>
+{
+  type ApplyOutput<A, B> = apply::Apply<A, B, AB, TofA, Self>;
+
+  fn apply_<A, B>(self, a: TofA) -> apply::Apply<A, B, AB, TofA, Self>
+    where AB: F1Once<A, Ret = B>
+  {
+    apply::Apply::new(self, a)
+  }
+}
+
+impl<I, S, A> ApplicativeSurrogate<hkt::State<S>, A> for I where I: StateLike<S, A>
+{
+  fn pure(a: A) -> State<S, A> {
+    State::pure(a)
+  }
+}
+
+impl<I, S, A> MonadSurrogate<hkt::State<S>, A> for I where I: StateLike<S, A>
+{
+  type BindOutput<B, AMB> = Bind<AMB, A, B, Self>;
+
+  fn bind_<B, AMB>(self, f: AMB) -> Self::BindOutput<B, AMB>
+    where AMB: F1<A, Ret = State<S, B>>
+  {
+    Bind::new(f, self)
+  }
+}
+
+/// Lazy stateful computation
+///
+/// Like [`IO`], a State computation is built from a stack of
+/// structs and does nothing until it is [`run`](StateLike::run)
+/// with an initial state, so no heap allocations or dynamic
+/// dispatch are needed.
+///
+/// `State<S, A>` itself is the computation that leaves the state
+/// untouched and yields `A`. Other state primitives are:
+///  - [`State::get`] - yield a copy of the state
+///  - [`State::gets`] - yield something derived from a reference to the state
+///  - [`State::put`] - replace the state
+///  - [`State::modify`] - transform the state
+///  - [`State::suspend`] - any function from `S` to `(A, S)`
+///
+/// These (and the types returned by `map_`/`bind_`/`apply_`/`and_then_`)
+/// all implement [`StateLike`], so prefer `impl StateLike<S, A>` in
+/// parameter and return positions.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// /// Pop the top of a stack
+/// fn pop() -> impl StateLike<Vec<u32>, Option<u32>> {
+///   State::suspend(|mut stack: Vec<u32>| (stack.pop(), stack))
+/// }
+///
+/// /// Push onto a stack
+/// fn push(n: u32) -> impl StateLike<Vec<u32>, ()> {
+///   State::modify(move |mut stack: Vec<u32>| {
+///     stack.push(n);
+///     stack
+///   })
+/// }
+///
+/// let add_top_two =
+///   pop().and_then_(|a: Option<u32>| pop().map_(move |b| a.zip(b)))
+///        .and_then_(|ab: Option<(u32, u32)>| push(ab.map(|(a, b)| a + b).unwrap_or(0)));
+///
+/// assert_eq!(add_top_two.exec(vec![1, 2, 3]), vec![1, 5]);
+///
+/// let len = State::gets(|stack: &Vec<u32>| stack.len());
+/// assert_eq!(len.run(vec![1, 2]), (2, vec![1, 2]));
+/// ```
+///
+/// `StateLike` also provides [`map_`](FunctorSurrogate), [`apply_`](ApplySurrogate), and [`bind_`](MonadSurrogate):
+/// ```
+/// use naan::prelude::*;
+///
+/// let next_id = || State::suspend(|n: usize| (n, n + 1)).map_(|n: usize| format!("id-{n}"));
+///
+/// let pair = next_id().map_(tuple2.curry()).apply_(next_id());
+/// assert_eq!(pair.run(7), ((format!("id-7"), format!("id-8")), 9));
+///
+/// let shout = next_id().bind_(|id: String| State::pure(id.to_uppercase()));
+/// assert_eq!(shout.eval(0), format!("ID-0"));
+/// ```
+///
+/// There is no [`Functor`] or [`Monad`] impl for `State`: those traits accept
+/// closures of any lifetime, and a lazy `State` would have to hold on to the
+/// closure until it is run. The surrogates above, [`State::tail_rec_m`],
+/// and [`mdo!`](crate::mdo)`(state { .. })` fill that role.
+pub struct State<S, A>(A, PhantomData<S>);
+
+impl<S, A> State<S, A> {
+  /// Lift a value to `State`, leaving the state untouched.
+  pub fn pure(a: A) -> Self {
+    Self(a, PhantomData)
+  }
+
+  /// Store a lazy function from the current state to a value
+  /// and a new state
+  pub fn suspend<F>(f: F) -> Suspend<S, F>
+    where F: F1Once<S, Ret = (A, S)>
+  {
+    Suspend::new(f)
+  }
+
+  /// Yield a value derived from a reference to the current state
+  pub fn gets<F>(f: F) -> Gets<S, F>
+    where F: for<'a> F1Once<&'a S, Ret = A>
+  {
+    Gets::new(f)
+  }
+
+  /// Run a stateful computation repeatedly, starting with `seed`, until it
+  /// yields [`ControlFlow::Break`](core::ops::ControlFlow::Break).
+  ///
+  /// This is the State counterpart of [`MonadRec::tail_rec_m`], and runs
+  /// in constant stack space no matter how many times `f` is called.
+  ///
+  /// ```
+  /// use core::ops::ControlFlow;
+  ///
+  /// use naan::prelude::*;
+  ///
+  /// // count down from the state to 0, summing the numbers visited
+  /// let sum = State::tail_rec_m(0u64, |sum: u64| {
+  ///   State::suspend(move |n: u64| {
+  ///     if n == 0 {
+  ///       (ControlFlow::Break(sum), n)
+  ///     } else {
+  ///       (ControlFlow::Continue(sum + n), n - 1)
+  ///     }
+  ///   })
+  /// });
+  ///
+  /// assert_eq!(sum.run(1_000_000), (500_000_500_000, 0));
+  /// ```
+  pub fn tail_rec_m<X, F>(seed: X, f: F) -> TailRecM<X, F, A>
+    where F: F1<X>,
+          F::Ret: StateLike<S, core::ops::ControlFlow<A, X>>
+  {
+    TailRecM::new(seed, f)
+  }
+}
+
+impl<S> State<S, S> {
+  /// Yield a copy of the current state
+  pub fn get() -> Get<S>
+    where S: Clone
+  {
+    Get::new()
+  }
+}
+
+impl<S> State<S, ()> {
+  /// Replace the current state
+  pub fn put(s: S) -> Put<S> {
+    Put::new(s)
+  }
+
+  /// Transform the current state
+  pub fn modify<F>(f: F) -> Modify<S, F>
+    where F: F1Once<S, Ret = S>
+  {
+    Modify::new(f)
+  }
+}
+
+impl<S, A> Equiv for State<S, A> {
+  type To = State<S, A>;
+}
+
+impl<S, A> StateLike<S, A> for State<S, A> {
+  fn run(self, s: S) -> (A, S) {
+    (self.0, s)
+  }
+}
@@ -0,0 +1,54 @@
+use core::marker::PhantomData;
+
+use super::{State, StateLike};
+use crate::prelude::*;
+
+/// Replaces the current state
+///
+/// See [`State::put`]
+#[derive(Debug, Clone, Copy)]
+#[must_use = "State is not evaluated until `StateLike.run` invoked"]
+pub struct Put<S>(S);
+
+impl<S> Put<S> {
+  /// See [`State::put`]
+  pub fn new(s: S) -> Self {
+    Self(s)
+  }
+}
+
+impl<S> Equiv for Put<S> {
+  type To = State<S, ()>;
+}
+
+impl<S> StateLike<S, ()> for Put<S> {
+  fn run(self, _: S) -> ((), S) {
+    ((), self.0)
+  }
+}
+
+/// Transforms the current state
+///
+/// See [`State::modify`]
+#[derive(Debug, Clone, Copy)]
+#[must_use = "State is not evaluated until `StateLike.run` invoked"]
+pub struct Modify<S, F>(F, PhantomData<S>);
+
+impl<S, F> Modify<S, F> {
+  /// See [`State::modify`]
+  pub fn new(f: F) -> Self {
+    Self(f, PhantomData)
+  }
+}
+
+impl<S, F> Equiv for Modify<S, F> where F: F1Once<S, Ret = S>
+{
+  type To = State<S, ()>;
+}
+
+impl<S, F> StateLike<S, ()> for Modify<S, F> where F: F1Once<S, Ret = S>
+{
+  fn run(self, s: S) -> ((), S) {
+    ((), self.0.call1(s))
+  }
+}
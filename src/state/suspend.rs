@@ -0,0 +1,30 @@
+use core::marker::PhantomData;
+
+use super::{State, StateLike};
+use crate::prelude::*;
+
+/// A deferred function from the current state to a value
+/// and a new state
+#[derive(Debug, Clone, Copy)]
+#[must_use = "State is not evaluated until `StateLike.run` invoked"]
+pub struct Suspend<S, F>(F, PhantomData<S>);
+
+impl<S, F> Suspend<S, F> {
+  /// See [`State::suspend`]
+  pub fn new(f: F) -> Self {
+    Self(f, PhantomData)
+  }
+}
+
+impl<S, A, F> Equiv for Suspend<S, F> where F: F1Once<S, Ret = (A, S)>
+{
+  /// `Suspend<S, F>` is conceptually equivalent to `State<S, A>`
+  type To = State<S, A>;
+}
+
+impl<S, A, F> StateLike<S, A> for Suspend<S, F> where F: F1Once<S, Ret = (A, S)>
+{
+  fn run(self, s: S) -> (A, S) {
+    self.0.call1(s)
+  }
+}
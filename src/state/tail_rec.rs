@@ -0,0 +1,59 @@
+use core::marker::PhantomData;
+use core::ops::ControlFlow;
+
+use super::{State, StateLike};
+use crate::prelude::*;
+
+/// A loop of stateful computations, run in constant stack space
+///
+/// This is the result of calling [`State::tail_rec_m`].
+#[must_use = "State is not evaluated until `StateLike.run` invoked"]
+pub struct TailRecM<X, F, A>(X, F, PhantomData<A>);
+
+impl<X, F, A> TailRecM<X, F, A> {
+  /// Create a new TailRecM
+  pub fn new(seed: X, f: F) -> Self {
+    Self(seed, f, PhantomData)
+  }
+}
+
+impl<X, F, A> Clone for TailRecM<X, F, A>
+  where X: Clone,
+        F: Clone
+{
+  fn clone(&self) -> Self {
+    Self(self.0.clone(), self.1.clone(), PhantomData)
+  }
+}
+
+impl<X, F, A> Copy for TailRecM<X, F, A>
+  where X: Copy,
+        F: Copy
+{
+}
+
+impl<S, X, F, A> Equiv for TailRecM<X, F, A>
+  where F: F1<X>,
+        F::Ret: Equiv<To = State<S, ControlFlow<A, X>>>
+{
+  type To = State<S, A>;
+}
+
+impl<S, X, F, A> StateLike<S, A> for TailRecM<X, F, A>
+  where F: F1<X>,
+        F::Ret: StateLike<S, ControlFlow<A, X>>
+{
+  fn run(self, s: S) -> (A, S) {
+    let Self(mut x, f, _) = self;
+    let mut s = s;
+    loop {
+      match f.call(x).run(s) {
+        | (ControlFlow::Break(a), s) => return (a, s),
+        | (ControlFlow::Continue(next), s_) => {
+          x = next;
+          s = s_;
+        },
+      }
+    }
+  }
+}
@@ -0,0 +1,67 @@
+use crate::prelude::*;
+
+/// `OptionT`
+pub mod option_t;
+
+/// `ResultT`
+pub mod result_t;
+
+/// `WriterT`
+pub mod writer_t;
+
+/// `StateT`
+pub mod state_t;
+
+/// `ReaderT`
+pub mod reader_t;
+
+pub use option_t::OptionT;
+pub use reader_t::{ReaderT, ReaderTLike};
+pub use result_t::ResultT;
+pub use state_t::{StateT, StateTLike};
+pub use writer_t::WriterT;
+
+/// An [`HKT1`] whose types `T<A>` are [`Monad`]s for _every_ `A`.
+///
+/// A monad transformer needs to `fmap` and `bind` its base monad at types
+/// only known inside its own `fmap` and `bind`, and Rust can't express
+/// `for<A> M::T<A>: Monad<M, A>` as a bound. Implementing this trait for a
+/// marker makes that promise instead; the [`deriving`] macro can do so for
+/// any marker whose types implement [`Monad`]:
+///
+/// ```ignore
+/// deriving!(impl MonadHKT for hkt::Option {..Monad});
+/// ```
+pub trait MonadHKT: HKT1 {
+  /// [`Applicative::pure`]
+  fn pure<A>(a: A) -> Self::T<A>;
+
+  /// [`Functor::fmap`]
+  fn fmap<A, B, AB>(ta: Self::T<A>, f: AB) -> Self::T<B>
+    where AB: F1<A, Ret = B>;
+
+  /// [`Apply::apply_with`]
+  fn apply_with<AB, A, B, Cloner>(tab: Self::T<AB>, ta: Self::T<A>, cloner: Cloner) -> Self::T<B>
+    where AB: F1<A, Ret = B>,
+          Cloner: for<'a> F1<&'a A, Ret = A>;
+
+  /// [`Monad::bind`]
+  fn bind<A, B, AMB>(ta: Self::T<A>, f: AMB) -> Self::T<B>
+    where AMB: F1<A, Ret = Self::T<B>>;
+}
+
+/// A monad transformer; `Self` adds some effect to the base monad `M`.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// let found = hkt::OptionT::<hkt::Vec>::lift(vec![1, 2]);
+///
+/// assert_eq!(found.run(), vec![Some(1), Some(2)]);
+/// ```
+pub trait MonadTrans<M>: HKT1
+  where M: MonadHKT
+{
+  /// Lift a computation in the base monad `M` into this transformer
+  fn lift<A>(ma: M::T<A>) -> Self::T<A>;
+}
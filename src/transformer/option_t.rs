@@ -0,0 +1,160 @@
+use core::fmt;
+
+use super::{MonadHKT, MonadTrans};
+use crate::prelude::*;
+
+/// OptionT Kinds
+pub mod hkt {
+  use core::marker::PhantomData;
+
+  use crate::prelude::*;
+
+  /// [`OptionT`](super::OptionT) lifted to an HKT1
+  /// with the base monad pinned to some `M`.
+  ///
+  /// (Kind `Type -> Type`)
+  pub struct OptionT<M>(PhantomData<M>);
+  impl<M> HKT1 for OptionT<M> where M: HKT1
+  {
+    type T<A> = super::OptionT<M, A>;
+  }
+}
+
+/// Adds the possibility of absence to a base monad `M`,
+/// wrapping `M<Option<A>>`.
+///
+/// [`bind`](Monad::bind) stops at the first `None`, without
+/// needing to match on the `Option` inside `M` by hand.
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use naan::prelude::*;
+///
+/// type Lookup<A> = OptionT<hkt::Writer<Vec<String>>, A>;
+///
+/// fn lookup(env: &HashMap<&'static str, u8>, key: &'static str) -> Lookup<u8> {
+///   let log = Writer::tell(vec![format!("looking up {key}")]);
+///   hkt::OptionT::lift(log).bind(|_| OptionT::new(Writer::pure(env.get(key).copied())))
+/// }
+///
+/// let env = HashMap::from([("a", 1), ("b", 2)]);
+///
+/// let sum = lookup(&env, "a").bind(|a| lookup(&env, "b").fmap(move |b| a + b));
+/// assert_eq!(sum.run().run(),
+///            (Some(3), vec![format!("looking up a"), format!("looking up b")]));
+///
+/// let missing = lookup(&env, "c").bind(|c| lookup(&env, "a").fmap(move |a| a + c));
+/// assert_eq!(missing.run().run(), (None, vec![format!("looking up c")]));
+/// ```
+pub struct OptionT<M, A>(M::T<Option<A>>) where M: HKT1;
+
+impl<M, A> OptionT<M, A> where M: HKT1
+{
+  /// Wrap `M<Option<A>>`
+  pub fn new(m: M::T<Option<A>>) -> Self {
+    Self(m)
+  }
+
+  /// Unwrap to `M<Option<A>>`
+  pub fn run(self) -> M::T<Option<A>> {
+    self.0
+  }
+}
+
+impl<M, A> OptionT<M, A> where M: MonadHKT
+{
+  /// `M<None>`
+  pub fn none() -> Self {
+    Self(M::pure(None))
+  }
+}
+
+impl<M, A> Clone for OptionT<M, A>
+  where M: HKT1,
+        M::T<Option<A>>: Clone
+{
+  fn clone(&self) -> Self {
+    Self(self.0.clone())
+  }
+}
+
+impl<M, A> fmt::Debug for OptionT<M, A>
+  where M: HKT1,
+        M::T<Option<A>>: fmt::Debug
+{
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_tuple("OptionT").field(&self.0).finish()
+  }
+}
+
+impl<M, A> PartialEq for OptionT<M, A>
+  where M: HKT1,
+        M::T<Option<A>>: PartialEq
+{
+  fn eq(&self, other: &Self) -> bool {
+    self.0 == other.0
+  }
+}
+
+impl<M, A> Eq for OptionT<M, A>
+  where M: HKT1,
+        M::T<Option<A>>: Eq
+{
+}
+
+impl<M, A> Functor<hkt::OptionT<M>, A> for OptionT<M, A> where M: MonadHKT
+{
+  fn fmap<AB, B>(self, f: AB) -> OptionT<M, B>
+    where AB: F1<A, Ret = B>
+  {
+    OptionT(M::fmap(self.0, move |oa: Option<A>| oa.map(|a| f.call(a))))
+  }
+}
+
+impl<M, AB> Apply<hkt::OptionT<M>, AB> for OptionT<M, AB> where M: MonadHKT
+{
+  fn apply_with<A, B, Cloner>(self, a: OptionT<M, A>, cloner: Cloner) -> OptionT<M, B>
+    where AB: F1<A, Ret = B>,
+          Cloner: for<'a> F1<&'a A, Ret = A>
+  {
+    let lifted = M::fmap(self.0, |f: Option<AB>| {
+      move |a: Option<A>| match (&f, a) {
+        | (Some(f), Some(a)) => Some(f.call(a)),
+        | _ => None,
+      }
+    });
+
+    OptionT(M::apply_with(lifted, a.0, move |a: &Option<A>| {
+              a.as_ref().map(|a| cloner.call(a))
+            }))
+  }
+}
+
+impl<M, A> Applicative<hkt::OptionT<M>, A> for OptionT<M, A> where M: MonadHKT
+{
+  fn pure(a: A) -> OptionT<M, A> {
+    OptionT(M::pure(Some(a)))
+  }
+}
+
+impl<M, A> Monad<hkt::OptionT<M>, A> for OptionT<M, A> where M: MonadHKT
+{
+  fn bind<B, AMB>(self, f: AMB) -> OptionT<M, B>
+    where AMB: F1<A, Ret = OptionT<M, B>>
+  {
+    OptionT(M::bind(self.0, move |oa: Option<A>| match oa {
+              | Some(a) => f.call(a).0,
+              | None => M::pure(None),
+            }))
+  }
+}
+
+impl<M> MonadTrans<M> for hkt::OptionT<M> where M: MonadHKT
+{
+  fn lift<A>(ma: M::T<A>) -> OptionT<M, A> {
+    OptionT(M::fmap(ma, Some))
+  }
+}
+
+deriving!(impl<M> MonadHKT for hkt::OptionT<M> where M: MonadHKT {..Monad});
@@ -0,0 +1,477 @@
+use core::marker::PhantomData;
+
+use super::{MonadHKT, MonadTrans};
+use crate::prelude::*;
+use crate::reader::{Ask, Asks};
+
+/// ReaderT Kinds
+pub mod hkt {
+  use core::marker::PhantomData;
+
+  use crate::prelude::*;
+
+  /// `ReaderT<M, R, A>` with the base monad pinned to some `M`
+  /// and the environment type pinned to some `R`.
+  ///
+  /// (Kind `Type -> Type`)
+  pub struct ReaderT<M, R>(PhantomData<(M, R)>);
+
+  impl<M, R> HKT1 for ReaderT<M, R> where M: HKT1
+  {
+    type T<A> = super::ReaderT<M, R, A>;
+  }
+}
+
+/// A lazy computation that reads from an environment `R`
+/// to produce a value `A` in a base monad `M`
+pub trait ReaderTLike<M, R, A>
+  where Self: Sized + Equiv<To = ReaderT<M, R, A>>,
+        M: MonadHKT
+{
+  /// Run this computation with an environment
+  fn run(self, r: R) -> M::T<A>;
+
+  /// Run this computation in an environment transformed by `f`
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let depth = ReaderT::<hkt::Option, usize, usize>::ask();
+  ///
+  /// assert_eq!(depth.local(|d| d + 1).run(0), Some(1));
+  /// ```
+  fn local<F>(self, f: F) -> Local<F, Self>
+    where F: F1Once<R, Ret = R>
+  {
+    Local::new(f, self)
+  }
+
+  /// [`MonadSurrogate::bind_`], but the function may return
+  /// any [`ReaderTLike`] rather than only [`ReaderT`].
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let last =
+  ///   ReaderT::<hkt::Option, Vec<u8>, usize>::asks(|v: Vec<u8>| v.len()).and_then_(|len: usize| {
+  ///     ReaderT::suspend(move |v: Vec<u8>| v.get(len.checked_sub(1)?).copied())
+  ///   });
+  ///
+  /// assert_eq!(last.run(vec![1, 2, 3]), Some(3));
+  /// ```
+  fn and_then_<B, AMB>(self, f: AMB) -> Bind<AMB, A, B, Self>
+    where AMB: F1<A>,
+          AMB::Ret: ReaderTLike<M, R, B>
+  {
+    Bind::new(f, self)
+  }
+}
+
+impl<I, M, R, A> FunctorSurrogate<hkt::ReaderT<M, R>, A> for I
+  where I: ReaderTLike<M, R, A>,
+        M: MonadHKT
+{
+  type Output<AB, B> = Map<AB, A, B, I>;
+
+  fn map_<AB, B>(self, f: AB) -> Map<AB, A, B, I>
+    where AB: F1<A, Ret = B>
+  {
+    Map::new(f, self)
+  }
+}
+
+impl<I, M, R, AB, TofA> ApplySurrogate<hkt::ReaderT<M, R>, AB, TofA> for I
+  where I: ReaderTLike<M, R, AB>,
+        M: MonadHKT
+{
+  type ApplyOutput<A, B> = Apply<A, B, AB, TofA, Self>;
+
+  fn apply_<A, B>(self, a: TofA) -> Apply<A, B, AB, TofA, Self>
+    where AB: F1Once<A, Ret = B>
+  {
+    Apply::new(self, a)
+  }
+}
+
+impl<I, M, R, A> ApplicativeSurrogate<hkt::ReaderT<M, R>, A> for I
+  where I: ReaderTLike<M, R, A>,
+        M: MonadHKT
+{
+  fn pure(a: A) -> ReaderT<M, R, A> {
+    ReaderT::lift(M::pure(a))
+  }
+}
+
+impl<I, M, R, A> MonadSurrogate<hkt::ReaderT<M, R>, A> for I
+  where I: ReaderTLike<M, R, A>,
+        M: MonadHKT
+{
+  type BindOutput<B, AMB> = Bind<AMB, A, B, Self>;
+
+  fn bind_<B, AMB>(self, f: AMB) -> Self::BindOutput<B, AMB>
+    where AMB: F1<A, Ret = ReaderT<M, R, B>>
+  {
+    Bind::new(f, self)
+  }
+}
+
+impl<M, R> MonadTrans<M> for hkt::ReaderT<M, R> where M: MonadHKT
+{
+  fn lift<A>(ma: M::T<A>) -> ReaderT<M, R, A> {
+    ReaderT::lift(ma)
+  }
+}
+
+/// Lazy computation that reads from an environment
+/// in a base monad `M`
+///
+/// Like [`Reader`], a ReaderT computation is a stack of structs
+/// that does nothing until it is [`run`](ReaderTLike::run) with an environment.
+///
+/// `ReaderT<M, R, A>` itself is a computation in `M` [lifted](MonadTrans::lift)
+/// to ignore the environment. Other primitives are:
+///  - [`ReaderT::suspend`] - any function from `R` to `M<A>`
+///  - [`ReaderT::from_reader`] - any [`ReaderLike`] computation
+///  - [`ReaderT::ask`], [`ReaderT::asks`]
+///
+/// Continuations are called once per value yielded by `M`, so
+/// functions given to `map_`/`bind_`/`and_then_` must be [`F1`], not [`F1Once`].
+///
+/// Like [`Reader`], `ReaderT` has no [`Functor`] or [`Monad`] impl (and so no
+/// [`MonadHKT`] to stack further transformers on); use `ReaderTLike` and
+/// [`mdo!`](crate::mdo)`(reader_t { .. })`.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// struct Config {
+///   retries: u8,
+/// }
+///
+/// fn retries() -> impl ReaderTLike<hkt::ResultOk<String>, &'static Config, u8> {
+///   ReaderT::suspend(|c: &Config| match c.retries {
+///     | 0 => Err(format!("retries must be nonzero")),
+///     | n => Ok(n),
+///   })
+/// }
+///
+/// let backoff = || retries().map_(|n: u8| (0..n).map(|i| 10u32 * 2u32.pow(i.into())).sum::<u32>());
+///
+/// assert_eq!(backoff().run(&Config { retries: 3 }), Ok(70));
+/// assert_eq!(backoff().run(&Config { retries: 0 }),
+///            Err(format!("retries must be nonzero")));
+/// ```
+pub struct ReaderT<M, R, A>(M::T<A>, PhantomData<R>) where M: HKT1;
+
+impl<M, R, A> ReaderT<M, R, A> where M: HKT1
+{
+  /// Lift a computation in `M`, ignoring the environment
+  pub fn lift(ma: M::T<A>) -> Self {
+    Self(ma, PhantomData)
+  }
+
+  /// Store a lazy function from the environment to a value in `M`
+  pub fn suspend<F>(f: F) -> Suspend<M, R, A, F>
+    where F: F1Once<R, Ret = M::T<A>>
+  {
+    Suspend::new(f)
+  }
+
+  /// Lift a [`ReaderLike`] computation into `M`
+  pub fn from_reader<RA>(ra: RA) -> Hoist<M, RA>
+    where RA: ReaderLike<R, A>
+  {
+    Hoist::new(ra)
+  }
+
+  /// Yield a value derived from the environment
+  pub fn asks<F>(f: F) -> Hoist<M, Asks<R, F>>
+    where F: F1Once<R, Ret = A>
+  {
+    Hoist::new(Reader::asks(f))
+  }
+}
+
+impl<M, R> ReaderT<M, R, R> where M: HKT1
+{
+  /// Yield the environment
+  pub fn ask() -> Hoist<M, Ask<R>> {
+    Hoist::new(Reader::ask())
+  }
+}
+
+impl<M, R, A> Clone for ReaderT<M, R, A>
+  where M: HKT1,
+        M::T<A>: Clone
+{
+  fn clone(&self) -> Self {
+    Self(self.0.clone(), PhantomData)
+  }
+}
+
+impl<M, R, A> Equiv for ReaderT<M, R, A> where M: HKT1
+{
+  type To = ReaderT<M, R, A>;
+}
+
+impl<M, R, A> ReaderTLike<M, R, A> for ReaderT<M, R, A> where M: MonadHKT
+{
+  fn run(self, _: R) -> M::T<A> {
+    self.0
+  }
+}
+
+/// A deferred function from the environment to a value in `M`
+///
+/// See [`ReaderT::suspend`]
+#[must_use = "ReaderT is not evaluated until `ReaderTLike.run` invoked"]
+pub struct Suspend<M, R, A, F>(F, PhantomData<(M, R, A)>);
+
+impl<M, R, A, F> Suspend<M, R, A, F> {
+  /// See [`ReaderT::suspend`]
+  pub fn new(f: F) -> Self {
+    Self(f, PhantomData)
+  }
+}
+
+impl<M, R, A, F> Clone for Suspend<M, R, A, F> where F: Clone
+{
+  fn clone(&self) -> Self {
+    Self(self.0.clone(), PhantomData)
+  }
+}
+
+impl<M, R, A, F> Copy for Suspend<M, R, A, F> where F: Copy {}
+
+impl<M, R, A, F> Equiv for Suspend<M, R, A, F>
+  where M: HKT1,
+        F: F1Once<R, Ret = M::T<A>>
+{
+  type To = ReaderT<M, R, A>;
+}
+
+impl<M, R, A, F> ReaderTLike<M, R, A> for Suspend<M, R, A, F>
+  where M: MonadHKT,
+        F: F1Once<R, Ret = M::T<A>>
+{
+  fn run(self, r: R) -> M::T<A> {
+    self.0.call1(r)
+  }
+}
+
+/// A [`ReaderLike`] computation lifted into `M`
+///
+/// See [`ReaderT::from_reader`]
+#[must_use = "ReaderT is not evaluated until `ReaderTLike.run` invoked"]
+pub struct Hoist<M, RA>(RA, PhantomData<M>);
+
+impl<M, RA> Hoist<M, RA> {
+  /// See [`ReaderT::from_reader`]
+  pub fn new(ra: RA) -> Self {
+    Self(ra, PhantomData)
+  }
+}
+
+impl<M, RA> Clone for Hoist<M, RA> where RA: Clone
+{
+  fn clone(&self) -> Self {
+    Self(self.0.clone(), PhantomData)
+  }
+}
+
+impl<M, RA> Copy for Hoist<M, RA> where RA: Copy {}
+
+impl<M, R, A, RA> Equiv for Hoist<M, RA>
+  where M: HKT1,
+        RA: Equiv<To = Reader<R, A>>
+{
+  type To = ReaderT<M, R, A>;
+}
+
+impl<M, R, A, RA> ReaderTLike<M, R, A> for Hoist<M, RA>
+  where M: MonadHKT,
+        RA: ReaderLike<R, A>
+{
+  fn run(self, r: R) -> M::T<A> {
+    M::pure(self.0.run(r))
+  }
+}
+
+/// A [`ReaderTLike`] run in an environment transformed by `F`
+///
+/// This is the result of calling [`ReaderTLike.local`].
+#[must_use = "ReaderT is not evaluated until `ReaderTLike.run` invoked"]
+pub struct Local<F, RA>(F, RA);
+
+impl<F, RA> Local<F, RA> {
+  /// Create a new Local
+  pub fn new(f: F, ra: RA) -> Self {
+    Self(f, ra)
+  }
+}
+
+impl<F, RA> Clone for Local<F, RA>
+  where F: Clone,
+        RA: Clone
+{
+  fn clone(&self) -> Self {
+    Self(self.0.clone(), self.1.clone())
+  }
+}
+
+impl<F, RA> Copy for Local<F, RA>
+  where F: Copy,
+        RA: Copy
+{
+}
+
+impl<F, M, R, A, RA> Equiv for Local<F, RA>
+  where M: HKT1,
+        F: F1Once<R, Ret = R>,
+        RA: Equiv<To = ReaderT<M, R, A>>
+{
+  type To = ReaderT<M, R, A>;
+}
+
+impl<F, M, R, A, RA> ReaderTLike<M, R, A> for Local<F, RA>
+  where M: MonadHKT,
+        F: F1Once<R, Ret = R>,
+        RA: ReaderTLike<M, R, A>
+{
+  fn run(self, r: R) -> M::T<A> {
+    self.1.run(self.0.call1(r))
+  }
+}
+
+/// A function from `X` to some `A` waiting to be applied to
+/// each value yielded by a [`ReaderTLike`].
+///
+/// This is the result of calling [`FunctorSurrogate.map_`] on a [`ReaderTLike`].
+#[must_use = "ReaderT is not evaluated until `ReaderTLike.run` invoked"]
+pub struct Map<F, X, A, RX>(F, RX, PhantomData<(X, A)>);
+
+impl<F, X, A, RX> Map<F, X, A, RX> {
+  /// Create a new Map
+  pub fn new(f: F, rx: RX) -> Self {
+    Self(f, rx, PhantomData)
+  }
+}
+
+impl<F, X, A, RX> Clone for Map<F, X, A, RX>
+  where F: Clone,
+        RX: Clone
+{
+  fn clone(&self) -> Self {
+    Self(self.0.clone(), self.1.clone(), PhantomData)
+  }
+}
+
+impl<F, X, A, RX> Copy for Map<F, X, A, RX>
+  where F: Copy,
+        RX: Copy
+{
+}
+
+impl<F, M, R, X, A, RX> Equiv for Map<F, X, A, RX>
+  where M: HKT1,
+        F: F1<X, Ret = A>,
+        RX: Equiv<To = ReaderT<M, R, X>>
+{
+  type To = ReaderT<M, R, A>;
+}
+
+impl<F, M, R, X, A, RX> ReaderTLike<M, R, A> for Map<F, X, A, RX>
+  where M: MonadHKT,
+        F: F1<X, Ret = A>,
+        RX: ReaderTLike<M, R, X>
+{
+  fn run(self, r: R) -> M::T<A> {
+    M::fmap(self.1.run(r), self.0)
+  }
+}
+
+/// A function from `A` to a `ReaderTLike<M, R, B>` waiting to be applied to
+/// each value yielded by a [`ReaderTLike`].
+///
+/// This is the result of calling [`MonadSurrogate.bind_`] or
+/// [`ReaderTLike.and_then_`] on a [`ReaderTLike`].
+#[must_use = "ReaderT is not evaluated until `ReaderTLike.run` invoked"]
+pub struct Bind<F, A, B, RA>(F, RA, PhantomData<(A, B)>);
+
+impl<F, A, B, RA> Bind<F, A, B, RA> {
+  /// Create a new Bind
+  pub fn new(f: F, ra: RA) -> Self {
+    Self(f, ra, PhantomData)
+  }
+}
+
+impl<F, A, B, RA> Clone for Bind<F, A, B, RA>
+  where F: Clone,
+        RA: Clone
+{
+  fn clone(&self) -> Self {
+    Self(self.0.clone(), self.1.clone(), PhantomData)
+  }
+}
+
+impl<F, A, B, RA> Copy for Bind<F, A, B, RA>
+  where F: Copy,
+        RA: Copy
+{
+}
+
+impl<F, M, R, A, B, RA> Equiv for Bind<F, A, B, RA>
+  where M: HKT1,
+        F: F1<A>,
+        F::Ret: Equiv<To = ReaderT<M, R, B>>,
+        RA: Equiv<To = ReaderT<M, R, A>>
+{
+  type To = ReaderT<M, R, B>;
+}
+
+impl<F, M, R, A, B, RA> ReaderTLike<M, R, B> for Bind<F, A, B, RA>
+  where M: MonadHKT,
+        R: Clone,
+        F: F1<A>,
+        F::Ret: ReaderTLike<M, R, B>,
+        RA: ReaderTLike<M, R, A>
+{
+  fn run(self, r: R) -> M::T<B> {
+    let f = self.0;
+    M::bind(self.1.run(r.clone()), move |a: A| f.call(a).run(r.clone()))
+  }
+}
+
+/// A [`ReaderTLike`] that, when run, will apply the functions yielded by
+/// `RAB` to the values yielded by `RA`.
+///
+/// This is the result of calling [`ApplySurrogate.apply_`] on a [`ReaderTLike`].
+#[must_use = "ReaderT is not evaluated until `ReaderTLike.run` invoked"]
+pub struct Apply<A, B, AB, RA, RAB>(RAB, RA, PhantomData<(A, B, AB)>);
+
+impl<A, B, AB, RA, RAB> Apply<A, B, AB, RA, RAB> {
+  /// Create a new Apply
+  pub fn new(rab: RAB, ra: RA) -> Self {
+    Self(rab, ra, PhantomData)
+  }
+}
+
+impl<M, R, A, B, AB, RA, RAB> Equiv for Apply<A, B, AB, RA, RAB>
+  where M: HKT1,
+        RAB: Equiv<To = ReaderT<M, R, AB>>
+{
+  type To = ReaderT<M, R, B>;
+}
+
+impl<M, R, A, B, AB, RA, RAB> ReaderTLike<M, R, B> for Apply<A, B, AB, RA, RAB>
+  where M: MonadHKT,
+        R: Clone,
+        A: Clone,
+        AB: F1<A, Ret = B>,
+        RA: ReaderTLike<M, R, A>,
+        RAB: ReaderTLike<M, R, AB>
+{
+  fn run(self, r: R) -> M::T<B> {
+    M::apply_with(self.0.run(r.clone()), self.1.run(r), Clone::clone)
+  }
+}
@@ -0,0 +1,185 @@
+use core::fmt;
+
+use super::{MonadHKT, MonadTrans};
+use crate::prelude::*;
+
+/// ResultT Kinds
+pub mod hkt {
+  use core::marker::PhantomData;
+
+  use crate::prelude::*;
+
+  /// [`ResultT`](super::ResultT) lifted to an HKT1
+  /// with the base monad pinned to some `M` and
+  /// the error type pinned to some `E`.
+  ///
+  /// (Kind `Type -> Type`)
+  pub struct ResultT<M, E>(PhantomData<(M, E)>);
+  impl<M, E> HKT1 for ResultT<M, E> where M: HKT1
+  {
+    type T<A> = super::ResultT<M, E, A>;
+  }
+}
+
+/// Adds failure with an error `E` to a base monad `M`,
+/// wrapping `M<Result<A, E>>`.
+///
+/// [`bind`](Monad::bind) stops at the first `Err`, without
+/// needing to match on the `Result` inside `M` by hand.
+///
+/// `E` must be [`Clone`] for `ResultT` to be [`Apply`]; the base monad
+/// may call the lifted function many times (e.g. once per element of a `Vec`)
+/// and each call needs its own copy of a failure.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// type Audited<A> = ResultT<hkt::Writer<Vec<String>>, String, A>;
+///
+/// fn parse(s: &'static str) -> Audited<u8> {
+///   let log = Writer::tell(vec![format!("parsing {s:?}")]);
+///   hkt::ResultT::lift(log).bind(|_| ResultT::new(Writer::pure(s.parse().map_err(|_| format!("{s:?} isn't a u8")))))
+/// }
+///
+/// let sum = parse("1").bind(|a| parse("2").fmap(move |b| a + b));
+/// assert_eq!(sum.run().run(),
+///            (Ok(3), vec![format!("parsing \"1\""), format!("parsing \"2\"")]));
+///
+/// let bad = parse("x").bind(|a| parse("2").fmap(move |b| a + b));
+/// assert_eq!(bad.run().run(),
+///            (Err(format!("\"x\" isn't a u8")), vec![format!("parsing \"x\"")]));
+/// ```
+pub struct ResultT<M, E, A>(M::T<Result<A, E>>) where M: HKT1;
+
+impl<M, E, A> ResultT<M, E, A> where M: HKT1
+{
+  /// Wrap `M<Result<A, E>>`
+  pub fn new(m: M::T<Result<A, E>>) -> Self {
+    Self(m)
+  }
+
+  /// Unwrap to `M<Result<A, E>>`
+  pub fn run(self) -> M::T<Result<A, E>> {
+    self.0
+  }
+}
+
+impl<M, E, A> ResultT<M, E, A> where M: MonadHKT
+{
+  /// `M<Err(e)>`
+  pub fn fail(e: E) -> Self {
+    Self(M::pure(Err(e)))
+  }
+
+  /// Transform the error, if there is one
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let r = ResultT::<hkt::Option, u8, ()>::fail(1).map_err(|e: u8| e + 1);
+  ///
+  /// assert_eq!(r.run(), Some(Err(2)));
+  /// ```
+  pub fn map_err<E2, F>(self, f: F) -> ResultT<M, E2, A>
+    where F: F1<E, Ret = E2>
+  {
+    ResultT(M::fmap(self.0, move |r: Result<A, E>| r.map_err(|e| f.call(e))))
+  }
+}
+
+impl<M, E, A> Clone for ResultT<M, E, A>
+  where M: HKT1,
+        M::T<Result<A, E>>: Clone
+{
+  fn clone(&self) -> Self {
+    Self(self.0.clone())
+  }
+}
+
+impl<M, E, A> fmt::Debug for ResultT<M, E, A>
+  where M: HKT1,
+        M::T<Result<A, E>>: fmt::Debug
+{
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_tuple("ResultT").field(&self.0).finish()
+  }
+}
+
+impl<M, E, A> PartialEq for ResultT<M, E, A>
+  where M: HKT1,
+        M::T<Result<A, E>>: PartialEq
+{
+  fn eq(&self, other: &Self) -> bool {
+    self.0 == other.0
+  }
+}
+
+impl<M, E, A> Eq for ResultT<M, E, A>
+  where M: HKT1,
+        M::T<Result<A, E>>: Eq
+{
+}
+
+impl<M, E, A> Functor<hkt::ResultT<M, E>, A> for ResultT<M, E, A> where M: MonadHKT
+{
+  fn fmap<AB, B>(self, f: AB) -> ResultT<M, E, B>
+    where AB: F1<A, Ret = B>
+  {
+    ResultT(M::fmap(self.0, move |ra: Result<A, E>| ra.map(|a| f.call(a))))
+  }
+}
+
+impl<M, E, AB> Apply<hkt::ResultT<M, E>, AB> for ResultT<M, E, AB>
+  where M: MonadHKT,
+        E: Clone
+{
+  fn apply_with<A, B, Cloner>(self, a: ResultT<M, E, A>, cloner: Cloner) -> ResultT<M, E, B>
+    where AB: F1<A, Ret = B>,
+          Cloner: for<'a> F1<&'a A, Ret = A>
+  {
+    let lifted = M::fmap(self.0, |f: Result<AB, E>| {
+      move |a: Result<A, E>| match (&f, a) {
+        | (Ok(f), Ok(a)) => Ok(f.call(a)),
+        | (Err(e), _) => Err(e.clone()),
+        | (_, Err(e)) => Err(e),
+      }
+    });
+
+    ResultT(M::apply_with(lifted, a.0, move |a: &Result<A, E>| match a {
+              | Ok(a) => Ok(cloner.call(a)),
+              | Err(e) => Err(e.clone()),
+            }))
+  }
+}
+
+impl<M, E, A> Applicative<hkt::ResultT<M, E>, A> for ResultT<M, E, A>
+  where M: MonadHKT,
+        E: Clone
+{
+  fn pure(a: A) -> ResultT<M, E, A> {
+    ResultT(M::pure(Ok(a)))
+  }
+}
+
+impl<M, E, A> Monad<hkt::ResultT<M, E>, A> for ResultT<M, E, A>
+  where M: MonadHKT,
+        E: Clone
+{
+  fn bind<B, AMB>(self, f: AMB) -> ResultT<M, E, B>
+    where AMB: F1<A, Ret = ResultT<M, E, B>>
+  {
+    ResultT(M::bind(self.0, move |ra: Result<A, E>| match ra {
+              | Ok(a) => f.call(a).0,
+              | Err(e) => M::pure(Err(e)),
+            }))
+  }
+}
+
+impl<M, E> MonadTrans<M> for hkt::ResultT<M, E> where M: MonadHKT
+{
+  fn lift<A>(ma: M::T<A>) -> ResultT<M, E, A> {
+    ResultT(M::fmap(ma, Ok))
+  }
+}
+
+deriving!(impl<M, E> MonadHKT for hkt::ResultT<M, E> where M: MonadHKT, E: Clone {..Monad});
@@ -0,0 +1,454 @@
+use core::marker::PhantomData;
+
+use super::{MonadHKT, MonadTrans};
+use crate::prelude::*;
+use crate::state::{Get, Gets, Modify, Put};
+
+/// StateT Kinds
+pub mod hkt {
+  use core::marker::PhantomData;
+
+  use crate::prelude::*;
+
+  /// `StateT<M, S, A>` with the base monad pinned to some `M`
+  /// and the state type pinned to some `S`.
+  ///
+  /// (Kind `Type -> Type`)
+  pub struct StateT<M, S>(PhantomData<(M, S)>);
+
+  impl<M, S> HKT1 for StateT<M, S> where M: HKT1
+  {
+    type T<A> = super::StateT<M, S, A>;
+  }
+}
+
+/// A lazy computation that threads a state `S` through
+/// a base monad `M` and produces a value `A`
+pub trait StateTLike<M, S, A>
+  where Self: Sized + Equiv<To = StateT<M, S, A>>,
+        M: MonadHKT
+{
+  /// Run this computation with an initial state,
+  /// yielding the value and the final state in `M`.
+  fn run(self, s: S) -> M::T<(A, S)>;
+
+  /// Run this computation with an initial state,
+  /// discarding the final state.
+  fn eval(self, s: S) -> M::T<A> {
+    M::fmap(self.run(s), |(a, _): (A, S)| a)
+  }
+
+  /// Run this computation with an initial state,
+  /// discarding the value.
+  fn exec(self, s: S) -> M::T<S> {
+    M::fmap(self.run(s), |(_, s): (A, S)| s)
+  }
+
+  /// [`MonadSurrogate::bind_`], but the function may return
+  /// any [`StateTLike`] rather than only [`StateT`].
+  ///
+  /// ```
+  /// use naan::prelude::*;
+  ///
+  /// let incr = StateT::<hkt::Option, usize, usize>::get().and_then_(|n: usize| StateT::put(n + 1));
+  ///
+  /// assert_eq!(incr.exec(1), Some(2));
+  /// ```
+  fn and_then_<B, AMB>(self, f: AMB) -> Bind<AMB, A, B, Self>
+    where AMB: F1<A>,
+          AMB::Ret: StateTLike<M, S, B>
+  {
+    Bind::new(f, self)
+  }
+}
+
+impl<I, M, S, A> FunctorSurrogate<hkt::StateT<M, S>, A> for I
+  where I: StateTLike<M, S, A>,
+        M: MonadHKT
+{
+  type Output<AB, B> = Map<AB, A, B, I>;
+
+  fn map_<AB, B>(self, f: AB) -> Map<AB, A, B, I>
+    where AB: F1<A, Ret = B>
+  {
+    Map::new(f, self)
+  }
+}
+
+impl<I, M, S, AB, TofA> ApplySurrogate<hkt::StateT<M, S>, AB, TofA> for I
+  where I: StateTLike<M, S, AB>,
+        M: MonadHKT
+{
+  type ApplyOutput<A, B> = Apply<A, B, AB, TofA, Self>;
+
+  fn apply_<A, B>(self, a: TofA) -> Apply<A, B, AB, TofA, Self>
+    where AB: F1Once<A, Ret = B>
+  {
+    Apply::new(self, a)
+  }
+}
+
+impl<I, M, S, A> ApplicativeSurrogate<hkt::StateT<M, S>, A> for I
+  where I: StateTLike<M, S, A>,
+        M: MonadHKT
+{
+  fn pure(a: A) -> StateT<M, S, A> {
+    StateT::lift(M::pure(a))
+  }
+}
+
+impl<I, M, S, A> MonadSurrogate<hkt::StateT<M, S>, A> for I
+  where I: StateTLike<M, S, A>,
+        M: MonadHKT
+{
+  type BindOutput<B, AMB> = Bind<AMB, A, B, Self>;
+
+  fn bind_<B, AMB>(self, f: AMB) -> Self::BindOutput<B, AMB>
+    where AMB: F1<A, Ret = StateT<M, S, B>>
+  {
+    Bind::new(f, self)
+  }
+}
+
+impl<M, S> MonadTrans<M> for hkt::StateT<M, S> where M: MonadHKT
+{
+  fn lift<A>(ma: M::T<A>) -> StateT<M, S, A> {
+    StateT::lift(ma)
+  }
+}
+
+/// Lazy stateful computation in a base monad `M`
+///
+/// Like [`State`], a StateT computation is a stack of structs
+/// that does nothing until it is [`run`](StateTLike::run) with an initial state.
+///
+/// `StateT<M, S, A>` itself is a computation in `M` [lifted](MonadTrans::lift)
+/// to leave the state untouched. Other primitives are:
+///  - [`StateT::suspend`] - any function from `S` to `M<(A, S)>`
+///  - [`StateT::from_state`] - any [`StateLike`] computation
+///  - [`StateT::get`], [`StateT::gets`], [`StateT::put`], [`StateT::modify`]
+///
+/// Continuations are called once per value yielded by `M`, so
+/// functions given to `map_`/`bind_`/`and_then_` must be [`F1`], not [`F1Once`].
+///
+/// Like [`State`], `StateT` has no [`Functor`] or [`Monad`] impl (and so no
+/// [`MonadHKT`] to stack further transformers on); use `StateTLike` and
+/// [`mdo!`](crate::mdo)`(state_t { .. })`.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// /// Take `n` items off the front of the input, failing when there aren't enough
+/// fn take(n: usize) -> impl StateTLike<hkt::Option, Vec<u8>, Vec<u8>> {
+///   StateT::suspend(move |mut input: Vec<u8>| {
+///     if input.len() < n {
+///       None
+///     } else {
+///       let rest = input.split_off(n);
+///       Some((input, rest))
+///     }
+///   })
+/// }
+///
+/// /// Take a length-prefixed chunk off the front of the input
+/// fn chunk() -> impl StateTLike<hkt::Option, Vec<u8>, Vec<u8>> {
+///   take(1).and_then_(|len: Vec<u8>| take(len[0] as usize))
+/// }
+///
+/// assert_eq!(chunk().run(vec![2, 10, 20, 30]),
+///            Some((vec![10, 20], vec![30])));
+/// assert_eq!(chunk().run(vec![4, 10, 20, 30]), None);
+/// ```
+pub struct StateT<M, S, A>(M::T<A>, PhantomData<S>) where M: HKT1;
+
+impl<M, S, A> StateT<M, S, A> where M: HKT1
+{
+  /// Lift a computation in `M`, leaving the state untouched
+  pub fn lift(ma: M::T<A>) -> Self {
+    Self(ma, PhantomData)
+  }
+
+  /// Store a lazy function from the current state to a value
+  /// and a new state in `M`
+  pub fn suspend<F>(f: F) -> Suspend<M, S, A, F>
+    where F: F1Once<S, Ret = M::T<(A, S)>>
+  {
+    Suspend::new(f)
+  }
+
+  /// Lift a [`StateLike`] computation into `M`
+  pub fn from_state<SA>(sa: SA) -> Hoist<M, SA>
+    where SA: StateLike<S, A>
+  {
+    Hoist::new(sa)
+  }
+
+  /// Yield a value derived from a reference to the current state
+  pub fn gets<F>(f: F) -> Hoist<M, Gets<S, F>>
+    where F: for<'a> F1Once<&'a S, Ret = A>
+  {
+    Hoist::new(State::gets(f))
+  }
+}
+
+impl<M, S> StateT<M, S, S> where M: HKT1
+{
+  /// Yield a copy of the current state
+  pub fn get() -> Hoist<M, Get<S>>
+    where S: Clone
+  {
+    Hoist::new(State::get())
+  }
+}
+
+impl<M, S> StateT<M, S, ()> where M: HKT1
+{
+  /// Replace the current state
+  pub fn put(s: S) -> Hoist<M, Put<S>> {
+    Hoist::new(State::put(s))
+  }
+
+  /// Transform the current state
+  pub fn modify<F>(f: F) -> Hoist<M, Modify<S, F>>
+    where F: F1Once<S, Ret = S>
+  {
+    Hoist::new(State::modify(f))
+  }
+}
+
+impl<M, S, A> Clone for StateT<M, S, A>
+  where M: HKT1,
+        M::T<A>: Clone
+{
+  fn clone(&self) -> Self {
+    Self(self.0.clone(), PhantomData)
+  }
+}
+
+impl<M, S, A> Equiv for StateT<M, S, A> where M: HKT1
+{
+  type To = StateT<M, S, A>;
+}
+
+impl<M, S, A> StateTLike<M, S, A> for StateT<M, S, A>
+  where M: MonadHKT,
+        S: Clone
+{
+  fn run(self, s: S) -> M::T<(A, S)> {
+    M::fmap(self.0, move |a| (a, s.clone()))
+  }
+}
+
+/// A deferred function from the current state to a value
+/// and a new state in `M`
+///
+/// See [`StateT::suspend`]
+#[must_use = "StateT is not evaluated until `StateTLike.run` invoked"]
+pub struct Suspend<M, S, A, F>(F, PhantomData<(M, S, A)>);
+
+impl<M, S, A, F> Suspend<M, S, A, F> {
+  /// See [`StateT::suspend`]
+  pub fn new(f: F) -> Self {
+    Self(f, PhantomData)
+  }
+}
+
+impl<M, S, A, F> Clone for Suspend<M, S, A, F> where F: Clone
+{
+  fn clone(&self) -> Self {
+    Self(self.0.clone(), PhantomData)
+  }
+}
+
+impl<M, S, A, F> Copy for Suspend<M, S, A, F> where F: Copy {}
+
+impl<M, S, A, F> Equiv for Suspend<M, S, A, F>
+  where M: HKT1,
+        F: F1Once<S, Ret = M::T<(A, S)>>
+{
+  type To = StateT<M, S, A>;
+}
+
+impl<M, S, A, F> StateTLike<M, S, A> for Suspend<M, S, A, F>
+  where M: MonadHKT,
+        F: F1Once<S, Ret = M::T<(A, S)>>
+{
+  fn run(self, s: S) -> M::T<(A, S)> {
+    self.0.call1(s)
+  }
+}
+
+/// A [`StateLike`] computation lifted into `M`
+///
+/// See [`StateT::from_state`]
+#[must_use = "StateT is not evaluated until `StateTLike.run` invoked"]
+pub struct Hoist<M, SA>(SA, PhantomData<M>);
+
+impl<M, SA> Hoist<M, SA> {
+  /// See [`StateT::from_state`]
+  pub fn new(sa: SA) -> Self {
+    Self(sa, PhantomData)
+  }
+}
+
+impl<M, SA> Clone for Hoist<M, SA> where SA: Clone
+{
+  fn clone(&self) -> Self {
+    Self(self.0.clone(), PhantomData)
+  }
+}
+
+impl<M, SA> Copy for Hoist<M, SA> where SA: Copy {}
+
+impl<M, S, A, SA> Equiv for Hoist<M, SA>
+  where M: HKT1,
+        SA: Equiv<To = State<S, A>>
+{
+  type To = StateT<M, S, A>;
+}
+
+impl<M, S, A, SA> StateTLike<M, S, A> for Hoist<M, SA>
+  where M: MonadHKT,
+        SA: StateLike<S, A>
+{
+  fn run(self, s: S) -> M::T<(A, S)> {
+    M::pure(self.0.run(s))
+  }
+}
+
+/// A function from `X` to some `A` waiting to be applied to
+/// each value yielded by a [`StateTLike`].
+///
+/// This is the result of calling [`FunctorSurrogate.map_`] on a [`StateTLike`].
+#[must_use = "StateT is not evaluated until `StateTLike.run` invoked"]
+pub struct Map<F, X, A, SX>(F, SX, PhantomData<(X, A)>);
+
+impl<F, X, A, SX> Map<F, X, A, SX> {
+  /// Create a new Map
+  pub fn new(f: F, sx: SX) -> Self {
+    Self(f, sx, PhantomData)
+  }
+}
+
+impl<F, X, A, SX> Clone for Map<F, X, A, SX>
+  where F: Clone,
+        SX: Clone
+{
+  fn clone(&self) -> Self {
+    Self(self.0.clone(), self.1.clone(), PhantomData)
+  }
+}
+
+impl<F, X, A, SX> Copy for Map<F, X, A, SX>
+  where F: Copy,
+        SX: Copy
+{
+}
+
+impl<F, M, S, X, A, SX> Equiv for Map<F, X, A, SX>
+  where M: HKT1,
+        F: F1<X, Ret = A>,
+        SX: Equiv<To = StateT<M, S, X>>
+{
+  type To = StateT<M, S, A>;
+}
+
+impl<F, M, S, X, A, SX> StateTLike<M, S, A> for Map<F, X, A, SX>
+  where M: MonadHKT,
+        F: F1<X, Ret = A>,
+        SX: StateTLike<M, S, X>
+{
+  fn run(self, s: S) -> M::T<(A, S)> {
+    let f = self.0;
+    M::fmap(self.1.run(s), move |(x, s): (X, S)| (f.call(x), s))
+  }
+}
+
+/// A function from `A` to a `StateTLike<M, S, B>` waiting to be applied to
+/// each value yielded by a [`StateTLike`].
+///
+/// This is the result of calling [`MonadSurrogate.bind_`] or
+/// [`StateTLike.and_then_`] on a [`StateTLike`].
+#[must_use = "StateT is not evaluated until `StateTLike.run` invoked"]
+pub struct Bind<F, A, B, SA>(F, SA, PhantomData<(A, B)>);
+
+impl<F, A, B, SA> Bind<F, A, B, SA> {
+  /// Create a new Bind
+  pub fn new(f: F, sa: SA) -> Self {
+    Self(f, sa, PhantomData)
+  }
+}
+
+impl<F, A, B, SA> Clone for Bind<F, A, B, SA>
+  where F: Clone,
+        SA: Clone
+{
+  fn clone(&self) -> Self {
+    Self(self.0.clone(), self.1.clone(), PhantomData)
+  }
+}
+
+impl<F, A, B, SA> Copy for Bind<F, A, B, SA>
+  where F: Copy,
+        SA: Copy
+{
+}
+
+impl<F, M, S, A, B, SA> Equiv for Bind<F, A, B, SA>
+  where M: HKT1,
+        F: F1<A>,
+        F::Ret: Equiv<To = StateT<M, S, B>>,
+        SA: Equiv<To = StateT<M, S, A>>
+{
+  type To = StateT<M, S, B>;
+}
+
+impl<F, M, S, A, B, SA> StateTLike<M, S, B> for Bind<F, A, B, SA>
+  where M: MonadHKT,
+        F: F1<A>,
+        F::Ret: StateTLike<M, S, B>,
+        SA: StateTLike<M, S, A>
+{
+  fn run(self, s: S) -> M::T<(B, S)> {
+    let f = self.0;
+    M::bind(self.1.run(s), move |(a, s): (A, S)| f.call(a).run(s))
+  }
+}
+
+/// A [`StateTLike`] that, when run, will apply the functions yielded by
+/// `SAB` to the values yielded by `SA`, threading the state through
+/// `SAB` first and `SA` second.
+///
+/// `SA` is run once per function yielded by `SAB`, so must be [`Clone`].
+///
+/// This is the result of calling [`ApplySurrogate.apply_`] on a [`StateTLike`].
+#[must_use = "StateT is not evaluated until `StateTLike.run` invoked"]
+pub struct Apply<A, B, AB, SA, SAB>(SAB, SA, PhantomData<(A, B, AB)>);
+
+impl<A, B, AB, SA, SAB> Apply<A, B, AB, SA, SAB> {
+  /// Create a new Apply
+  pub fn new(sab: SAB, sa: SA) -> Self {
+    Self(sab, sa, PhantomData)
+  }
+}
+
+impl<M, S, A, B, AB, SA, SAB> Equiv for Apply<A, B, AB, SA, SAB>
+  where M: HKT1,
+        SAB: Equiv<To = StateT<M, S, AB>>
+{
+  type To = StateT<M, S, B>;
+}
+
+impl<M, S, A, B, AB, SA, SAB> StateTLike<M, S, B> for Apply<A, B, AB, SA, SAB>
+  where M: MonadHKT,
+        AB: F1<A, Ret = B>,
+        SA: StateTLike<M, S, A> + Clone,
+        SAB: StateTLike<M, S, AB>
+{
+  fn run(self, s: S) -> M::T<(B, S)> {
+    let sa = self.1;
+    M::bind(self.0.run(s), move |(ab, s): (AB, S)| {
+      M::fmap(sa.clone().run(s), move |(a, s): (A, S)| (ab.call(a), s))
+    })
+  }
+}
@@ -0,0 +1,163 @@
+use core::fmt;
+
+use super::{MonadHKT, MonadTrans};
+use crate::prelude::*;
+
+/// WriterT Kinds
+pub mod hkt {
+  use core::marker::PhantomData;
+
+  use crate::prelude::*;
+
+  /// [`WriterT`](super::WriterT) lifted to an HKT1
+  /// with the base monad pinned to some `M` and
+  /// the log type pinned to some `W`.
+  ///
+  /// (Kind `Type -> Type`)
+  pub struct WriterT<M, W>(PhantomData<(M, W)>);
+  impl<M, W> HKT1 for WriterT<M, W> where M: HKT1
+  {
+    type T<A> = super::WriterT<M, W, A>;
+  }
+}
+
+/// Adds a log `W` to a base monad `M`, wrapping `M<(A, W)>`.
+///
+/// Like [`Writer`], sequencing `WriterT`s [`append`](Semigroup::append)s their logs.
+///
+/// ```
+/// use naan::prelude::*;
+///
+/// type Search<A> = WriterT<hkt::Vec, Vec<u8>, A>;
+///
+/// // every way to choose one of 1, 2 then one of 10, 20,
+/// // logging the choices made
+/// fn choose(ns: Vec<u8>) -> Search<u8> {
+///   WriterT::new(ns.into_iter().map(|n| (n, vec![n])).collect())
+/// }
+///
+/// let sums = choose(vec![1, 2]).bind(|a| choose(vec![10, 20]).fmap(move |b| a + b));
+///
+/// assert_eq!(sums.run(),
+///            vec![(11, vec![1, 10]),
+///                 (21, vec![1, 20]),
+///                 (12, vec![2, 10]),
+///                 (22, vec![2, 20])]);
+/// ```
+pub struct WriterT<M, W, A>(M::T<(A, W)>) where M: HKT1;
+
+impl<M, W, A> WriterT<M, W, A> where M: HKT1
+{
+  /// Wrap `M<(A, W)>`
+  pub fn new(m: M::T<(A, W)>) -> Self {
+    Self(m)
+  }
+
+  /// Unwrap to `M<(A, W)>`
+  pub fn run(self) -> M::T<(A, W)> {
+    self.0
+  }
+}
+
+impl<M, W> WriterT<M, W, ()> where M: MonadHKT
+{
+  /// Write to the log
+  pub fn tell(w: W) -> Self {
+    Self(M::pure(((), w)))
+  }
+}
+
+impl<M, W, A> Clone for WriterT<M, W, A>
+  where M: HKT1,
+        M::T<(A, W)>: Clone
+{
+  fn clone(&self) -> Self {
+    Self(self.0.clone())
+  }
+}
+
+impl<M, W, A> fmt::Debug for WriterT<M, W, A>
+  where M: HKT1,
+        M::T<(A, W)>: fmt::Debug
+{
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_tuple("WriterT").field(&self.0).finish()
+  }
+}
+
+impl<M, W, A> PartialEq for WriterT<M, W, A>
+  where M: HKT1,
+        M::T<(A, W)>: PartialEq
+{
+  fn eq(&self, other: &Self) -> bool {
+    self.0 == other.0
+  }
+}
+
+impl<M, W, A> Eq for WriterT<M, W, A>
+  where M: HKT1,
+        M::T<(A, W)>: Eq
+{
+}
+
+impl<M, W, A> Functor<hkt::WriterT<M, W>, A> for WriterT<M, W, A> where M: MonadHKT
+{
+  fn fmap<AB, B>(self, f: AB) -> WriterT<M, W, B>
+    where AB: F1<A, Ret = B>
+  {
+    WriterT(M::fmap(self.0, move |(a, w): (A, W)| (f.call(a), w)))
+  }
+}
+
+impl<M, W, AB> Apply<hkt::WriterT<M, W>, AB> for WriterT<M, W, AB>
+  where M: MonadHKT,
+        W: Semigroup + Clone
+{
+  fn apply_with<A, B, Cloner>(self, a: WriterT<M, W, A>, cloner: Cloner) -> WriterT<M, W, B>
+    where AB: F1<A, Ret = B>,
+          Cloner: for<'a> F1<&'a A, Ret = A>
+  {
+    let lifted = M::fmap(self.0, |(f, w1): (AB, W)| {
+      move |(a, w2): (A, W)| (f.call(a), w1.clone().append(w2))
+    });
+
+    WriterT(M::apply_with(lifted, a.0, move |(a, w): &(A, W)| {
+              (cloner.call(a), w.clone())
+            }))
+  }
+}
+
+impl<M, W, A> Applicative<hkt::WriterT<M, W>, A> for WriterT<M, W, A>
+  where M: MonadHKT,
+        W: Monoid + Clone
+{
+  fn pure(a: A) -> WriterT<M, W, A> {
+    WriterT(M::pure((a, W::identity())))
+  }
+}
+
+impl<M, W, A> Monad<hkt::WriterT<M, W>, A> for WriterT<M, W, A>
+  where M: MonadHKT,
+        W: Monoid + Clone
+{
+  fn bind<B, AMB>(self, f: AMB) -> WriterT<M, W, B>
+    where AMB: F1<A, Ret = WriterT<M, W, B>>
+  {
+    WriterT(M::bind(self.0, move |(a, w1): (A, W)| {
+              M::fmap(f.call(a).0, move |(b, w2): (B, W)| {
+                (b, w1.clone().append(w2))
+              })
+            }))
+  }
+}
+
+impl<M, W> MonadTrans<M> for hkt::WriterT<M, W>
+  where M: MonadHKT,
+        W: Monoid
+{
+  fn lift<A>(ma: M::T<A>) -> WriterT<M, W, A> {
+    WriterT(M::fmap(ma, |a| (a, W::identity())))
+  }
+}
+
+deriving!(impl<M, W> MonadHKT for hkt::WriterT<M, W> where M: MonadHKT, W: Monoid + Clone {..Monad});
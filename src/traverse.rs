@@ -1,3 +1,9 @@
+#[cfg(feature = "alloc")]
+use core::cell::RefCell;
+
+#[cfg(feature = "alloc")]
+use std_alloc::vec::Vec;
+
 use crate::prelude::*;
 
 /// Sequence extends [`Traversable`] with a function that inverts collection
@@ -329,3 +335,169 @@ pub trait Traversable<F, A, B, TF> {
     self.traversem1::<Ap, AtoApOfB>(f)
   }
 }
+
+/// The shape of a [`Functor`] with its values taken out,
+/// and the values that will be put back in their place.
+///
+/// This makes any [`Functor`] traversable by moving its values into a
+/// [`Vec`], traversing that, and putting each result back where its input was
+/// taken from.
+///
+/// The shape remembers the position of each value it gave out, so this
+/// works for functors that don't `fmap` in the same order twice (e.g. `HashMap`).
+///
+/// `#[derive(Traversable)]` uses this as the `TF` type of the impls it generates.
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use naan::prelude::*;
+/// use naan::traverse::Refill;
+///
+/// let (refill, values) = Refill::<hkt::Option, &str>::new(Some(1));
+/// assert_eq!(values, vec![1]);
+/// assert_eq!(refill.call1("one").fill(), Some("one"));
+///
+/// let parsed = Refill::<hkt::Vec, u8>::traversem1::<_, hkt::Option, _>(vec!["1", "2"],
+///                                                                      |s: &str| s.parse().ok());
+/// assert_eq!(parsed, Some(vec![1, 2]));
+///
+/// let nums = (0..32u8).map(|n| (n, n.to_string()))
+///                     .collect::<HashMap<_, _>>();
+/// let parsed =
+///   Refill::<hkt::HashMapValues<u8>, u8>::traversem1::<_, hkt::Option, _>(nums, |s: String| {
+///     s.parse().ok()
+///   });
+/// assert!(parsed.unwrap().into_iter().all(|(k, v)| k == v));
+/// ```
+#[cfg(feature = "alloc")]
+pub struct Refill<F, B>
+  where F: HKT1
+{
+  shape: F::T<usize>,
+  clone_shape: fn(&F::T<usize>) -> F::T<usize>,
+  values: Vec<B>,
+}
+
+#[cfg(feature = "alloc")]
+impl<F, B> Refill<F, B>
+  where F: HKT1,
+        F::T<usize>: Functor<F, usize>
+{
+  /// Take the values out of `fa`, leaving its shape behind
+  pub fn new<A>(fa: F::T<A>) -> (Self, Vec<A>)
+    where F::T<A>: Functor<F, A>,
+          F::T<usize>: Clone
+  {
+    Self::new_with(fa, Clone::clone)
+  }
+
+  /// [`Refill::new`], using `clone_shape` to copy the shape when the `Refill` is cloned
+  pub fn new_with<A>(fa: F::T<A>, clone_shape: fn(&F::T<usize>) -> F::T<usize>) -> (Self, Vec<A>)
+    where F::T<A>: Functor<F, A>
+  {
+    let values = RefCell::new(Vec::new());
+    let shape = fa.fmap(|a| {
+                    let mut values = values.borrow_mut();
+                    values.push(a);
+                    values.len() - 1
+                  });
+
+    (Self { shape,
+            clone_shape,
+            values: Vec::new() },
+     values.into_inner())
+  }
+
+  /// Put the values back, each where the value at the same index was taken from
+  ///
+  /// Panics if fewer values were put back than were taken out.
+  pub fn fill(self) -> F::T<B> {
+    let values = RefCell::new(self.values.into_iter().map(Some).collect::<Vec<_>>());
+    self.shape.fmap(|ix: usize| {
+                values.borrow_mut()
+                      .get_mut(ix)
+                      .and_then(Option::take)
+                      .expect("Refill was given fewer values than were taken out")
+              })
+  }
+
+  /// [`Traversable::traversem1`] for any [`Functor`]
+  pub fn traversem1<A, Ap, AtoApOfB>(fa: F::T<A>, f: AtoApOfB) -> Ap::T<F::T<B>>
+    where Ap: HKT1,
+          F::T<A>: Functor<F, A>,
+          Ap::T<Self>: Applicative<Ap, Self> + ApplyOnce<Ap, Self>,
+          AtoApOfB: F1<A, Ret = Ap::T<B>>
+  {
+    // `ApplyOnce` never needs to clone the `Refill`, so the shape is never cloned.
+    let (refill, values) = Self::new_with(fa, |_| {
+      unreachable!("Refill::traversem1 never clones its shape")
+    });
+    values.into_iter()
+          .fold(Ap::T::pure(refill), |ap, a| ap.apply1(f.call(a)))
+          .fmap(Self::fill)
+  }
+
+  /// [`Traversable::traversemm`] for any [`Functor`]
+  pub fn traversemm<A, Ap, AtoApOfB>(fa: F::T<A>, f: AtoApOfB) -> Ap::T<F::T<B>>
+    where Ap: HKT1,
+          B: Clone,
+          F::T<A>: Functor<F, A>,
+          F::T<usize>: Clone,
+          Ap::T<Self>: Applicative<Ap, Self>,
+          AtoApOfB: F1<A, Ret = Ap::T<B>>
+  {
+    Self::traversemm_with::<A, Ap, AtoApOfB>(fa, Clone::clone, f)
+  }
+
+  /// [`Refill::traversemm`], using `clone_shape` to copy the shape
+  /// for each result of the applicative
+  pub fn traversemm_with<A, Ap, AtoApOfB>(fa: F::T<A>,
+                                          clone_shape: fn(&F::T<usize>) -> F::T<usize>,
+                                          f: AtoApOfB)
+                                          -> Ap::T<F::T<B>>
+    where Ap: HKT1,
+          B: Clone,
+          F::T<A>: Functor<F, A>,
+          Ap::T<Self>: Applicative<Ap, Self>,
+          AtoApOfB: F1<A, Ret = Ap::T<B>>
+  {
+    let (refill, values) = Self::new_with(fa, clone_shape);
+    values.into_iter()
+          .fold(Ap::T::pure(refill), |ap, a| ap.apply(f.call(a)))
+          .fmap(Self::fill)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<F, B> Clone for Refill<F, B>
+  where F: HKT1,
+        B: Clone
+{
+  fn clone(&self) -> Self {
+    Self { shape: (self.clone_shape)(&self.shape),
+           clone_shape: self.clone_shape,
+           values: self.values.clone() }
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<F, B> F1Once<B> for Refill<F, B> where F: HKT1
+{
+  type Ret = Self;
+
+  fn call1(mut self, b: B) -> Self {
+    self.values.push(b);
+    self
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<F, B> F1<B> for Refill<F, B>
+  where F: HKT1,
+        B: Clone
+{
+  fn call(&self, b: B) -> Self {
+    self.clone().call1(b)
+  }
+}